@@ -0,0 +1,62 @@
+use aoc2015::*;
+use aoc_cli::Day;
+use std::{process::ExitCode, str};
+
+const DAYS: &[Day] = &[
+	Day::new(1, "Not Quite Lisp", "assets/01.txt", |input, _| {
+		aoc_cli::answers(d01::solve(str::from_utf8(input)?))
+	}),
+	Day::new(
+		2,
+		"I Was Told There Would Be No Math",
+		"assets/02.txt",
+		|input, _| aoc_cli::answers(d02::solve(str::from_utf8(input)?)),
+	),
+	Day::new(
+		3,
+		"Perfectly Spherical Houses in a Vacuum",
+		"assets/03.txt",
+		|input, _| aoc_cli::answers(d03::solve(str::from_utf8(input)?)),
+	),
+	Day::new(
+		4,
+		"The Ideal Stocking Stuffer",
+		"assets/04.txt",
+		|input, _| {
+			let input = str::from_utf8(input)?.trim();
+			Ok([
+				d04::solve("00000", input).into(),
+				d04::solve("000000", input).into(),
+			])
+		},
+	),
+	Day::new(
+		5,
+		"Doesn't He Have Intern-Elves For This?",
+		"assets/05.txt",
+		|input, _| aoc_cli::answers(d05::solve(str::from_utf8(input)?.trim())),
+	),
+	Day::new(6, "Probably a Fire Hazard", "assets/06.txt", |input, _| {
+		aoc_cli::answers(d06::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(
+		7,
+		"Some Assembly Required",
+		"assets/07.txt",
+		|input, params| {
+			let input = str::from_utf8(input)?.trim();
+			aoc_cli::answers(d07::solve(input, &params.get::<String>("wire")?)?)
+		},
+	)
+	.with_params(&[("wire", "a")]),
+	Day::new(8, "Matchsticks", "assets/08.txt", |input, _| {
+		aoc_cli::answers(d08::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(9, "All in a Single Night", "assets/09.txt", |input, _| {
+		aoc_cli::answers(d09::solve(str::from_utf8(input)?.trim()))
+	}),
+];
+
+fn main() -> ExitCode {
+	aoc_cli::main(2015, concat!(env!("CARGO_MANIFEST_DIR"), "/.."), DAYS)
+}
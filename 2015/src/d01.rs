@@ -1,9 +1,19 @@
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	floor: i128,
 	first_time_basement: Option<usize>,
 }
 
-fn solve(input: &str) -> Solution {
+impl aoc_solution::Solution for Solution {
+	type Part1 = i128;
+	type Part2 = Option<usize>;
+
+	fn into_parts(self) -> (i128, Option<usize>) {
+		(self.floor, self.first_time_basement)
+	}
+}
+
+pub fn solve(input: &str) -> Solution {
 	let mut first_time_basement = None;
 
 	let floor = input.chars().enumerate().fold(0, |floor, (index, c)| {
@@ -28,7 +38,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -46,23 +55,9 @@ mod tests {
 		assert_eq!(solve(")())())").floor, -3);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/01.txt").unwrap();
-
-		assert_eq!(solve(&input).floor, 232);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve(")").first_time_basement, Some(1));
 		assert_eq!(solve("()())").first_time_basement, Some(5));
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/01.txt").unwrap();
-
-		assert_eq!(solve(&input).first_time_basement, Some(1783));
-	}
 }
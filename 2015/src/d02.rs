@@ -2,11 +2,21 @@ use std::str::FromStr;
 
 use anyhow::Error;
 
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	square_feet: u128,
 	ribbon_length: u128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u128;
+	type Part2 = u128;
+
+	fn into_parts(self) -> (u128, u128) {
+		(self.square_feet, self.ribbon_length)
+	}
+}
+
 struct Measurements(u128, u128, u128);
 
 impl FromStr for Measurements {
@@ -25,7 +35,7 @@ impl FromStr for Measurements {
 	}
 }
 
-fn solve(input: &str) -> Solution {
+pub fn solve(input: &str) -> Solution {
 	let (square_feet, ribbon_length) = input
 		.lines()
 		.map(|line| line.parse::<Measurements>().unwrap())
@@ -53,7 +63,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -63,13 +72,6 @@ mod tests {
 		assert_eq!(solve("2x3x4\n1x1x10").square_feet, 58 + 43);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/02.txt").unwrap();
-
-		assert_eq!(solve(&input).square_feet, 1598415);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("2x3x4").ribbon_length, 34);
@@ -77,11 +79,4 @@ mod tests {
 
 		assert_eq!(solve("2x3x4\n1x1x10").ribbon_length, 34 + 14);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/02.txt").unwrap();
-
-		assert_eq!(solve(&input).ribbon_length, 3812909);
-	}
 }
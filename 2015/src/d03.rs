@@ -1,8 +1,18 @@
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	gifted_houses: usize,
 	robo_houses: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.gifted_houses, self.robo_houses)
+	}
+}
+
 struct House {
 	coords: (i128, i128),
 	presents: u128,
@@ -58,7 +68,7 @@ impl VisitsMap {
 	}
 }
 
-fn solve(input: &str) -> Solution {
+pub fn solve(input: &str) -> Solution {
 	let mut visits = VisitsMap::new();
 	let mut visits_with_robo = VisitsMap::new();
 	input.trim().chars().enumerate().for_each(|(i, c)| {
@@ -79,7 +89,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -88,24 +97,10 @@ mod tests {
 		assert_eq!(solve("^v^v^v^v^v").gifted_houses, 2);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/03.txt").unwrap();
-
-		assert_eq!(solve(&input).gifted_houses, 2572);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("^v").robo_houses, 3);
 		assert_eq!(solve("^>v<").robo_houses, 3);
 		assert_eq!(solve("^v^v^v^v^v").robo_houses, 11);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/03.txt").unwrap();
-
-		assert_eq!(solve(&input).robo_houses, 2631);
-	}
 }
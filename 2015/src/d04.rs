@@ -1,13 +1,21 @@
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	first_coin_integer: u64,
 }
 
+/// Part 1 and 2 only differ in the `coin_goal`, so each call yields one answer.
+impl From<Solution> for aoc_solution::Answer {
+	fn from(solution: Solution) -> Self {
+		solution.first_coin_integer.into()
+	}
+}
+
 fn get_hash(input: &str, suffix_int: u64) -> String {
 	let digest = md5::compute(format!("{}{}", input, suffix_int));
 	format!("{:x}", digest)
 }
 
-fn solve(coin_goal: &str, input: &str) -> Solution {
+pub fn solve(coin_goal: &str, input: &str) -> Solution {
 	let mut suffix_int = 0;
 	let mut hash = get_hash(input, suffix_int);
 
@@ -1,8 +1,18 @@
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	nice_amount: usize,
 	new_rulez_nice_amount: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.nice_amount, self.new_rulez_nice_amount)
+	}
+}
+
 const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
 const NAUGHTY_STRINGS: [&str; 4] = ["ab", "cd", "pq", "xy"];
 
@@ -40,7 +50,7 @@ fn is_nice_new_rulez(word: &&str) -> bool {
 	has_double_pair && has_skip_pair
 }
 
-fn solve(input: &str) -> Solution {
+pub fn solve(input: &str) -> Solution {
 	let nice_amount = input.lines().filter(is_nice).count();
 
 	let new_rulez_nice_amount = input.lines().filter(is_nice_new_rulez).count();
@@ -54,7 +64,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -71,13 +80,6 @@ mod tests {
 		);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/05.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).nice_amount, 258);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("qjhvhtzxzqqjkmpb").new_rulez_nice_amount, 1);
@@ -91,11 +93,4 @@ mod tests {
 			2
 		);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/05.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).new_rulez_nice_amount, 53);
-	}
 }
@@ -2,11 +2,21 @@ use std::{ops::RangeInclusive, str::FromStr};
 
 use anyhow::{bail, Error};
 
+#[derive(Debug)]
 pub struct Solution {
 	lights_on: u32,
 	allover_brightness: u32,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u32;
+	type Part2 = u32;
+
+	fn into_parts(self) -> (u32, u32) {
+		(self.lights_on, self.allover_brightness)
+	}
+}
+
 struct Coordinates {
 	x: u16,
 	y: u16,
@@ -107,7 +117,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -120,13 +129,6 @@ mod tests {
 		assert_eq!(solve("turn on 0,0 through 499,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500").lights_on, 499_998);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/06.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).lights_on, 400410);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(
@@ -139,11 +141,4 @@ mod tests {
 		);
 		assert_eq!(solve("turn on 0,0 through 499,999\ntoggle 0,0 through 999,0\nturn off 499,499 through 500,500").allover_brightness, 501_998);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/06.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).allover_brightness, 15343601);
-	}
 }
@@ -74,12 +74,22 @@ impl WireCommand {
 	}
 }
 
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	wire_out: Bits,
 	b_reroute_wire_out: Bits,
 }
 
-fn solve(input: &str, wire: &str) -> Result<Solution> {
+impl aoc_solution::Solution for Solution {
+	type Part1 = Bits;
+	type Part2 = Bits;
+
+	fn into_parts(self) -> (Bits, Bits) {
+		(self.wire_out, self.b_reroute_wire_out)
+	}
+}
+
+pub fn solve(input: &str, wire: &str) -> Result<Solution> {
 	let mut salad: WireSalad = HashMap::new();
 
 	for line in input.lines() {
@@ -124,7 +134,6 @@ fn solve(input: &str, wire: &str) -> Result<Solution> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -149,22 +158,8 @@ NOT y -> i
 		assert_eq!(solve(example.trim(), "y").unwrap().wire_out, 456);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/07.txt").unwrap();
-
-		assert_eq!(solve(input.trim(), "a").unwrap().wire_out, 16076);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		// No examples here
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/07.txt").unwrap();
-
-		assert_eq!(solve(input.trim(), "a").unwrap().b_reroute_wire_out, 2797);
-	}
 }
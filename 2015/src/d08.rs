@@ -1,13 +1,23 @@
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	code_str_overhead: usize,
 	escaped_code_str_overhead: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.code_str_overhead, self.escaped_code_str_overhead)
+	}
+}
+
 const MATCH_ESC_BACK: &str = r#"\\"#;
 const MATCH_UNICODE: &str = r#"\x"#;
 const MATCH_ESC: &str = r#"\""#;
 
-fn solve(input: &str) -> Solution {
+pub fn solve(input: &str) -> Solution {
 	let code_str_overhead = input
 		.lines()
 		.map(|line| {
@@ -53,7 +63,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -71,13 +80,6 @@ mod tests {
 		assert_eq!(solve(combined.trim()).code_str_overhead, 12);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/08.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).code_str_overhead, 1333);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve(r#""""#).escaped_code_str_overhead, 4);
@@ -93,11 +95,4 @@ mod tests {
 "#;
 		assert_eq!(solve(combined.trim()).escaped_code_str_overhead, 19);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/08.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).escaped_code_str_overhead, 2046);
-	}
 }
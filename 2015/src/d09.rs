@@ -5,11 +5,21 @@ use hashbrown::HashSet;
 use itertools::Itertools;
 use std::str::FromStr;
 
-struct Solution {
+#[derive(Debug)]
+pub struct Solution {
 	shortest_distance: usize,
 	longest_distance: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.shortest_distance, self.longest_distance)
+	}
+}
+
 struct Route {
 	places: [String; 2],
 	dist: usize,
@@ -50,7 +60,7 @@ fn get_dist(places: Vec<&String>, routes: &[Route]) -> usize {
 		.sum()
 }
 
-fn solve(input: &str) -> Solution {
+pub fn solve(input: &str) -> Solution {
 	let routes: Vec<_> = input.lines().map(|l| Route::from_str(l).unwrap()).collect();
 	let unique_places: HashSet<String> = routes
 		.iter()
@@ -72,7 +82,6 @@ fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -83,13 +92,6 @@ mod tests {
 		)
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/09.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).shortest_distance, 117);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(
@@ -98,11 +100,4 @@ mod tests {
 			982
 		)
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/09.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).longest_distance, 909);
-	}
 }
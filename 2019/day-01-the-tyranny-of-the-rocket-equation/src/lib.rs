@@ -1,8 +1,18 @@
+#[derive(Debug)]
 pub struct Solution {
 	pub core_fuel_requirements: u64,
 	pub full_fuel_requirements: u64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.core_fuel_requirements, self.full_fuel_requirements)
+	}
+}
+
 fn calculate_fuel(module_mass: u64) -> Option<u64> {
 	(module_mass / 3).checked_sub(2)
 }
@@ -59,14 +69,6 @@ mod tests {
 		);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input).core_fuel_requirements, 3210097);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("12").full_fuel_requirements, 2);
@@ -83,12 +85,4 @@ mod tests {
 			combined_result
 		);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input).full_fuel_requirements, 4812287);
-	}
 }
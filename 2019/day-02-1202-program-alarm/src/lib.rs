@@ -9,11 +9,24 @@ pub struct Params {
 	verb: i64,
 }
 
+#[derive(Debug)]
 pub struct Solution {
 	pub output_1202: i64,
 	pub secret_params: Params,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i64;
+	type Part2 = i64;
+
+	fn into_parts(self) -> (i64, i64) {
+		(
+			self.output_1202,
+			self.secret_params.noun * 100 + self.secret_params.verb,
+		)
+	}
+}
+
 const PARAMS_1202: Params = Params { noun: 12, verb: 2 };
 
 const REVERSE_RESULT: i64 = 19690720;
@@ -51,31 +64,3 @@ pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 		secret_params: backsolve(program, REVERSE_RESULT)?,
 	})
 }
-
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use std::fs;
-
-	#[test]
-	fn part_1_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.output_1202, 4945026);
-		Ok(())
-	}
-
-	#[test]
-	fn part_2_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		let params = solve(&input)?.secret_params;
-
-		assert_eq!(params.noun, 52);
-		assert_eq!(params.verb, 96);
-		assert_eq!(params.noun * 100 + params.verb, 5296);
-		Ok(())
-	}
-}
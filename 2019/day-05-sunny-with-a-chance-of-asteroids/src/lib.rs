@@ -2,11 +2,21 @@ use std::str::FromStr;
 
 use intcode::{IntCodeError, IntCodeProgram};
 
+#[derive(Debug)]
 pub struct Solution {
 	pub ac_diagnostic: i64,
 	pub tr_diagnostic: i64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i64;
+	type Part2 = i64;
+
+	fn into_parts(self) -> (i64, i64) {
+		(self.ac_diagnostic, self.tr_diagnostic)
+	}
+}
+
 const AC_UNIT_ID: i64 = 1;
 const THERMAL_RADIATOR_ID: i64 = 5;
 
@@ -38,27 +48,3 @@ pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 		tr_diagnostic,
 	})
 }
-
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use std::fs;
-
-	#[test]
-	fn part_1_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.ac_diagnostic, 13285749);
-		Ok(())
-	}
-
-	#[test]
-	fn part_2_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.tr_diagnostic, 5000972);
-		Ok(())
-	}
-}
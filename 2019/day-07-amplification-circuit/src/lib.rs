@@ -3,11 +3,21 @@ use intcode::{IntCodeError, IntCodeProgram};
 use itertools::Itertools;
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	pub max_thruster_signal: i64,
 	pub max_looped_thruster_signal: i64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i64;
+	type Part2 = i64;
+
+	fn into_parts(self) -> (i64, i64) {
+		(self.max_thruster_signal, self.max_looped_thruster_signal)
+	}
+}
+
 fn get_thruster_signal(
 	program: &IntCodeProgram,
 	phase_sequence: Vec<i64>,
@@ -84,7 +94,6 @@ pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_examples() -> Result<(), IntCodeError> {
@@ -99,22 +108,4 @@ mod tests {
 
 		Ok(())
 	}
-
-	#[test]
-	fn part_1_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.max_thruster_signal, 21760);
-		Ok(())
-	}
-
-	#[test]
-	fn part_2_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.max_looped_thruster_signal, 69816958);
-		Ok(())
-	}
 }
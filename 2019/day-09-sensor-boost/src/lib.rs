@@ -2,11 +2,21 @@ use std::str::FromStr;
 
 use intcode::{IntCodeError, IntCodeProgram};
 
+#[derive(Debug)]
 pub struct Solution {
 	pub boost_keycode: i64,
 	pub distress_coordinates: i64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i64;
+	type Part2 = i64;
+
+	fn into_parts(self) -> (i64, i64) {
+		(self.boost_keycode, self.distress_coordinates)
+	}
+}
+
 const TEST_MODE_INPUT: i64 = 1;
 const BOOST_MODE_INPUT: i64 = 2;
 
@@ -36,27 +46,3 @@ pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 		distress_coordinates,
 	})
 }
-
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use std::fs;
-
-	#[test]
-	fn part_1_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.boost_keycode, 4261108180);
-		Ok(())
-	}
-
-	#[test]
-	fn part_2_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.distress_coordinates, 77944);
-		Ok(())
-	}
-}
@@ -6,11 +6,21 @@ use intcode::{IntCodeError, IntCodeProgram};
 use robot::Robot;
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	pub first_try_painted_tiles: usize,
 	pub registration_identifier: String,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = String;
+
+	fn into_parts(self) -> (usize, String) {
+		(self.first_try_painted_tiles, self.registration_identifier)
+	}
+}
+
 pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 	let program = IntCodeProgram::from_str(input)?;
 
@@ -22,42 +32,3 @@ pub fn solve(input: &str) -> Result<Solution, IntCodeError> {
 		registration_identifier: format!("{second_try_hull_map}"),
 	})
 }
-
-#[cfg(test)]
-mod tests {
-	use intcode::IntCodeError;
-
-	use super::*;
-	use std::fs;
-
-	#[test]
-	fn part_1_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		assert_eq!(solve(&input)?.first_try_painted_tiles, 2255);
-
-		Ok(())
-	}
-
-	#[test]
-	fn part_2_solution() -> Result<(), IntCodeError> {
-		let input =
-			fs::read_to_string("inputs/personal.txt").expect("Error reading personal input file");
-
-		let expected = r#"
-⬛⬜️⬜️⬜️⬛⬛⬛⬜️⬜️⬛⬛⬜️⬛⬛⬜️⬛⬜️⬜️⬜️⬜️⬛⬜️⬜️⬜️⬛⬛⬛⬜️⬜️⬛⬛⬜️⬜️⬜️⬛⬛⬛⬜️⬜️⬛⬛⬛⬛
-⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛
-⬛⬜️⬜️⬜️⬛⬛⬜️⬛⬛⬛⬛⬜️⬜️⬛⬛⬛⬜️⬜️⬜️⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛
-⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛⬛⬜️⬜️⬜️⬛⬛⬜️⬛⬛⬛⬛⬜️⬜️⬜️⬛⬛⬜️⬜️⬜️⬜️⬛⬛⬛
-⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛⬛⬜️⬛⬛⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬜️⬛⬛⬛
-⬛⬜️⬜️⬜️⬛⬛⬛⬜️⬜️⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬛⬛⬜️⬛⬛⬛⬛⬛⬜️⬜️⬛⬛⬜️⬛⬛⬜️⬛⬜️⬛⬛⬜️⬛⬛⬛
-"#
-		.trim_start()
-		.to_string();
-
-		assert_eq!(solve(&input)?.registration_identifier, expected);
-
-		Ok(())
-	}
-}
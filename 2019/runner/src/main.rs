@@ -0,0 +1,45 @@
+use aoc_cli::Day;
+use std::{process::ExitCode, str};
+
+const DAYS: &[Day] = &[
+	Day::new(
+		1,
+		"The Tyranny of the Rocket Equation",
+		"day-01-the-tyranny-of-the-rocket-equation/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_01::solve(str::from_utf8(input)?)),
+	),
+	Day::new(
+		2,
+		"1202 Program Alarm",
+		"day-02-1202-program-alarm/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_02::solve(str::from_utf8(input)?)?),
+	),
+	Day::new(
+		5,
+		"Sunny with a Chance of Asteroids",
+		"day-05-sunny-with-a-chance-of-asteroids/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_05::solve(str::from_utf8(input)?)?),
+	),
+	Day::new(
+		7,
+		"Amplification Circuit",
+		"day-07-amplification-circuit/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_07::solve(str::from_utf8(input)?)?),
+	),
+	Day::new(
+		9,
+		"Sensor Boost",
+		"day-09-sensor-boost/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_09::solve(str::from_utf8(input)?)?),
+	),
+	Day::new(
+		11,
+		"Space Police",
+		"day-11-space-police/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2019_11::solve(str::from_utf8(input)?)?),
+	),
+];
+
+fn main() -> ExitCode {
+	aoc_cli::main(2019, concat!(env!("CARGO_MANIFEST_DIR"), "/.."), DAYS)
+}
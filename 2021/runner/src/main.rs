@@ -0,0 +1,85 @@
+use aoc2021::*;
+use aoc_cli::Day;
+use std::{process::ExitCode, str};
+
+const DAYS: &[Day] = &[
+	Day::new(1, "Sonar Sweep", "assets/01.txt", |input, _| {
+		aoc_cli::answers(d01::solve(str::from_utf8(input)?))
+	}),
+	Day::new(2, "Dive!", "assets/02.txt", |input, _| {
+		aoc_cli::answers(d02::solve(str::from_utf8(input)?))
+	}),
+	Day::new(3, "Binary Diagnostic", "assets/03.txt", |input, params| {
+		let input = str::from_utf8(input)?;
+		match params.get("bits")? {
+			5 => aoc_cli::answers(d03::solve::<5>(input)),
+			12 => aoc_cli::answers(d03::solve::<12>(input)),
+			bits => Err(format!("unsupported diagnostic width {bits}").into()),
+		}
+	})
+	.with_params(&[("bits", "12")]),
+	Day::new(4, "Giant Squid", "assets/04.txt", |input, _| {
+		aoc_cli::answers(d04::solve(str::from_utf8(input)?))
+	}),
+	Day::new(5, "Hydrothermal Venture", "assets/05.txt", |input, _| {
+		aoc_cli::answers(d05::solve(str::from_utf8(input)?))
+	}),
+	Day::new(6, "Lanternfish", "assets/06.txt", |input, _| {
+		aoc_cli::answers(d06::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(7, "The Treachery of Whales", "assets/07.txt", |input, _| {
+		aoc_cli::answers(d07::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(8, "Seven Segment Search", "assets/08.txt", |input, _| {
+		aoc_cli::answers(d08::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(9, "Smoke Basin", "assets/09.txt", |input, _| {
+		aoc_cli::answers(d09::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(10, "Syntax Scoring", "assets/10.txt", |input, _| {
+		aoc_cli::answers(d10::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(11, "Dumbo Octopus", "assets/11.txt", |input, _| {
+		aoc_cli::answers(d11::solve(str::from_utf8(input)?.trim()))
+	}),
+	Day::new(12, "Passage Pathing", "assets/12.txt", |input, _| {
+		aoc_cli::answers(d12::solve(str::from_utf8(input)?))
+	}),
+	Day::new(13, "Transparent Origami", "assets/13.txt", |input, _| {
+		aoc_cli::answers(d13::solve(str::from_utf8(input)?))
+	}),
+	Day::new(
+		14,
+		"Extended Polymerization",
+		"assets/14.txt",
+		|input, _| aoc_cli::answers(d14::solve(str::from_utf8(input)?)),
+	),
+	Day::new(15, "Chiton", "assets/15.txt", |input, _| {
+		aoc_cli::answers(d15::solve(str::from_utf8(input)?))
+	}),
+	Day::new(16, "Packet Decoder", "assets/16.txt", |input, _| {
+		aoc_cli::answers(d16::solve(str::from_utf8(input)?))
+	}),
+	Day::new(17, "Trick Shot", "assets/17.txt", |input, _| {
+		aoc_cli::answers(d17::solve(str::from_utf8(input)?))
+	}),
+	Day::new(18, "Snailfish", "assets/18.txt", |input, _| {
+		aoc_cli::answers(d18::solve(str::from_utf8(input)?))
+	}),
+	Day::new(19, "Beacon Scanner", "assets/19.txt", |input, _| {
+		aoc_cli::answers(d19::solve(str::from_utf8(input)?))
+	}),
+	Day::new(20, "Trench Map", "assets/20.txt", |input, _| {
+		aoc_cli::answers(d20::solve(str::from_utf8(input)?))
+	}),
+	Day::new(21, "Dirac Dice", "assets/21.txt", |input, _| {
+		aoc_cli::answers(d21::solve(str::from_utf8(input)?))
+	}),
+	Day::new(22, "Reactor Reboot", "assets/22.txt", |input, _| {
+		aoc_cli::answers(d22::solve(str::from_utf8(input)?))
+	}),
+];
+
+fn main() -> ExitCode {
+	aoc_cli::main(2021, concat!(env!("CARGO_MANIFEST_DIR"), "/.."), DAYS)
+}
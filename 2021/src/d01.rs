@@ -1,8 +1,18 @@
+#[derive(Debug)]
 pub struct Solution {
 	depth_increases: u128,
 	average_depth_increases: u128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u128;
+	type Part2 = u128;
+
+	fn into_parts(self) -> (u128, u128) {
+		(self.depth_increases, self.average_depth_increases)
+	}
+}
+
 pub fn solve(input: &str) -> Solution {
 	let measurements: Vec<_> = input.lines().map(|l| l.parse::<i128>().unwrap()).collect();
 
@@ -35,7 +45,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -44,24 +53,10 @@ mod tests {
 		assert_eq!(solve(example).depth_increases, 7)
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/01.txt").unwrap();
-
-		assert_eq!(solve(&input).depth_increases, 1342);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
 
 		assert_eq!(solve(example).average_depth_increases, 5)
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/01.txt").unwrap();
-
-		assert_eq!(solve(&input).average_depth_increases, 1378);
-	}
 }
@@ -1,12 +1,22 @@
 use anyhow::{bail, Error, Result};
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	depth: i128,
 	distance: i128,
 	aimed_depth: i128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i128;
+	type Part2 = i128;
+
+	fn into_parts(self) -> (i128, i128) {
+		(self.distance * self.depth, self.distance * self.aimed_depth)
+	}
+}
+
 enum Move {
 	Forward(i128),
 	Down(i128),
@@ -82,7 +92,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -94,17 +103,6 @@ mod tests {
 		assert_eq!(solution.distance * solution.depth, 150);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/02.txt").unwrap();
-
-		let solution = solve(&input);
-
-		assert_eq!(solution.distance, 2083);
-		assert_eq!(solution.depth, 955);
-		assert_eq!(solution.distance * solution.depth, 1989265);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
@@ -114,15 +112,4 @@ mod tests {
 		assert_eq!(solution.aimed_depth, 60);
 		assert_eq!(solution.distance * solution.aimed_depth, 900);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/02.txt").unwrap();
-
-		let solution = solve(&input);
-
-		assert_eq!(solution.distance, 2083);
-		assert_eq!(solution.aimed_depth, 1002964);
-		assert_eq!(solution.distance * solution.aimed_depth, 2089174012);
-	}
 }
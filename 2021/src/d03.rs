@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use anyhow::{Error, Result};
 
+#[derive(Debug)]
 pub struct Solution {
 	gamma: i128,
 	epsilon: i128,
@@ -9,6 +10,18 @@ pub struct Solution {
 	co2_scrubber_rating: i128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i128;
+	type Part2 = i128;
+
+	fn into_parts(self) -> (i128, i128) {
+		(
+			self.gamma * self.epsilon,
+			self.oxygen_generator_rating * self.co2_scrubber_rating,
+		)
+	}
+}
+
 #[derive(Debug, Clone)]
 struct Diagnostic<const N: usize>([i128; N]);
 
@@ -71,14 +84,11 @@ where
 
 		let comparison_bit = predicate_get_comparison_bit(leftover_len, bit_sum);
 
-		diagnostics = diagnostics
-			.into_iter()
-			.filter(|Diagnostic(dia)| dia[bit] == comparison_bit)
-			.collect();
+		diagnostics.retain(|Diagnostic(dia)| dia[bit] == comparison_bit);
 	}
 	assert_eq!(diagnostics.len(), 1);
 
-	diagnostics.get(0).unwrap().to_decimal()
+	diagnostics.first().unwrap().to_decimal()
 }
 
 impl<const N: usize> DiagnosticCollection<N> {
@@ -156,7 +166,6 @@ pub fn solve<const N: usize>(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -169,17 +178,6 @@ mod tests {
 		assert_eq!(solution.gamma * solution.epsilon, 198);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/03.txt").unwrap();
-
-		let solution = solve::<12>(&input);
-
-		assert_eq!(solution.gamma, 2601);
-		assert_eq!(solution.epsilon, 1494);
-		assert_eq!(solution.gamma * solution.epsilon, 3885894);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example =
@@ -193,18 +191,4 @@ mod tests {
 			230
 		);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/03.txt").unwrap();
-
-		let solution = solve::<12>(&input);
-
-		assert_eq!(solution.oxygen_generator_rating, 3775);
-		assert_eq!(solution.co2_scrubber_rating, 1159);
-		assert_eq!(
-			solution.oxygen_generator_rating * solution.co2_scrubber_rating,
-			4375225
-		);
-	}
 }
@@ -1,11 +1,21 @@
 use anyhow::{Error, Result};
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	first_win_score: u128,
 	last_win_score: u128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u128;
+	type Part2 = u128;
+
+	fn into_parts(self) -> (u128, u128) {
+		(self.first_win_score, self.last_win_score)
+	}
+}
+
 const BOARD_SIZE: usize = 5;
 
 struct BingoCard {
@@ -146,7 +156,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -175,15 +184,6 @@ mod tests {
 		assert_eq!(solution.first_win_score, 4512);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/04.txt").unwrap();
-
-		let solution = solve(&input);
-
-		assert_eq!(solution.first_win_score, 28082);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
@@ -209,13 +209,4 @@ mod tests {
 
 		assert_eq!(solution.last_win_score, 1924);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/04.txt").unwrap();
-
-		let solution = solve(&input);
-
-		assert_eq!(solution.last_win_score, 8224);
-	}
 }
@@ -3,11 +3,21 @@ use anyhow::Error;
 use hashbrown::HashSet;
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	straight_intersections: usize,
 	all_intersections: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.straight_intersections, self.all_intersections)
+	}
+}
+
 #[derive(Clone, Debug)]
 struct Line {
 	start: (u16, u16),
@@ -139,7 +149,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -148,24 +157,10 @@ mod tests {
 		assert_eq!(solve(example).straight_intersections, 5);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/05.txt").unwrap();
-
-		assert_eq!(solve(&input).straight_intersections, 6666);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2";
 
 		assert_eq!(solve(example).all_intersections, 12);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/05.txt").unwrap();
-
-		assert_eq!(solve(&input).all_intersections, 19081);
-	}
 }
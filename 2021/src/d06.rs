@@ -2,11 +2,21 @@ use std::{collections::VecDeque, str::FromStr};
 
 use anyhow::Error;
 
+#[derive(Debug)]
 pub struct Solution {
 	fish_count_80: u128,
 	fish_count_256: u128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u128;
+	type Part2 = u128;
+
+	fn into_parts(self) -> (u128, u128) {
+		(self.fish_count_80, self.fish_count_256)
+	}
+}
+
 struct Population {
 	by_inv: VecDeque<u128>,
 }
@@ -70,29 +80,14 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
 		assert_eq!(solve("3,4,3,1,2").fish_count_80, 5934);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/06.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).fish_count_80, 360268);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("3,4,3,1,2").fish_count_256, 26984457539);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/06.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).fish_count_256, 1632146183902);
-	}
 }
@@ -1,8 +1,18 @@
+#[derive(Debug)]
 pub struct Solution {
 	lowest_cost_misunderstood: i32,
 	lowest_cost_understood: i32,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i32;
+	type Part2 = i32;
+
+	fn into_parts(self) -> (i32, i32) {
+		(self.lowest_cost_misunderstood, self.lowest_cost_understood)
+	}
+}
+
 fn fuel_sum(num: i32) -> i32 {
 	// Naive approach:
 	// (0..=of).into_iter().sum()
@@ -41,29 +51,14 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
 		assert_eq!(solve("16,1,2,0,4,2,7,1,2,14").lowest_cost_misunderstood, 37);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/07.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).lowest_cost_misunderstood, 331067);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("16,1,2,0,4,2,7,1,2,14").lowest_cost_understood, 168);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/07.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).lowest_cost_understood, 92881128);
-	}
 }
@@ -1,10 +1,20 @@
 use hashbrown::HashSet;
 
+#[derive(Debug)]
 pub struct Solution {
 	easy_digits: u32,
 	outputs_sum: u32,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u32;
+	type Part2 = u32;
+
+	fn into_parts(self) -> (u32, u32) {
+		(self.easy_digits, self.outputs_sum)
+	}
+}
+
 #[derive(PartialEq)]
 struct Digit {
 	segments: HashSet<char>,
@@ -118,24 +128,10 @@ mod tests {
 		assert_eq!(solve(input.trim()).easy_digits, 26);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/08.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).easy_digits, 473);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/08_sample.txt").unwrap();
 
 		assert_eq!(solve(input.trim()).outputs_sum, 61229);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/08.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).outputs_sum, 1097568);
-	}
 }
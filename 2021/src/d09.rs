@@ -1,10 +1,20 @@
 use hashbrown::HashSet;
 
+#[derive(Debug)]
 pub struct Solution {
 	risk_level_sum: u32,
 	top_3_multiplied: u128,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u32;
+	type Part2 = u128;
+
+	fn into_parts(self) -> (u32, u128) {
+		(self.risk_level_sum, self.top_3_multiplied)
+	}
+}
+
 const MAX_LEVEL: u8 = 9;
 
 struct Surroundings {
@@ -127,7 +137,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -141,13 +150,6 @@ mod tests {
 		assert_eq!(solve(example.trim()).risk_level_sum, 15);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/09.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).risk_level_sum, 603);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = r#"
@@ -159,11 +161,4 @@ mod tests {
 "#;
 		assert_eq!(solve(example.trim()).top_3_multiplied, 1134);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/09.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).top_3_multiplied, 786780);
-	}
 }
@@ -1,8 +1,18 @@
+#[derive(Debug)]
 pub struct Solution {
 	corrupted_score: usize,
 	unclosed_middle_score: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.corrupted_score, self.unclosed_middle_score)
+	}
+}
+
 enum LineScore {
 	Corrupted(usize),
 	Unclosed(usize),
@@ -75,7 +85,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -94,13 +103,6 @@ mod tests {
 		assert_eq!(solve(example.trim()).corrupted_score, 26397);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/10.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).corrupted_score, 271245);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = r#"
@@ -117,11 +119,4 @@ mod tests {
 "#;
 		assert_eq!(solve(example.trim()).unclosed_middle_score, 288957);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/10.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).unclosed_middle_score, 1685293086);
-	}
 }
@@ -1,8 +1,18 @@
+#[derive(Debug)]
 pub struct Solution {
 	total_flashes_100: usize,
 	first_mega_flash: Option<usize>,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = Option<usize>;
+
+	fn into_parts(self) -> (usize, Option<usize>) {
+		(self.total_flashes_100, self.first_mega_flash)
+	}
+}
+
 fn count_octopile(octopi: &[u8], x_size: usize, xy: usize) -> u8 {
 	let cur_x = xy % x_size;
 	// has top
@@ -92,7 +102,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -112,13 +121,6 @@ mod tests {
 		assert_eq!(solve(big.trim()).total_flashes_100, 1656);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/11.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).total_flashes_100, 1655);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let big = r#"
@@ -136,11 +138,4 @@ mod tests {
 
 		assert_eq!(solve(big.trim()).first_mega_flash, Some(195));
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/11.txt").unwrap();
-
-		assert_eq!(solve(input.trim()).first_mega_flash, Some(337));
-	}
 }
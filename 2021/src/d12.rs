@@ -1,10 +1,20 @@
 use hashbrown::{HashMap, HashSet};
 
+#[derive(Debug)]
 pub struct Solution {
 	cave_paths: usize,
 	cave_paths_with_extra_peek: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.cave_paths, self.cave_paths_with_extra_peek)
+	}
+}
+
 struct Cave {
 	big_outs: Vec<String>,
 	small_outs: Vec<String>,
@@ -112,7 +122,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -136,13 +145,6 @@ mod tests {
 		);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/12.txt").unwrap();
-
-		assert_eq!(solve(&input).cave_paths, 3887);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(
@@ -160,11 +162,4 @@ mod tests {
 			3509
 		);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/12.txt").unwrap();
-
-		assert_eq!(solve(&input).cave_paths_with_extra_peek, 104834);
-	}
 }
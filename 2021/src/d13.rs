@@ -1,10 +1,20 @@
 use hashbrown::HashSet;
 
+#[derive(Debug)]
 pub struct Solution {
 	points_first_fold: usize,
 	code_art: String,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = String;
+
+	fn into_parts(self) -> (usize, String) {
+		(self.points_first_fold, self.code_art)
+	}
+}
+
 #[derive(Clone)]
 struct Fold {
 	is_x: bool,
@@ -112,7 +122,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -142,13 +151,6 @@ fold along x=5
 		assert_eq!(solve(example.trim()).points_first_fold, 17);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/13.txt").unwrap();
-
-		assert_eq!(solve(&input).points_first_fold, 592);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let example = r#"
@@ -185,20 +187,4 @@ fold along x=5
 
 		assert_eq!(solve(example.trim()).code_art.trim(), expected.trim());
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/13.txt").unwrap();
-
-		let expected = r#"
-░░██░░██░░░██░░░░██░████░████░█░░█░█░░█
-░░░█░█░░█░█░░█░░░░█░█░░░░█░░░░█░█░░█░░█
-░░░█░█░░░░█░░█░░░░█░███░░███░░██░░░█░░█
-░░░█░█░██░████░░░░█░█░░░░█░░░░█░█░░█░░█
-█░░█░█░░█░█░░█░█░░█░█░░░░█░░░░█░█░░█░░█
-░██░░░███░█░░█░░██░░████░█░░░░█░░█░░██░
-"#;
-
-		assert_eq!(solve(&input).code_art.trim(), expected.trim());
-	}
 }
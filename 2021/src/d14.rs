@@ -1,10 +1,20 @@
 use itertools::Itertools;
 
+#[derive(Debug)]
 pub struct Solution {
 	extreme_element_diff_10: usize,
 	extreme_element_diff_40: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.extreme_element_diff_10, self.extreme_element_diff_40)
+	}
+}
+
 // convert uppercase ascii char to 0..25
 fn get_char_code(c: char) -> u8 {
 	// debug_assert!(c.is_ascii_alphabetic() && c.is_ascii_uppercase());
@@ -103,24 +113,10 @@ mod tests {
 		assert_eq!(solve(&input).extreme_element_diff_10, 1588);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/14.txt").unwrap();
-
-		assert_eq!(solve(&input).extreme_element_diff_10, 3697);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/14_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).extreme_element_diff_40, 2188189693529);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/14.txt").unwrap();
-
-		assert_eq!(solve(&input).extreme_element_diff_40, 4371307836157);
-	}
 }
@@ -1,8 +1,21 @@
+#[derive(Debug)]
 pub struct Solution {
 	small_cave_lowest_path_sum: usize,
 	huge_cave_lowest_path_sum: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(
+			self.small_cave_lowest_path_sum,
+			self.huge_cave_lowest_path_sum,
+		)
+	}
+}
+
 // How many "crossroads" where paths split are considered per iteration
 const TRY_LENGTH: usize = 2;
 // How many of the paths we tried are allowed to continue for the next iteration
@@ -114,12 +127,12 @@ fn get_chiton_approved_path(cave_map: Vec<u8>, x_size: usize, y_size: usize, end
 			paths,
 			end,
 			x_size,
-			finished_paths.get(0).map(|p| p.total_score),
+			finished_paths.first().map(|p| p.total_score),
 		);
 	}
 
 	finished_paths.sort_by_key(|path| path.total_score);
-	let winner = finished_paths.get(0).unwrap();
+	let winner = finished_paths.first().unwrap();
 
 	winner.total_score
 }
@@ -179,24 +192,10 @@ mod tests {
 		assert_eq!(solve(&input).small_cave_lowest_path_sum, 40);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/15.txt").unwrap();
-
-		assert_eq!(solve(&input).small_cave_lowest_path_sum, 363);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/15_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).huge_cave_lowest_path_sum, 315);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/15.txt").unwrap();
-
-		assert_eq!(solve(&input).huge_cave_lowest_path_sum, 2835);
-	}
 }
@@ -1,10 +1,20 @@
 use itertools::Itertools;
 
+#[derive(Debug)]
 pub struct Solution {
 	version_sum: usize,
 	value: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.version_sum, self.value)
+	}
+}
+
 struct BitStream {
 	bytes_stack: Vec<u8>,
 	byte_index: u8, //0..7 (getting to 8 resets to 0)
@@ -179,13 +189,13 @@ impl Packet {
 				OpKind::Minimum => packets.iter().map(|p| p.value()).min().unwrap(),
 				OpKind::Maximum => packets.iter().map(|p| p.value()).max().unwrap(),
 				OpKind::GreaterThan => {
-					(packets.get(0).unwrap().value() > packets.get(1).unwrap().value()) as usize
+					(packets.first().unwrap().value() > packets.get(1).unwrap().value()) as usize
 				}
 				OpKind::LessThan => {
-					(packets.get(0).unwrap().value() < packets.get(1).unwrap().value()) as usize
+					(packets.first().unwrap().value() < packets.get(1).unwrap().value()) as usize
 				}
 				OpKind::EqualTo => {
-					(packets.get(0).unwrap().value() == packets.get(1).unwrap().value()) as usize
+					(packets.first().unwrap().value() == packets.get(1).unwrap().value()) as usize
 				}
 			},
 		}
@@ -206,7 +216,6 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
@@ -220,13 +229,6 @@ mod tests {
 		assert_eq!(solve("A0016C880162017C3686B18A3D4780").version_sum, 31);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/16.txt").unwrap();
-
-		assert_eq!(solve(&input).version_sum, 877);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(solve("D2FE28").value, 2021);
@@ -242,11 +244,4 @@ mod tests {
 		assert_eq!(solve("9C005AC2F8F0").value, 0);
 		assert_eq!(solve("9C0141080250320F1802104A08").value, 1);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/16.txt").unwrap();
-
-		assert_eq!(solve(&input).value, 194435634456);
-	}
 }
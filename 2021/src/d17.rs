@@ -1,10 +1,20 @@
 use std::str::FromStr;
 
+#[derive(Debug)]
 pub struct Solution {
 	y_max: i32,
 	possible_velocities: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = i32;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (i32, usize) {
+		(self.y_max, self.possible_velocities)
+	}
+}
+
 fn parse_range(range_s: &str) -> (i32, i32) {
 	let (min_s, max_s) = range_s.split_once("..").unwrap();
 	let min = i32::from_str(min_s).unwrap();
@@ -122,20 +132,12 @@ pub fn solve(input: &str) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::fs;
 
 	#[test]
 	fn part_1_example_cases() {
 		assert_eq!(solve("target area: x=20..30, y=-10..-5").y_max, 45);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/17.txt").unwrap();
-
-		assert_eq!(solve(&input).y_max, 10296);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		assert_eq!(
@@ -143,11 +145,4 @@ mod tests {
 			112
 		);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/17.txt").unwrap();
-
-		assert_eq!(solve(&input).possible_velocities, 2371);
-	}
 }
@@ -2,11 +2,21 @@ use std::{iter::Peekable, ops::Add, str::Chars};
 
 use itertools::Itertools;
 
+#[derive(Debug)]
 pub struct Solution {
 	sum_magnitude: usize,
 	highest_sum_magnitude: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.sum_magnitude, self.highest_sum_magnitude)
+	}
+}
+
 #[derive(Clone)]
 enum SnailValue {
 	Num(usize),
@@ -292,24 +302,10 @@ mod tests {
 		assert_eq!(solve(&input).sum_magnitude, 4140);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/18.txt").unwrap();
-
-		assert_eq!(solve(&input).sum_magnitude, 4433);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/18_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).highest_sum_magnitude, 3993);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/18.txt").unwrap();
-
-		assert_eq!(solve(&input).highest_sum_magnitude, 4559);
-	}
 }
@@ -3,11 +3,21 @@ use std::ops::{Add, AddAssign, Sub};
 use hashbrown::HashSet;
 use itertools::Itertools;
 
+#[derive(Debug)]
 pub struct Solution {
 	beacons: usize,
 	biggest_scanner_distance: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.beacons, self.biggest_scanner_distance)
+	}
+}
+
 type Coordinate = i32;
 type CoordinateScalar = i32; // for less casting
 
@@ -274,24 +284,10 @@ mod tests {
 		assert_eq!(solve(&input).beacons, 79);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/19.txt").unwrap();
-
-		assert_eq!(solve(&input).beacons, 451);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/19_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).biggest_scanner_distance, 3621);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/19.txt").unwrap();
-
-		assert_eq!(solve(&input).biggest_scanner_distance, 13184);
-	}
 }
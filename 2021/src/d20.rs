@@ -2,11 +2,21 @@ use std::collections::VecDeque;
 
 use itertools::Itertools;
 
+#[derive(Debug)]
 pub struct Solution {
 	lit_pixels_2: usize,
 	lit_pixels_50: usize,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.lit_pixels_2, self.lit_pixels_50)
+	}
+}
+
 type Pixels = VecDeque<VecDeque<bool>>;
 
 struct Image {
@@ -145,24 +155,10 @@ mod tests {
 		assert_eq!(solve(&input).lit_pixels_2, 35);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/20.txt").unwrap();
-
-		assert_eq!(solve(&input).lit_pixels_2, 5819);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/20_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).lit_pixels_50, 3351);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/20.txt").unwrap();
-
-		assert_eq!(solve(&input).lit_pixels_50, 18516);
-	}
 }
@@ -6,11 +6,21 @@ use std::{
 use anyhow::{anyhow, Error};
 use hashbrown::HashMap;
 
+#[derive(Debug)]
 pub struct Solution {
 	with_deterministic_die: usize,
 	with_dirac_die: u64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (usize, u64) {
+		(self.with_deterministic_die, self.with_dirac_die)
+	}
+}
+
 struct DeterministicPlayer {
 	field: u8, // 0-based (Fields 1..10)
 	score: u16,
@@ -132,10 +142,7 @@ fn dirac_round(memo: &mut DiracMemo, p1: DiracPlayer, p2: DiracPlayer) -> Univer
 
 pub fn solve(input: &str) -> Solution {
 	let mut die_rolls = 0;
-	let mut deterministic_die = (1..=100_u16).cycle().map(|roll| {
-		die_rolls += 1;
-		roll
-	});
+	let mut deterministic_die = (1..=100_u16).cycle().inspect(|_| die_rolls += 1);
 
 	let mut players = input.lines().map(DeterministicPlayer::from_str);
 	let mut p1 = players.next().unwrap().unwrap();
@@ -193,24 +200,10 @@ mod tests {
 		assert_eq!(solve(&input).with_deterministic_die, 739785);
 	}
 
-	#[test]
-	fn part_1_solution() {
-		let input = fs::read_to_string("assets/21.txt").unwrap();
-
-		assert_eq!(solve(&input).with_deterministic_die, 1073709);
-	}
-
 	#[test]
 	fn part_2_example_cases() {
 		let input = fs::read_to_string("assets/21_sample.txt").unwrap();
 
 		assert_eq!(solve(&input).with_dirac_die, 444356092776315);
 	}
-
-	#[test]
-	fn part_2_solution() {
-		let input = fs::read_to_string("assets/21.txt").unwrap();
-
-		assert_eq!(solve(&input).with_dirac_die, 148747830493442);
-	}
 }
@@ -1,10 +1,20 @@
 use std::ops::RangeInclusive;
 
+#[derive(Debug)]
 pub struct Solution {
 	cubes_on_in_center: u64,
 	cubes_on: u64,
 }
 
+impl aoc_solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.cubes_on_in_center, self.cubes_on)
+	}
+}
+
 #[derive(Clone)]
 struct RebootStep {
 	on: bool,
@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::hint::black_box;
 use std::include_str;
@@ -31,6 +32,32 @@ fn criterion_benchmark(c: &mut Criterion) {
 				aoc_2022_01::solve_loop(black_box(file))
 			})
 		});
+
+		for (phase, _) in phase::record(|| aoc_2022_01::solve_iterators(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_iterators phase {phase}"), lines),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || {
+							aoc_2022_01::solve_iterators(black_box(file))
+						})
+					})
+				},
+			);
+		}
+
+		for (phase, _) in phase::record(|| aoc_2022_01::solve_loop(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_loop phase {phase}"), lines),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || aoc_2022_01::solve_loop(black_box(file)))
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
@@ -1,7 +1,19 @@
+use aoc_2022_utils::phase::span;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 pub fn solve_iterators(input: &str) -> Solution {
+	let parsing = span("parse");
 	let mut elves: Vec<u64> = input
 		.trim()
 		.split("\n\n")
@@ -15,7 +27,9 @@ pub fn solve_iterators(input: &str) -> Solution {
 				.sum()
 		})
 		.collect();
+	parsing.end();
 
+	let _sorting = span("sort");
 	elves.sort_unstable();
 
 	let max_energy = elves.iter().rev().take(1).sum();
@@ -25,6 +39,7 @@ pub fn solve_iterators(input: &str) -> Solution {
 }
 
 pub fn solve_loop(input: &str) -> Solution {
+	let parsing = span("parse");
 	let mut elves: Vec<u64> = vec![];
 	let mut reading_elf = false;
 
@@ -46,7 +61,9 @@ pub fn solve_loop(input: &str) -> Solution {
 
 		*elf += calories;
 	}
+	parsing.end();
 
+	let _sorting = span("sort");
 	elves.sort_unstable();
 
 	let max_calories: u64 = elves.iter().rev().take(1).sum();
@@ -63,7 +80,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_str!("../inputs/example.txt"), Solution(24000, 45000))]
-	#[case(include_str!("../inputs/personal.txt"), Solution(74198,209914))]
 	fn solution(
 		#[case] input: &str,
 		#[case] expected: Solution,
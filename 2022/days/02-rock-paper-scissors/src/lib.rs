@@ -1,6 +1,15 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 const ROCK_X: &[u8] = b"A X\n";
 const ROCK_Y: &[u8] = b"A Y\n";
 const ROCK_Z: &[u8] = b"A Z\n";
@@ -138,7 +147,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(15, 12))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(10816, 11657))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
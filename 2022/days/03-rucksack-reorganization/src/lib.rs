@@ -1,6 +1,15 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 const LINE_SPLIT: u8 = b'\n';
 
 const UPPERCASE_START: u8 = b'A' - 1;
@@ -22,7 +31,7 @@ const fn priority_flag_to_priority(flag: u64) -> u64 {
 fn get_compartment_set(compartment: &[u8]) -> u64 {
 	compartment
 		.iter()
-		.fold(0_u64, |set, item| (set | item_to_priority_flag(item)))
+		.fold(0_u64, |set, item| set | item_to_priority_flag(item))
 }
 
 pub fn solve(input: &[u8]) -> Solution {
@@ -64,7 +73,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(157, 70))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(7446, 2646))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
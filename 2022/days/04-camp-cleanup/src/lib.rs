@@ -1,8 +1,18 @@
+use aoc_2022_utils::phase::span;
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 struct SectionRange(u8, u8);
 
 impl FromStr for SectionRange {
@@ -52,13 +62,16 @@ impl AssignmentPair {
 }
 
 pub fn solve(input: &str) -> Solution {
+	let parsing = span("parse");
 	let mut pairs: Vec<_> = input
 		.trim()
 		.lines()
 		.map(AssignmentPair::from_str)
 		.map(|pair| pair.expect("malformed input line"))
 		.collect();
+	parsing.end();
 
+	let _overlaps = span("overlaps");
 	pairs.retain(AssignmentPair::has_overlap);
 
 	let overlap_pairs_count = pairs.len() as u64;
@@ -78,7 +91,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_str!("../inputs/example.txt"), Solution(2, 4))]
-	#[case(include_str!("../inputs/personal.txt"), Solution(433, 852))]
 	fn solution(#[case] input: &str, #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::hint::black_box;
 
@@ -22,6 +23,32 @@ fn criterion_benchmark(c: &mut Criterion) {
 			let byte_input = file.as_bytes();
 			b.iter(|| aoc_2022_05::bytes::solve(black_box(byte_input)))
 		});
+
+		for (phase, _) in phase::record(|| aoc_2022_05::string::solve(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_string phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || aoc_2022_05::string::solve(black_box(file)))
+					})
+				},
+			);
+		}
+
+		for (phase, _) in phase::record(|| aoc_2022_05::bytes::solve(input.as_bytes())) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_bytes phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || {
+							aoc_2022_05::bytes::solve(black_box(file).as_bytes())
+						})
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase::span;
 use core::slice;
 
 use crate::Solution;
@@ -36,6 +37,7 @@ fn instruction_9001(stacks: &mut [Vec<u8>; 9], amt: usize, from: usize, to: usiz
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut stacks_9000: [Vec<u8>; 9] = Default::default();
 
 	let split_point = input
@@ -56,6 +58,9 @@ pub fn solve(input: &[u8]) -> Solution {
 	}
 
 	let mut stacks_9001 = stacks_9000.clone();
+	parsing.end();
+
+	let _moving = span("moves");
 
 	let mut instructions = bottom
 		.split(|b| *b == b'\n' || *b == b' ')
@@ -90,7 +95,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution("CMZ".into(),"MCD".into()))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution("DHBJQJCCW".into(), "WJVRLSJJT".into()))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
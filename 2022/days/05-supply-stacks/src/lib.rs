@@ -3,3 +3,12 @@ pub mod string;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(String, String);
+
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = String;
+	type Part2 = String;
+
+	fn into_parts(self) -> (String, String) {
+		(self.0, self.1)
+	}
+}
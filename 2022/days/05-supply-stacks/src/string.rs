@@ -1,6 +1,9 @@
+use aoc_2022_utils::phase::span;
+
 use crate::Solution;
 
 pub fn solve(input: &str) -> Solution {
+	let parsing = span("parse");
 	let mut stacks_9000: Vec<Vec<char>> = vec![vec![]; 9];
 
 	let (init, moves) = input
@@ -17,6 +20,9 @@ pub fn solve(input: &str) -> Solution {
 	}
 
 	let mut stacks_9001 = stacks_9000.clone();
+	parsing.end();
+
+	let _moving = span("moves");
 
 	for line in moves.trim().lines() {
 		let parts: Vec<_> = line.split(' ').collect();
@@ -62,7 +68,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_str!("../inputs/example.txt"), Solution("CMZ".into(),"MCD".into()))]
-	#[case(include_str!("../inputs/personal.txt"), Solution("DHBJQJCCW".into(), "WJVRLSJJT".into()))]
 	fn solution(#[case] input: &str, #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
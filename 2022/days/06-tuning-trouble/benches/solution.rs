@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::hint::black_box;
 
@@ -23,6 +24,34 @@ fn criterion_benchmark(c: &mut Criterion) {
 		group.bench_with_input(BenchmarkId::new("solve_loop", bytes), input, |b, file| {
 			b.iter(|| aoc_2022_06::solve_loop::solve_loop(black_box(file)))
 		});
+
+		for (phase, _) in phase::record(|| aoc_2022_06::solve_hashset::solve_hashset(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_hashset phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || {
+							aoc_2022_06::solve_hashset::solve_hashset(black_box(file))
+						})
+					})
+				},
+			);
+		}
+
+		for (phase, _) in phase::record(|| aoc_2022_06::solve_loop::solve_loop(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("solve_loop phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || {
+							aoc_2022_06::solve_loop::solve_loop(black_box(file))
+						})
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
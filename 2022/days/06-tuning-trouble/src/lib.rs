@@ -4,6 +4,15 @@ pub mod solve_loop;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -15,7 +24,6 @@ mod tests {
 	#[case(include_bytes!("../inputs/example_7.txt"), Solution(7,19))]
 	#[case(include_bytes!("../inputs/example_10.txt"), Solution(10,29))]
 	#[case(include_bytes!("../inputs/example_11.txt"), Solution(11,26))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(1142,2803))]
 	fn solution(
 		#[case] input: &[u8],
 		#[case] expected: Solution,
@@ -1,4 +1,5 @@
 use crate::Solution;
+use aoc_2022_utils::phase::span;
 use std::collections::HashSet;
 
 fn has_duplicates(set: &mut HashSet<u8>, values: &[u8]) -> bool {
@@ -34,10 +35,14 @@ fn count_until_n_different_hashset<const N: usize>(bytes: &[u8]) -> usize {
 }
 
 pub fn solve_hashset(input: &[u8]) -> Solution {
+	let packet = span("start of packet");
 	let until_sop_marker = count_until_n_different_hashset::<4>(input);
+	packet.end();
 	// we can utilise that we already know that before this there cannot be 14 equal ones
 
+	let message = span("start of message");
 	let until_som_marker = count_until_n_different_hashset::<14>(&input[until_sop_marker..]);
+	message.end();
 
 	let sop_bytes_read = until_sop_marker + 4;
 	let som_bytes_read = until_som_marker + until_sop_marker + 14;
@@ -1,3 +1,5 @@
+use aoc_2022_utils::phase::span;
+
 use crate::Solution;
 
 fn count_until_n_different_loop<const N: usize>(bytes: &[u8]) -> usize {
@@ -18,10 +20,14 @@ fn count_until_n_different_loop<const N: usize>(bytes: &[u8]) -> usize {
 }
 
 pub fn solve_loop(input: &[u8]) -> Solution {
+	let packet = span("start of packet");
 	let until_sop_marker = count_until_n_different_loop::<4>(input);
+	packet.end();
 	// we can utilise that we already know that before this there cannot be 14 equal ones
 
+	let message = span("start of message");
 	let until_som_marker = count_until_n_different_loop::<14>(&input[until_sop_marker..]);
+	message.end();
 
 	let sop_bytes_read = until_sop_marker + 4;
 	let som_bytes_read = until_som_marker + until_sop_marker + 14;
@@ -1,6 +1,17 @@
+use aoc_2022_utils::phase::span;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 struct Directory<'i> {
 	name: &'i [u8],
 	dirs: Vec<Directory<'i>>,
@@ -160,11 +171,14 @@ impl<'i> From<&'i [u8]> for Command<'i> {
 }
 
 pub fn solve<'i>(input: &'i [u8]) -> Solution {
+	let commands = span("commands");
 	let mut root: Directory<'i> = Directory::new_root();
 
 	let halt = root.execute_commands(input);
 	assert_eq!(halt, HaltReason::Done);
+	commands.end();
 
+	let _sizes = span("sizes");
 	root.update_total_size();
 
 	assert!(root.total_size < 70_000_000);
@@ -191,7 +205,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(95437,24933642))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(1581595,1544176))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
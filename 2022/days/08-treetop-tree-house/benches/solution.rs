@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::hint::black_box;
 
@@ -17,6 +18,18 @@ fn criterion_benchmark(c: &mut Criterion) {
 		group.bench_with_input(BenchmarkId::new("solve", bytes), input, |b, file| {
 			b.iter(|| aoc_2022_08::solve(black_box(file)))
 		});
+
+		for (phase, _) in phase::record(|| aoc_2022_08::solve(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || aoc_2022_08::solve(black_box(file)))
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
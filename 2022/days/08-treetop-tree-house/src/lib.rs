@@ -1,8 +1,14 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
-#[derive(Debug, PartialEq, Eq)]
-struct Point(usize, usize);
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
 
 fn tree_outlook<'a>(
 	tree: &'a u8,
@@ -97,7 +103,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(21,8))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(1705,371200))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -3,6 +3,15 @@ use std::ops::{Add, AddAssign, Sub};
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 mod assumptions {
 	pub type GridInt = i16;
 	pub type GridUint = u16;
@@ -198,7 +207,6 @@ mod tests {
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(13,1))]
 	#[case(include_bytes!("../inputs/example_large.txt"), Solution(88,36))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(6367,2536))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
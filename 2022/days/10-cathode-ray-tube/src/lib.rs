@@ -3,6 +3,15 @@ use std::ops::RangeInclusive;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, String);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = String;
+
+	fn into_parts(self) -> (usize, String) {
+		(self.0, self.1)
+	}
+}
+
 const NUMBERS_START: u8 = b'0';
 fn parse_usize(b: &[u8]) -> usize {
 	let mut num = (b[0] - NUMBERS_START) as usize;
@@ -120,17 +129,8 @@ mod tests {
 ██████┄┄┄┄┄┄██████┄┄┄┄┄┄██████┄┄┄┄┄┄████
 ███████┄┄┄┄┄┄┄███████┄┄┄┄┄┄┄███████┄┄┄┄┄"#;
 
-	const SOLUTION_SCREEN: &str = r#"
-████┄┄██┄┄█┄┄┄┄█┄┄█┄███┄┄█┄┄┄┄████┄┄┄██┄
-█┄┄┄┄█┄┄█┄█┄┄┄┄█┄┄█┄█┄┄█┄█┄┄┄┄█┄┄┄┄┄┄┄█┄
-███┄┄█┄┄┄┄█┄┄┄┄████┄███┄┄█┄┄┄┄███┄┄┄┄┄█┄
-█┄┄┄┄█┄██┄█┄┄┄┄█┄┄█┄█┄┄█┄█┄┄┄┄█┄┄┄┄┄┄┄█┄
-█┄┄┄┄█┄┄█┄█┄┄┄┄█┄┄█┄█┄┄█┄█┄┄┄┄█┄┄┄┄█┄┄█┄
-████┄┄███┄████┄█┄┄█┄███┄┄████┄█┄┄┄┄┄██┄┄"#;
-
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(13140, EXAMPLE_SCREEN.trim().to_string()))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(13920, SOLUTION_SCREEN.trim().to_string()))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -1,9 +1,19 @@
 // use hashbrown::HashMap;
-use std::iter::repeat;
+use aoc_2022_utils::phase::span;
+use std::iter::repeat_n;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 // Assumptions
 type Worry = usize;
 
@@ -108,6 +118,7 @@ impl From<&[u8]> for Monkey {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut monkeys: Vec<_> = input
 		.split(|b| *b == b'M')
 		.skip(1)
@@ -116,7 +127,7 @@ pub fn solve(input: &[u8]) -> Solution {
 
 	let monkeys_len = monkeys.len();
 
-	let mut counts: Vec<_> = repeat(0_usize).take(monkeys_len).collect();
+	let mut counts: Vec<_> = repeat_n(0_usize, monkeys_len).collect();
 
 	let mut worrysome_monkeys = monkeys.clone();
 	let mut panicked_counts = counts.clone();
@@ -124,7 +135,9 @@ pub fn solve(input: &[u8]) -> Solution {
 	// let mut cache: HashMap<Vec<usize>, (usize, Vec<usize>)> = HashMap::new();
 
 	let common_divisor: Worry = monkeys.iter().map(|m| m.division_test.0).product();
+	parsing.end();
 
+	let calm = span("20 rounds");
 	for _ in 0..20 {
 		for monkey_index in 0..monkeys_len {
 			while let Some((item, to_index)) = monkeys[monkey_index].throw_item(true) {
@@ -136,6 +149,9 @@ pub fn solve(input: &[u8]) -> Solution {
 
 	counts.sort_unstable();
 	let monkey_business_20 = counts.iter().rev().take(2).product();
+	calm.end();
+
+	let _worried = span("10000 rounds");
 
 	// let mut rounds = 0_usize..10000;
 
@@ -193,7 +209,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(10605,2713310158))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(56350,13954061248))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -1,8 +1,18 @@
+use aoc_2022_utils::phase::span;
 use std::{cmp::Ordering, fmt::Debug, iter::Peekable, slice};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(PartialEq, Eq, Clone)]
 enum Packet {
 	List(Vec<Packet>),
@@ -114,6 +124,7 @@ impl PartialOrd for Packet {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let pairs = span("pairs");
 	let mut packets = input.split(|b| *b == b'\n');
 
 	let mut correct_count = 0_usize;
@@ -138,7 +149,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		parsed_packets.push(packet_a);
 		parsed_packets.push(packet_b);
 	}
+	pairs.end();
 
+	let _sorting = span("decoder key");
 	let div_1: Packet = Packet::List(vec![Packet::List(vec![Packet::Value(2)])]);
 	let div_2: Packet = Packet::List(vec![Packet::List(vec![Packet::Value(6)])]);
 
@@ -162,7 +175,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(13,140))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(5003,20280))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
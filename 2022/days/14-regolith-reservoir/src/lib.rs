@@ -1,10 +1,22 @@
+use aoc_2022_utils::phase::span;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
 const X_OFFSET: usize = 500 - 200;
 const X_500: usize = 500 - X_OFFSET;
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut map = CaveMap { lines: vec![] };
 
 	for line in input.split(|b| *b == b'\n') {
@@ -28,9 +40,13 @@ pub fn solve(input: &[u8]) -> Solution {
 			last = Some((x, y));
 		}
 	}
+	parsing.end();
 
+	let sand = span("sand");
 	let sand_count = map.fill_with_sand();
+	sand.end();
 
+	let _floor = span("sand with floor");
 	map.fill_row(map.lines.len() + 1, 0, 399);
 
 	let sand_count_2 = sand_count + map.fill_with_sand();
@@ -88,7 +104,7 @@ impl CaveMap {
 		loop {
 			let Some((x, y)) = current else {
 				// max pileup
-				return count
+				return count;
 			};
 			if y == self.lines.len() - 1 {
 				break;
@@ -137,7 +153,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), Solution(24,93))]
-	#[case(include_bytes!("../inputs/personal.txt"), Solution(1199,23925))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -1,3 +1,4 @@
+use aoc_2022_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::hint::black_box;
 
@@ -26,6 +27,20 @@ fn criterion_benchmark(c: &mut Criterion) {
 				)
 			})
 		});
+
+		for (phase, _) in phase::record(|| aoc_2022_15::solve(input, y_check, search_scope)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("phase {phase}"), bytes),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || {
+							aoc_2022_15::solve(black_box(file), y_check, search_scope)
+						})
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
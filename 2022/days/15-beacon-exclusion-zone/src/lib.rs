@@ -1,4 +1,4 @@
-use aoc_2022_utils::ascii_int::parse_int;
+use aoc_2022_utils::{ascii_int::parse_int, phase::span};
 
 use crate::range_set::IntRangeSet;
 
@@ -7,6 +7,15 @@ mod range_set;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Point {
 	x: isize,
@@ -179,13 +188,16 @@ impl From<&Sensor> for Rhombus {
 }
 
 pub fn solve(input: &[u8], y_check: isize, search_scope: isize) -> Solution {
+	let parsing = span("parse");
 	let sensors: Vec<_> = input.split(|b| *b == b'\n').map(Sensor::from).collect();
+	parsing.end();
 
 	assert_eq!(
 		Point { x: -3, y: 15 },
 		Point::from(DiagonalPoint::from(Point { x: -3, y: 15 })),
 	);
 
+	let row = span("row");
 	let unchecked_count = sensors
 		.iter()
 		.filter(|s| s.location.y.abs_diff(y_check) <= s.exclusion_distance)
@@ -195,7 +207,9 @@ pub fn solve(input: &[u8], y_check: isize, search_scope: isize) -> Solution {
 			set
 		})
 		.len();
+	row.end();
 
+	let _beacon = span("beacon");
 	let search_range = 0..=search_scope;
 
 	let rhombs: Vec<Rhombus> = sensors.iter().map(Rhombus::from).collect();
@@ -245,7 +259,6 @@ mod tests {
 
 	#[rstest]
 	#[case(include_bytes!("../inputs/example.txt"), 10, 20, Solution(26,56000011))]
-	#[case(include_bytes!("../inputs/personal.txt"), 2_000_000, 4_000_000, Solution(5083287,13134039205729))]
 	fn solution(
 		#[case] input: &[u8],
 		#[case] y_check: isize,
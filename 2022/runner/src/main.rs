@@ -0,0 +1,100 @@
+use aoc_cli::Day;
+use std::{process::ExitCode, str};
+
+const DAYS: &[Day] = &[
+	Day::new(
+		1,
+		"Energy Counting",
+		"days/01-energy-counting/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_01::solve_loop(str::from_utf8(input)?)),
+	),
+	Day::new(
+		2,
+		"Rock Paper Scissors",
+		"days/02-rock-paper-scissors/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_02::solve(input)),
+	),
+	Day::new(
+		3,
+		"Rucksack Reorganization",
+		"days/03-rucksack-reorganization/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_03::solve(input)),
+	),
+	Day::new(
+		4,
+		"Camp Cleanup",
+		"days/04-camp-cleanup/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_04::solve(str::from_utf8(input)?)),
+	),
+	Day::new(
+		5,
+		"Supply Stacks",
+		"days/05-supply-stacks/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_05::bytes::solve(input)),
+	),
+	Day::new(
+		6,
+		"Tuning Trouble",
+		"days/06-tuning-trouble/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_06::solve_loop::solve_loop(input)),
+	),
+	Day::new(
+		7,
+		"No Space Left On Device",
+		"days/07-no-space-left-on-device/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_07::solve(input)),
+	),
+	Day::new(
+		8,
+		"Treetop Tree House",
+		"days/08-treetop-tree-house/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_08::solve(input)),
+	),
+	Day::new(
+		9,
+		"Rope Bridge",
+		"days/09-rope-bridge/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_09::solve(input)),
+	),
+	Day::new(
+		10,
+		"Cathode Ray Tube",
+		"days/10-cathode-ray-tube/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_10::solve(input)),
+	),
+	Day::new(
+		11,
+		"Monkey in the Middle",
+		"days/11-monkey-in-the-middle/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_11::solve(input)),
+	),
+	Day::new(
+		13,
+		"Distress Signal",
+		"days/13-distress-signal/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_13::solve(input)),
+	),
+	Day::new(
+		14,
+		"Regolith Reservoir",
+		"days/14-regolith-reservoir/inputs/personal.txt",
+		|input, _| aoc_cli::answers(aoc_2022_14::solve(input)),
+	),
+	Day::new(
+		15,
+		"Beacon Exclusion Zone",
+		"days/15-beacon-exclusion-zone/inputs/personal.txt",
+		|input, params| {
+			aoc_cli::answers(aoc_2022_15::solve(
+				input,
+				params.get("y_check")?,
+				params.get("search_scope")?,
+			))
+		},
+	)
+	.with_params(&[("y_check", "2000000"), ("search_scope", "4000000")]),
+];
+
+fn main() -> ExitCode {
+	aoc_cli::main(2022, concat!(env!("CARGO_MANIFEST_DIR"), "/.."), DAYS)
+}
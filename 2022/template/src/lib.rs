@@ -1,7 +1,16 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(u64, u64);
 
-pub fn solve(input: &[u8]) -> Solution {
+impl aoc_2022_utils::solution::Solution for Solution {
+	type Part1 = u64;
+	type Part2 = u64;
+
+	fn into_parts(self) -> (u64, u64) {
+		(self.0, self.1)
+	}
+}
+
+pub fn solve(_input: &[u8]) -> Solution {
 	Solution(0, 0)
 }
 
@@ -12,7 +21,6 @@ mod tests {
 
 	#[rstest]
 	#[case(todo!(), Solution(0,0))]
-	#[case(todo!(), Solution(0,0))]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
@@ -1,3 +1,5 @@
+use std::{error::Error, fmt::Display};
+
 fn b_digit(b: u8) -> usize {
 	if !b.is_ascii_digit() {
 		panic!("Not an ascii digit")
@@ -6,7 +8,7 @@ fn b_digit(b: u8) -> usize {
 }
 
 /// Parse `usize` from ascii digit bytes.
-/// Panics on unexpected bytes (non-digits).
+/// Panics on unexpected bytes (non-digits). See [`try_parse_uint`] for a checked version.
 pub fn parse_uint(digits: &[u8]) -> usize {
 	digits[1..]
 		.iter()
@@ -14,7 +16,8 @@ pub fn parse_uint(digits: &[u8]) -> usize {
 }
 
 /// Parse `isize` from ascii digit bytes. First byte can be either a digit or `-`.
-/// Panics on unexpected bytes (non-minus & non-digit)
+/// Panics on unexpected bytes (non-minus & non-digit). See [`try_parse_int`] for a
+/// checked version.
 pub fn parse_int(digits: &[u8]) -> isize {
 	if digits[0] == b'-' {
 		-(parse_uint(&digits[1..]) as isize)
@@ -22,3 +25,221 @@ pub fn parse_int(digits: &[u8]) -> isize {
 		parse_uint(digits) as isize
 	}
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntErrorKind {
+	/// There were no digits to parse.
+	Empty,
+	/// A byte that is neither a digit nor a leading `-`.
+	InvalidByte(u8),
+	/// The number is larger than the target type's `MAX`.
+	PosOverflow,
+	/// The number is smaller than the target type's `MIN`.
+	NegOverflow,
+}
+
+/// Error of the checked parsing functions, pointing at the offending byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseIntError {
+	/// Index into the parsed slice. For [`ParseIntErrorKind::Empty`] this is where the
+	/// digits should have started.
+	pub offset: usize,
+	pub kind: ParseIntErrorKind,
+}
+
+impl Display for ParseIntError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.kind {
+			ParseIntErrorKind::Empty => write!(f, "expected digits at byte {}", self.offset),
+			ParseIntErrorKind::InvalidByte(b) => write!(
+				f,
+				"unexpected byte '{}' at byte {}",
+				b.escape_ascii(),
+				self.offset
+			),
+			ParseIntErrorKind::PosOverflow => {
+				write!(f, "number too large at byte {}", self.offset)
+			}
+			ParseIntErrorKind::NegOverflow => {
+				write!(f, "number too small at byte {}", self.offset)
+			}
+		}
+	}
+}
+
+impl Error for ParseIntError {}
+
+/// Integers the checked parsing functions can produce.
+pub trait CheckedDigits: Copy {
+	const ZERO: Self;
+	const SIGNED: bool;
+
+	/// `self * 10 + digit`, or `None` on overflow.
+	fn append_digit(self, digit: u8) -> Option<Self>;
+
+	/// `self * 10 - digit`, or `None` on overflow. Negative numbers are built this way so
+	/// that `MIN` can be parsed.
+	fn append_negative_digit(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! checked_digits {
+	($signed:literal: $($int:ty),*) => {
+		$(
+			impl CheckedDigits for $int {
+				const ZERO: Self = 0;
+				const SIGNED: bool = $signed;
+
+				#[inline(always)]
+				fn append_digit(self, digit: u8) -> Option<Self> {
+					self.checked_mul(10)?.checked_add(digit as Self)
+				}
+
+				#[inline(always)]
+				fn append_negative_digit(self, digit: u8) -> Option<Self> {
+					self.checked_mul(10)?.checked_sub(digit as Self)
+				}
+			}
+		)*
+	};
+}
+
+checked_digits!(false: u8, u16, u32, u64, u128, usize);
+checked_digits!(true: i8, i16, i32, i64, i128, isize);
+
+#[inline]
+fn try_parse_digits<T: CheckedDigits>(
+	digits: &[u8],
+	offset: usize,
+	negative: bool,
+) -> Result<T, ParseIntError> {
+	if digits.is_empty() {
+		return Err(ParseIntError {
+			offset,
+			kind: ParseIntErrorKind::Empty,
+		});
+	}
+
+	digits
+		.iter()
+		.enumerate()
+		.try_fold(T::ZERO, |number, (i, &b)| {
+			let error = |kind| ParseIntError {
+				offset: offset + i,
+				kind,
+			};
+			if !b.is_ascii_digit() {
+				return Err(error(ParseIntErrorKind::InvalidByte(b)));
+			}
+
+			let digit = b - b'0';
+			if negative {
+				number
+					.append_negative_digit(digit)
+					.ok_or(error(ParseIntErrorKind::NegOverflow))
+			} else {
+				number
+					.append_digit(digit)
+					.ok_or(error(ParseIntErrorKind::PosOverflow))
+			}
+		})
+}
+
+/// Parse any integer type from ascii digit bytes.
+/// Errors on empty input, unexpected bytes (non-digits) and overflow.
+#[inline]
+pub fn try_parse_uint<T: CheckedDigits>(digits: &[u8]) -> Result<T, ParseIntError> {
+	try_parse_digits(digits, 0, false)
+}
+
+/// Parse any integer type from ascii digit bytes. First byte can be `-` for signed types.
+/// Errors on empty input, unexpected bytes (non-minus & non-digit) and overflow.
+#[inline]
+pub fn try_parse_int<T: CheckedDigits>(digits: &[u8]) -> Result<T, ParseIntError> {
+	match digits.split_first() {
+		Some((b'-', rest)) if T::SIGNED => try_parse_digits(rest, 1, true),
+		_ => try_parse_digits(digits, 0, false),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn error(offset: usize, kind: ParseIntErrorKind) -> ParseIntError {
+		ParseIntError { offset, kind }
+	}
+
+	#[test]
+	fn parses_checked_uints() {
+		assert_eq!(try_parse_uint::<usize>(b"98696"), Ok(98696));
+		assert_eq!(try_parse_uint::<u8>(b"255"), Ok(255));
+		assert_eq!(try_parse_uint::<u64>(b"007"), Ok(7));
+		assert_eq!(
+			try_parse_uint::<u128>(b"340282366920938463463374607431768211455"),
+			Ok(u128::MAX)
+		);
+
+		assert_eq!(
+			try_parse_uint::<u8>(b""),
+			Err(error(0, ParseIntErrorKind::Empty))
+		);
+		assert_eq!(
+			try_parse_uint::<u8>(b"256"),
+			Err(error(2, ParseIntErrorKind::PosOverflow))
+		);
+		assert_eq!(
+			try_parse_uint::<u32>(b"12,34"),
+			Err(error(2, ParseIntErrorKind::InvalidByte(b',')))
+		);
+		assert_eq!(
+			try_parse_uint::<i32>(b"-1"),
+			Err(error(0, ParseIntErrorKind::InvalidByte(b'-')))
+		);
+	}
+
+	#[test]
+	fn parses_checked_ints() {
+		assert_eq!(try_parse_int::<isize>(b"-42"), Ok(-42));
+		assert_eq!(try_parse_int::<i64>(b"42"), Ok(42));
+		assert_eq!(try_parse_int::<i8>(b"-128"), Ok(i8::MIN));
+		assert_eq!(try_parse_int::<i8>(b"127"), Ok(i8::MAX));
+		assert_eq!(try_parse_int::<u16>(b"65535"), Ok(u16::MAX));
+
+		assert_eq!(
+			try_parse_int::<i8>(b"-"),
+			Err(error(1, ParseIntErrorKind::Empty))
+		);
+		assert_eq!(
+			try_parse_int::<i8>(b"-129"),
+			Err(error(3, ParseIntErrorKind::NegOverflow))
+		);
+		assert_eq!(
+			try_parse_int::<i8>(b"128"),
+			Err(error(2, ParseIntErrorKind::PosOverflow))
+		);
+		assert_eq!(
+			try_parse_int::<i32>(b"--1"),
+			Err(error(1, ParseIntErrorKind::InvalidByte(b'-')))
+		);
+		assert_eq!(
+			try_parse_int::<u8>(b"-1"),
+			Err(error(0, ParseIntErrorKind::InvalidByte(b'-')))
+		);
+	}
+
+	#[test]
+	fn describes_errors() {
+		assert_eq!(
+			error(4, ParseIntErrorKind::InvalidByte(b'\n')).to_string(),
+			"unexpected byte '\\n' at byte 4"
+		);
+		assert_eq!(
+			error(2, ParseIntErrorKind::PosOverflow).to_string(),
+			"number too large at byte 2"
+		);
+		assert_eq!(
+			error(3, ParseIntErrorKind::NegOverflow).to_string(),
+			"number too small at byte 3"
+		);
+	}
+}
@@ -1 +1,4 @@
 pub mod ascii_int;
+pub mod phase;
+
+pub use aoc_solution as solution;
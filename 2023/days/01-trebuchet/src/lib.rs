@@ -1,6 +1,15 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 const LINE_SPLIT: u8 = b'\n';
 
 fn check_spelled_digit_l(tri: &[u8], quad: Option<&u8>, quint: Option<&u8>) -> Option<usize> {
@@ -143,18 +152,72 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(142 + 209,142 + 281),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(54632, 54019),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Looks for a digit, or a spelled out one, at every position of a line.
+	fn naive(input: &[u8]) -> Solution {
+		const SPELLED: [&str; 9] = [
+			"one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+		];
+		let mut simple = 0;
+		let mut spelled = 0;
+		for line in std::str::from_utf8(input).unwrap().lines() {
+			let digit = |i: usize| {
+				(line.as_bytes()[i] as char)
+					.to_digit(10)
+					.map(|d| d as usize)
+			};
+			let digits: Vec<usize> = (0..line.len()).filter_map(digit).collect();
+			let words: Vec<usize> = (0..line.len())
+				.filter_map(|i| {
+					digit(i).or_else(|| {
+						SPELLED
+							.iter()
+							.position(|word| line[i..].starts_with(word))
+							.map(|position| position + 1)
+					})
+				})
+				.collect();
+			simple += digits[0] * 10 + digits[digits.len() - 1];
+			spelled += words[0] * 10 + words[words.len() - 1];
+		}
+		Solution(simple, spelled)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..100_usize) {
+			let input = generate::day_01(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
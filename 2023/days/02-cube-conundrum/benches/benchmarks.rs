@@ -1,5 +1,7 @@
-use day_02_cube_conundrum::solve;
+use aoc_utils::generate::{self, Rng};
+use aoc_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_02_cube_conundrum::solve;
 use std::hint::black_box;
 
 const NAME: &str = env!("CARGO_PKG_NAME");
@@ -9,6 +11,9 @@ const INPUTS: [(&str, &[u8]); 2] = [
 	("personal", include_bytes!("../inputs/personal.txt")),
 ];
 
+/// Sizes of generated inputs, to see how the solver scales.
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
 fn criterion_benchmark(c: &mut Criterion) {
 	let mut group = c.benchmark_group(NAME);
 
@@ -20,6 +25,28 @@ fn criterion_benchmark(c: &mut Criterion) {
 		group.bench_with_input(BenchmarkId::new("solve", id), input, |b, file| {
 			b.iter(|| solve(black_box(file)))
 		});
+
+		for (phase, _) in phase::record(|| solve(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("phase {phase}"), id),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| phase::time(phase, iters, || solve(black_box(file))))
+				},
+			);
+		}
+	}
+
+	for size in SIZES {
+		let input = generate::day_02(&mut Rng::new(0), size);
+
+		group.throughput(Throughput::Bytes(input.len() as u64));
+
+		group.bench_with_input(
+			BenchmarkId::new("generated", size),
+			&input[..],
+			|b, file| b.iter(|| solve(black_box(file))),
+		);
 	}
 
 	group.finish();
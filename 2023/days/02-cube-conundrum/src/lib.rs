@@ -1,8 +1,17 @@
-use aoc_utils::ascii_int::parse_u8_unchecked;
+use aoc_utils::{ascii_int::parse_u8_unchecked, phase::span};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 const LINE_SEP: u8 = b'\n';
 const GAME_SEP: u8 = b':';
 const GRAB_SEP: u8 = b';';
@@ -120,12 +129,15 @@ impl From<&[u8]> for Game {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let games: Vec<_> = input
 		.split(|b| *b == LINE_SEP)
 		.filter(|line| !line.is_empty())
 		.map(Game::from)
 		.collect();
+	parsing.end();
 
+	let _summing = span("sums");
 	let possible_check_id_sum: usize = games
 		.iter()
 		.enumerate()
@@ -141,6 +153,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -148,11 +163,47 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(8,2286),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(2285,77021),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Takes the maximum of every colour over all grabs of a game.
+	fn naive(input: &[u8]) -> Solution {
+		let mut possible = 0;
+		let mut power = 0;
+		for (index, line) in std::str::from_utf8(input).unwrap().lines().enumerate() {
+			let (_, grabs) = line.split_once(": ").unwrap();
+			let mut max = [0; 3];
+			for cubes in grabs.split([';', ',']) {
+				let (count, colour) = cubes.trim().split_once(' ').unwrap();
+				let colour = ["red", "green", "blue"]
+					.iter()
+					.position(|c| *c == colour)
+					.unwrap();
+				max[colour] = max[colour].max(count.parse::<usize>().unwrap());
+			}
+			if max[0] <= 12 && max[1] <= 13 && max[2] <= 14 {
+				possible += index + 1;
+			}
+			power += max.iter().product::<usize>();
+		}
+		Solution(possible, power)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..100_usize) {
+			let input = generate::day_02(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,6 +1,17 @@
+use aoc_utils::phase::span;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 struct Num {
 	value: usize,
 	span: (isize, isize),
@@ -82,6 +93,8 @@ pub fn solve(input: &[u8]) -> Solution {
 	let mut positions: Vec<isize> = vec![];
 	let mut current_num: Option<Num> = None;
 
+	let numbers = span("numbers");
+
 	for (position, b) in input.iter().enumerate() {
 		match (&mut current_num, b) {
 			(None, b) if b.is_ascii_digit() => {
@@ -106,6 +119,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		}
 	}
 
+	numbers.end();
+
+	let _gears = span("gears");
 	gear_connections.sort_unstable_by_key(|gear| gear.position);
 
 	let gears = gear_connections
@@ -141,18 +157,96 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::collections::HashMap;
 
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(4361,467835),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(537832,81939900),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Finds the numbers in the grid and looks at every cell around them.
+	fn naive(input: &[u8]) -> Solution {
+		let grid: Vec<&[u8]> = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.collect();
+		let mut part_sum = 0;
+		let mut gears: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+		for (y, row) in grid.iter().enumerate() {
+			let mut x = 0;
+			while x < row.len() {
+				if !row[x].is_ascii_digit() {
+					x += 1;
+					continue;
+				}
+				let start = x;
+				while x < row.len() && row[x].is_ascii_digit() {
+					x += 1;
+				}
+				let number: usize = std::str::from_utf8(&row[start..x])
+					.unwrap()
+					.parse()
+					.unwrap();
+
+				let mut is_part = false;
+				let columns = start.saturating_sub(1)..(x + 1).min(row.len());
+				for (ny, around) in grid
+					.iter()
+					.enumerate()
+					.take(y + 2)
+					.skip(y.saturating_sub(1))
+				{
+					for (nx, &b) in around
+						.iter()
+						.enumerate()
+						.take(columns.end)
+						.skip(columns.start)
+					{
+						if !b.is_ascii_digit() && b != b'.' {
+							is_part = true;
+						}
+						if b == b'*' {
+							gears.entry((ny, nx)).or_default().push(number);
+						}
+					}
+				}
+				if is_part {
+					part_sum += number;
+				}
+			}
+		}
+
+		let ratios = gears
+			.values()
+			.filter(|numbers| numbers.len() == 2)
+			.map(|numbers| numbers[0] * numbers[1])
+			.sum();
+		Solution(part_sum, ratios)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..40_usize) {
+			let input = generate::day_03(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,7 +1,16 @@
+use aoc_utils::bits::FixedBitSet;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
-const EMPTY: [bool; 100] = [false; 100];
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
 
 fn parse_2_digit_uint(input: &[u8]) -> usize {
 	// println!("{}", String::from_utf8(input.to_vec()).unwrap());
@@ -14,7 +23,7 @@ fn parse_2_digit_uint(input: &[u8]) -> usize {
 
 pub fn solve(input: &[u8]) -> Solution {
 	let mut copies_won: [usize; 500] = [0; 500];
-	let mut card_numbers: [bool; 100];
+	let mut card_numbers: FixedBitSet<2>;
 	let mut points_won = 0;
 
 	let input_width = input
@@ -37,7 +46,7 @@ pub fn solve(input: &[u8]) -> Solution {
 
 	for (index, line) in input.chunks(input_width).enumerate() {
 		let game = index + 1;
-		card_numbers = EMPTY;
+		card_numbers = FixedBitSet::new();
 
 		let game_copies = copies_won[game] + 1;
 		copies_won[game] = game_copies;
@@ -46,7 +55,7 @@ pub fn solve(input: &[u8]) -> Solution {
 			let span_start = line_prefix_width + 3 * win_index;
 			let span_end = span_start + 2;
 			let win_nr = parse_2_digit_uint(&line[span_start..span_end]);
-			card_numbers[win_nr] = true;
+			card_numbers.insert(win_nr);
 		}
 
 		let winning = (0..card_nrs)
@@ -55,7 +64,7 @@ pub fn solve(input: &[u8]) -> Solution {
 				let span_end = span_start + 2;
 				parse_2_digit_uint(&line[span_start..span_end])
 			})
-			.filter(|num| card_numbers[*num])
+			.filter(|num| card_numbers.contains(*num))
 			.count();
 
 		// manual power of 2
@@ -74,18 +83,71 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(13,30),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(25183,5667240),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Compares every number on a card with every winning one, then hands out the
+	/// copies card by card.
+	fn naive(input: &[u8]) -> Solution {
+		let wins: Vec<usize> = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| {
+				let (_, numbers) = line.split_once(':').unwrap();
+				let (winning, own) = numbers.split_once('|').unwrap();
+				let winning: Vec<&str> = winning.split_whitespace().collect();
+				own.split_whitespace()
+					.filter(|number| winning.contains(number))
+					.count()
+			})
+			.collect();
+
+		let points = wins
+			.iter()
+			.map(|&w| if w == 0 { 0 } else { 1 << (w - 1) })
+			.sum();
+		let mut copies = vec![1; wins.len()];
+		for card in 0..wins.len() {
+			for won in card + 1..=card + wins[card] {
+				copies[won] += copies[card];
+			}
+		}
+		Solution(points, copies.iter().sum())
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..100_usize) {
+			let input = generate::day_04(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
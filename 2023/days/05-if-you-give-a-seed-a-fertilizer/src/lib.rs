@@ -1,120 +1,22 @@
-use aoc_utils::{ascii_int::parse_uint_unchecked, iteration::expect_n};
+use aoc_utils::{
+	ascii_int::parse_uint_unchecked, interval_map::IntervalMap, iteration::expect_n, phase::span,
+	range_set::IntRangeSet,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
-#[derive(Debug)]
-struct Mapping {
-	range: (usize, usize),
-	offset: isize,
-}
-
-enum RangeMatch {
-	Full {
-		mapped: (usize, usize),
-	},
-	PartialOneSide {
-		mapped: (usize, usize),
-		side: (usize, usize),
-	},
-	PartialTwoSides {
-		mapped: (usize, usize),
-		side_1: (usize, usize),
-		side_2: (usize, usize),
-	},
-}
-
-impl Mapping {
-	fn parse(line: &[u8]) -> Self {
-		let [dest_start, source_start, len] = expect_n(
-			line.split(|b| *b == b' ').map(parse_uint_unchecked),
-			"bad input, expected 3 numbers in line",
-		);
-
-		Mapping {
-			offset: dest_start as isize - source_start as isize,
-			range: (source_start, source_start + len - 1),
-		}
-	}
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
 
-	fn matches(&self, num: usize) -> bool {
-		num >= self.range.0 && num <= self.range.1
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
 	}
-
-	fn map_range(&self, range: &(usize, usize)) -> Option<RangeMatch> {
-		if range.1 < self.range.0 || range.0 > self.range.1 {
-			None
-		} else if range.0 >= self.range.0 && range.1 <= self.range.1 {
-			Some(RangeMatch::Full {
-				mapped: (self.apply(range.0), self.apply(range.1)),
-			})
-		} else if range.0 >= self.range.0 && range.1 > self.range.1 {
-			// leftover to the right
-			Some(RangeMatch::PartialOneSide {
-				mapped: (self.apply(range.0), self.apply(self.range.1)),
-				side: (self.range.1 + 1, range.1),
-			})
-		} else if range.0 < self.range.0 && range.1 <= self.range.1 {
-			// leftover to the left
-			Some(RangeMatch::PartialOneSide {
-				side: (range.0, self.range.0 - 1),
-				mapped: (self.apply(self.range.0), self.apply(range.1)),
-			})
-		} else {
-			// leftover to both sides
-			Some(RangeMatch::PartialTwoSides {
-				side_1: (range.0, self.range.0 - 1),
-				mapped: (self.apply(self.range.0), self.apply(self.range.1)),
-				side_2: (self.range.1 + 1, range.1),
-			})
-		}
-	}
-
-	fn apply(&self, num: usize) -> usize {
-		(num as isize + self.offset) as usize
-	}
-}
-
-fn apply_mappings(mappings: &[Mapping], nums: &mut [usize], ranges: &mut Vec<(usize, usize)>) {
-	for num in nums.iter_mut() {
-		if let Some(mapping) = mappings.iter().find(|m| m.matches(*num)) {
-			*num = mapping.apply(*num)
-		}
-	}
-
-	let mut mapped_ranges: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
-
-	while let Some(range) = ranges.pop() {
-		if let Some(range_match) = mappings
-			.iter()
-			.find_map(|mapping| mapping.map_range(&range))
-		{
-			match range_match {
-				RangeMatch::Full { mapped } => mapped_ranges.push(mapped),
-				RangeMatch::PartialOneSide { mapped, side } => {
-					mapped_ranges.push(mapped);
-					ranges.push(side);
-				}
-				RangeMatch::PartialTwoSides {
-					mapped,
-					side_1,
-					side_2,
-				} => {
-					mapped_ranges.push(mapped);
-					ranges.push(side_1);
-					ranges.push(side_2);
-				}
-			}
-		} else {
-			// no mapping = stays the same
-			mapped_ranges.push(range);
-		}
-	}
-
-	*ranges = mapped_ranges;
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let seeds = span("seeds");
 	let mut nums: Vec<usize> = input
 		.split(|b| *b == b'\n')
 		.next()
@@ -124,35 +26,53 @@ pub fn solve(input: &[u8]) -> Solution {
 		.map(parse_uint_unchecked)
 		.collect();
 
-	let mut num_iter = nums.iter();
-	let mut ranges: Vec<(usize, usize)> = vec![];
-	while let (Some(a), Some(b)) = (num_iter.next(), num_iter.next()) {
-		ranges.push((*a, *a + *b - 1));
-	}
+	let mut ranges: IntRangeSet<usize> = nums
+		.chunks_exact(2)
+		.map(|pair| (pair[0], pair[0] + pair[1] - 1))
+		.collect();
 
-	let mut block_mappings: Vec<Mapping> = vec![];
+	seeds.end();
+
+	let _mapping = span("mappings");
+	let mut block = IntervalMap::new();
 
 	for line in input.split(|b| *b == b'\n') {
 		if line.first().is_some_and(|b| b.is_ascii_digit()) {
-			block_mappings.push(Mapping::parse(line));
-		} else if !block_mappings.is_empty() {
-			apply_mappings(&block_mappings, &mut nums, &mut ranges);
-			block_mappings.clear();
+			let [dest_start, source_start, len] = expect_n(
+				line.split(|b| *b == b' ').map(parse_uint_unchecked),
+				"bad input, expected 3 numbers in line",
+			);
+			block.insert((source_start, source_start + len - 1), dest_start);
+		} else if !block.is_empty() {
+			apply(&mut block, &mut nums, &mut ranges);
 		}
 	}
+	// the last map, when no newline follows it
+	if !block.is_empty() {
+		apply(&mut block, &mut nums, &mut ranges);
+	}
 
-	nums.sort_unstable();
-	let lowest_location = nums[0];
-
-	ranges.sort_unstable_by_key(|range| range.0);
-	let lowest_range_location = ranges[0].0;
+	let lowest_location = *nums.iter().min().expect("has seeds");
+	let lowest_range_location = ranges.first().expect("has seeds");
 
 	Solution(lowest_location, lowest_range_location)
 }
 
+/// Sends the numbers and ranges through a map, leaving it empty for the next one.
+fn apply(block: &mut IntervalMap<usize>, nums: &mut [usize], ranges: &mut IntRangeSet<usize>) {
+	for num in nums.iter_mut() {
+		*num = block.get(*num);
+	}
+	*ranges = block.apply(ranges);
+	block.clear();
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -160,15 +80,70 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(35, 46),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(551761867,57451709),
-	)]
-	#[case::friend(
-		include_bytes!("../inputs/friend.txt"),
-		Solution(323142486,79874951),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	#[test]
+	fn applies_the_last_map() {
+		// unlike the example's, this map moves the lowest locations
+		let example = include_str!("../inputs/example.txt");
+		let (maps, _) = example.split_once("humidity-to-location").unwrap();
+		let input = format!("{maps}humidity-to-location map:\n100 0 100");
+		assert_eq!(solve(input.as_bytes()), Solution(135, 146));
+		assert_eq!(solve(format!("{input}\n").as_bytes()), Solution(135, 146));
+	}
+
+	/// Sends every single seed through all the maps.
+	fn naive(input: &[u8]) -> Solution {
+		let text = std::str::from_utf8(input).unwrap();
+		let mut blocks = text.split("\n\n");
+		let seeds: Vec<usize> = blocks.next().unwrap()["seeds: ".len()..]
+			.split(' ')
+			.map(|seed| seed.parse().unwrap())
+			.collect();
+		let maps: Vec<Vec<Vec<usize>>> = blocks
+			.map(|block| {
+				block
+					.lines()
+					.skip(1)
+					.map(|line| line.split(' ').map(|n| n.parse().unwrap()).collect())
+					.collect()
+			})
+			.collect();
+
+		let location = |seed: usize| {
+			maps.iter().fold(seed, |id, map| {
+				map.iter()
+					.find(|range| (range[1]..range[1] + range[2]).contains(&id))
+					.map_or(id, |range| range[0] + id - range[1])
+			})
+		};
+
+		let lowest = seeds.iter().map(|&seed| location(seed)).min().unwrap();
+		let lowest_of_ranges = seeds
+			.chunks(2)
+			.flat_map(|pair| pair[0]..pair[0] + pair[1])
+			.map(location)
+			.min()
+			.unwrap();
+		Solution(lowest, lowest_of_ranges)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..20_usize) {
+			let input = generate::day_05(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
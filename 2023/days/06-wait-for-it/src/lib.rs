@@ -1,11 +1,21 @@
 use aoc_utils::{
 	ascii_int::{parse_uint, parse_uint_coerce},
 	iteration::expect_n,
+	phase::span,
 };
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 const LINE_OFFSET: usize = "Distance: ".len();
 
 fn calculate_win_possibilities((time, dist): (usize, usize)) -> usize {
@@ -26,6 +36,7 @@ fn calculate_win_possibilities((time, dist): (usize, usize)) -> usize {
 pub fn solve(input: &[u8]) -> Solution {
 	let [time_line, dist_line] = expect_n(input.split(|b| *b == b'\n'), "input has 2 lines");
 
+	let races = span("races");
 	let times = time_line[LINE_OFFSET..]
 		.split(|b| *b == b' ')
 		.filter(|part| !part.is_empty())
@@ -37,6 +48,9 @@ pub fn solve(input: &[u8]) -> Solution {
 
 	let multiplied_possibilities: usize =
 		times.zip(dists).map(calculate_win_possibilities).product();
+	races.end();
+
+	let _kerning = span("kerning");
 
 	let time_keming = parse_uint_coerce(&time_line[LINE_OFFSET..]);
 	let dist_keming = parse_uint_coerce(&dist_line[LINE_OFFSET..]);
@@ -49,18 +63,76 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(288,71503),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(5133600,40651271),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Counts with integers only: the distance grows up to half the race time, so the
+	/// first winning time can be searched for.
+	fn naive(input: &[u8]) -> Solution {
+		let lines: Vec<Vec<&str>> = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| line.split_whitespace().skip(1).collect())
+			.collect();
+
+		let ways = |time: usize, record: usize| {
+			let (mut low, mut high) = (0, time / 2);
+			while low < high {
+				let mid = (low + high) / 2;
+				if mid * (time - mid) > record {
+					high = mid;
+				} else {
+					low = mid + 1;
+				}
+			}
+			time + 1 - 2 * low
+		};
+
+		let product = lines[0]
+			.iter()
+			.zip(&lines[1])
+			.map(|(time, record)| ways(time.parse().unwrap(), record.parse().unwrap()))
+			.product();
+		let long_race = ways(
+			lines[0].concat().parse().unwrap(),
+			lines[1].concat().parse().unwrap(),
+		);
+		Solution(product, long_race)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..5_usize) {
+			let input = generate::day_06(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
@@ -1,8 +1,17 @@
-use aoc_utils::ascii_int::parse_uint;
+use aoc_utils::{ascii_int::parse_uint, phase::span};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 struct Hand {
 	score: u32,
 	score_joker: u32,
@@ -87,12 +96,15 @@ impl Hand {
 
 pub fn solve(input: &[u8]) -> Solution {
 	// let mut card_store = CardCollection::new();
+	let parsing = span("parse");
 	let mut hands: Vec<_> = input
 		.split(|b| *b == b'\n')
 		.filter(|l| !l.is_empty())
 		.map(Hand::parse)
 		.collect();
+	parsing.end();
 
+	let ranking = span("rank");
 	hands.sort_unstable_by_key(|hand| hand.score); // ~8 us for sorting
 
 	let winnings = hands
@@ -100,7 +112,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		.enumerate()
 		.map(|(index, hand)| hand.bet * (index + 1))
 		.sum();
+	ranking.end();
 
+	let _ranking_jokers = span("rank jokers");
 	hands.sort_unstable_by_key(|hand| hand.score_joker); // ~8 us for sorting
 
 	let winnings_joker = hands
@@ -115,6 +129,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -122,11 +139,83 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(6440,5905),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(248453531,248781813),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Ranks hands by their sorted card counts, trying every card in place of the jokers.
+	fn naive(input: &[u8]) -> Solution {
+		const CARDS: &[u8] = b"23456789TJQKA";
+		const JOKER_CARDS: &[u8] = b"J23456789TQKA";
+		// kind of hand first, then the values of the cards in order
+		type Strength = (Vec<usize>, Vec<usize>);
+
+		fn kind(hand: &[u8]) -> Vec<usize> {
+			let mut counts: Vec<usize> = CARDS
+				.iter()
+				.map(|card| hand.iter().filter(|c| *c == card).count())
+				.filter(|count| *count > 0)
+				.collect();
+			counts.sort_unstable_by(|a, b| b.cmp(a));
+			counts
+		}
+		fn values(hand: &[u8], order: &[u8]) -> Vec<usize> {
+			hand.iter()
+				.map(|card| order.iter().position(|c| c == card).unwrap())
+				.collect()
+		}
+
+		let hands: Vec<(&[u8], usize)> = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.map(|line| {
+				let bid = std::str::from_utf8(&line[6..]).unwrap().parse().unwrap();
+				(&line[..5], bid)
+			})
+			.collect();
+
+		let winnings = |key: &dyn Fn(&[u8]) -> Strength| -> usize {
+			let mut sorted = hands.clone();
+			sorted.sort_by_key(|(hand, _)| key(hand));
+			sorted
+				.iter()
+				.enumerate()
+				.map(|(index, (_, bid))| (index + 1) * bid)
+				.sum()
+		};
+
+		let plain = winnings(&|hand| (kind(hand), values(hand, CARDS)));
+		let jokers = winnings(&|hand| {
+			let best = CARDS
+				.iter()
+				.map(|joker| {
+					let hand: Vec<u8> = hand
+						.iter()
+						.map(|&card| if card == b'J' { *joker } else { card })
+						.collect();
+					kind(&hand)
+				})
+				.max()
+				.unwrap();
+			(best, values(hand, JOKER_CARDS))
+		});
+		Solution(plain, jokers)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..200_usize) {
+			let input = generate::day_07(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -3,13 +3,18 @@ use aoc_utils::ascii_int::parse_int;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(isize, isize);
 
-fn save_diffs(firsts: &mut Vec<isize>, diffs: &mut Vec<isize>, comp: isize) -> bool {
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = isize;
+	type Part2 = isize;
+
+	fn into_parts(self) -> (isize, isize) {
+		(self.0, self.1)
+	}
+}
+
+fn save_diffs(firsts: &mut Vec<isize>, diffs: &mut Vec<isize>, comp: isize) {
 	let mut next = comp;
 	for latest in diffs.iter_mut() {
-		if *latest == 0 && next == 0 {
-			return true;
-		}
-
 		let diff = next - *latest;
 		*latest = next;
 		next = diff;
@@ -17,16 +22,6 @@ fn save_diffs(firsts: &mut Vec<isize>, diffs: &mut Vec<isize>, comp: isize) -> b
 
 	firsts.push(next);
 	diffs.push(next);
-
-	false
-}
-
-fn extrapolate_once(diffs: &mut [isize]) {
-	let mut last_res = 0;
-	for latest in diffs.iter_mut().rev().skip(1) {
-		*latest += last_res;
-		last_res = *latest;
-	}
 }
 
 fn extrapolate_from_line(
@@ -37,16 +32,10 @@ fn extrapolate_from_line(
 	shared_firsts.clear();
 	shared_diffs.clear();
 
-	let mut can_extrapolate = false;
-
+	// we cannot stop at the first 0 0 diffs, a level can have zeros next to each other
+	// without being all zeros (e.g. for x * (x - 1)), so every input span is needed
 	for part in line.split(|b| *b == b' ') {
-		// we can stop parsing input once the first 0 0 diffs were found
-		// instead just extrapolate once for every further input span found
-		if can_extrapolate {
-			extrapolate_once(shared_diffs);
-		} else {
-			can_extrapolate = save_diffs(shared_firsts, shared_diffs, parse_int(part));
-		}
+		save_diffs(shared_firsts, shared_diffs, parse_int(part));
 	}
 
 	let next = shared_diffs.iter().sum();
@@ -81,6 +70,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -88,11 +80,49 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(114,2),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(1681758908,803),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Builds every level of differences until all of them are zero.
+	fn naive(input: &[u8]) -> Solution {
+		let mut next = 0;
+		let mut previous = 0;
+		for line in std::str::from_utf8(input).unwrap().lines() {
+			let mut levels: Vec<Vec<isize>> =
+				vec![line.split(' ').map(|n| n.parse().unwrap()).collect()];
+			while levels.last().unwrap().iter().any(|value| *value != 0) {
+				let differences = levels
+					.last()
+					.unwrap()
+					.windows(2)
+					.map(|pair| pair[1] - pair[0])
+					.collect();
+				levels.push(differences);
+			}
+			next += levels
+				.iter()
+				.map(|level| level[level.len() - 1])
+				.sum::<isize>();
+			previous += levels.iter().rev().fold(0, |below, level| level[0] - below);
+		}
+		Solution(next, previous)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..100_usize) {
+			let input = generate::day_09(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,3 +1,4 @@
+use aoc_utils::phase;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use day_10_pipe_maze::{raycast, shoelace_picks};
 use std::hint::black_box;
@@ -28,6 +29,30 @@ fn criterion_benchmark(c: &mut Criterion) {
 		group.bench_with_input(BenchmarkId::new("shoelace_picks", id), input, |b, file| {
 			b.iter(|| shoelace_picks::solve(black_box(file)))
 		});
+
+		for (phase, _) in phase::record(|| raycast::solve(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("raycast phase {phase}"), id),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || raycast::solve(black_box(file)))
+					})
+				},
+			);
+		}
+
+		for (phase, _) in phase::record(|| shoelace_picks::solve(input)) {
+			group.bench_with_input(
+				BenchmarkId::new(format!("shoelace_picks phase {phase}"), id),
+				input,
+				|b, file| {
+					b.iter_custom(|iters| {
+						phase::time(phase, iters, || shoelace_picks::solve(black_box(file)))
+					})
+				},
+			);
+		}
 	}
 
 	group.finish();
@@ -3,6 +3,15 @@ use aoc_utils::direction::*;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 mod sym {
 	pub const START: u8 = b'S';
 	pub const NEWLINE: u8 = b'\n';
@@ -31,6 +40,7 @@ pub mod shoelace_picks;
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
 	use rstest::rstest;
 
 	#[rstest]
@@ -70,10 +80,6 @@ mod tests {
 		include_bytes!("../inputs/example_6.txt"),
 		Solution(22, 43),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(6701,303),
-	)]
 	fn solution(
 		#[case] input: &[u8],
 		#[case] expected: Solution,
@@ -81,4 +87,24 @@ mod tests {
 	) {
 		assert_eq!(solver(input), expected);
 	}
+
+	#[rstest]
+	#[case::example_1_clean(include_bytes!("../inputs/example_1_clean.txt"))]
+	#[case::example_1(include_bytes!("../inputs/example_1.txt"))]
+	#[case::example_2_clean(include_bytes!("../inputs/example_2_clean.txt"))]
+	#[case::example_2(include_bytes!("../inputs/example_2.txt"))]
+	#[case::example_3(include_bytes!("../inputs/example_3.txt"))]
+	#[case::example_3_squeeze(include_bytes!("../inputs/example_3_squeeze.txt"))]
+	#[case::example_4(include_bytes!("../inputs/example_4.txt"))]
+	#[case::example_5(include_bytes!("../inputs/example_5.txt"))]
+	#[case::example_6(include_bytes!("../inputs/example_6.txt"))]
+	fn input_variants(
+		#[case] input: &[u8],
+		#[values(shoelace_picks::solve, raycast::solve)] solver: impl Fn(&[u8]) -> Solution,
+	) {
+		let expected = solver(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solver(&normalize(&input)), expected, "{variant}");
+		}
+	}
 }
@@ -1,5 +1,5 @@
 use crate::{follow_facing_pipe, sym, Solution};
-use aoc_utils::direction::*;
+use aoc_utils::{direction::*, phase::span};
 
 #[derive(Debug)]
 struct Maze<'a> {
@@ -16,7 +16,7 @@ impl<'a> Maze<'a> {
 			.position(|b| *b == sym::NEWLINE)
 			.expect("maze map must have at least one newline");
 		let line_width = width + 1;
-		let height = map.len() / line_width;
+		let height = map.len().div_ceil(line_width);
 		let start = map
 			.iter()
 			.position(|b| *b == sym::START)
@@ -116,6 +116,7 @@ impl MazeRunner {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let running = span("loop");
 	let maze = Maze::new(input);
 
 	// record of all loop parts and vertical intersections of the maze
@@ -130,10 +131,12 @@ pub fn solve(input: &[u8]) -> Solution {
 		});
 
 		if runner.follow_pipe_or_end(&maze) {
-			break (runner.distance / 2);
+			break runner.distance / 2;
 		}
 	};
+	running.end();
 
+	let _scanning = span("scan lines");
 	let mut points_inside = 0;
 
 	for mut scan_line in scan_lines {
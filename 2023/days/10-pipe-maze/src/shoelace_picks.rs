@@ -1,5 +1,5 @@
 use crate::{follow_facing_pipe, sym, Solution};
-use aoc_utils::direction::*;
+use aoc_utils::{direction::*, phase::span, point::Point2, polygon::Trace};
 
 #[derive(Debug)]
 struct Maze<'a> {
@@ -44,13 +44,15 @@ impl<'a> Maze<'a> {
 			// assure this facing direction is leading to a next location
 			follow_facing_pipe(&dir, self.map[index])?;
 
+			let start = Point2::new(
+				(self.start % self.line_width) as isize,
+				(self.start / self.line_width) as isize,
+			);
 			Some(MazeRunner {
 				position: self.start,
 				distance: 0,
-				shoelace_sum: 0,
+				trace: Trace::new(start),
 				facing: dir,
-				x: (self.start % self.line_width) as isize,
-				y: (self.start / self.line_width) as isize,
 			})
 		})
 		.expect("expected 1 (of 2) start connections")
@@ -62,22 +64,20 @@ struct MazeRunner {
 	position: usize,
 	distance: usize,
 	facing: Direction,
-	shoelace_sum: isize,
-	y: isize,
-	x: isize,
+	trace: Trace<isize>,
 }
 
 impl MazeRunner {
 	fn follow_pipe_or_end(&mut self, maze: &Maze) -> bool {
-		let (next_position, next_x, next_y) = match self.facing {
-			North => (self.position - maze.line_width, self.x, self.y - 1),
-			East => (self.position + 1, self.x + 1, self.y),
-			South => (self.position + maze.line_width, self.x, self.y + 1),
-			West => (self.position - 1, self.x - 1, self.y),
+		let next_position = match self.facing {
+			North => self.position - maze.line_width,
+			East => self.position + 1,
+			South => self.position + maze.line_width,
+			West => self.position - 1,
 		};
 
 		self.distance += 1;
-		self.shoelace_sum += (self.x - next_x) * (self.y + next_y);
+		self.trace.step(self.facing, 1);
 
 		if next_position == maze.start {
 			return true;
@@ -90,32 +90,29 @@ impl MazeRunner {
 
 		self.position = next_position;
 		self.facing = next_facing;
-		self.y = next_y;
-		self.x = next_x;
 		false
 	}
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let running = span("loop");
 	let maze = Maze::new(input);
 
 	let mut runner = maze.start_running();
 
-	// Shoelace / Determinants  (x1 - x2) * (y1 + y2)  +   (x2 - x3) * (y2 + y3)  +   ...  +  (xn - x1) * (yn + y1)
-
-	let (loop_length, shoelace_sum) = loop {
+	let (loop_length, trace) = loop {
 		if runner.follow_pipe_or_end(&maze) {
-			break (runner.distance, runner.shoelace_sum);
+			break (runner.distance, runner.trace);
 		}
 	};
+	running.end();
 
-	let area = shoelace_sum.unsigned_abs() / 2;
-
+	let _area = span("area");
 	// always even
 	let furthest_distance = loop_length / 2;
 
-	// picks theorem
-	let points_inside = area - furthest_distance + 1;
+	// shoelace formula and Pick's theorem
+	let points_inside = trace.interior_points() as usize;
 
 	Solution(furthest_distance, points_inside)
 }
@@ -1,6 +1,17 @@
+use aoc_utils::phase::span;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 // Some musings on how to calculate all distances between points on a single axis in O(n)
 //
 // . point on axis
@@ -103,13 +114,14 @@ fn evaluate_axis_distances(point_counts: Vec<u8>) -> (usize, usize) {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let counting = span("count");
 	let width = input
 		.iter()
 		.position(|b| *b == b'\n')
 		.expect("input must have newline");
 
 	let line_width = width + 1;
-	let height = input.len() / line_width;
+	let height = input.len().div_ceil(line_width);
 
 	let mut x_counts = vec![0_u8; width];
 	let mut y_counts = vec![0_u8; height];
@@ -118,7 +130,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		x_counts[index % line_width] += 1;
 		y_counts[index / line_width] += 1;
 	}
+	counting.end();
 
+	let _distances = span("distances");
 	let (x_paths, x_expanse) = evaluate_axis_distances(x_counts);
 	let (y_paths, y_expanse) = evaluate_axis_distances(y_counts);
 
@@ -134,6 +148,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -141,11 +158,57 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(374,82000210),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(9918828,692506533832),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Walks from every galaxy to every other one, crossing empty rows and columns one
+	/// by one.
+	fn naive(input: &[u8]) -> Solution {
+		let grid: Vec<&[u8]> = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.collect();
+		let empty_rows: Vec<bool> = grid.iter().map(|row| !row.contains(&b'#')).collect();
+		let empty_columns: Vec<bool> = (0..grid[0].len())
+			.map(|x| grid.iter().all(|row| row[x] != b'#'))
+			.collect();
+		let galaxies: Vec<(usize, usize)> = (0..grid.len())
+			.flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)))
+			.filter(|&(x, y)| grid[y][x] == b'#')
+			.collect();
+
+		let distances = |factor: usize| {
+			let mut sum = 0;
+			for (i, a) in galaxies.iter().enumerate() {
+				for b in &galaxies[i + 1..] {
+					let crossed = empty_columns[a.0.min(b.0)..a.0.max(b.0)]
+						.iter()
+						.chain(&empty_rows[a.1.min(b.1)..a.1.max(b.1)]);
+					sum += crossed
+						.map(|&empty| if empty { factor } else { 1 })
+						.sum::<usize>();
+				}
+			}
+			sum
+		};
+		Solution(distances(2), distances(1_000_000))
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..40_usize) {
+			let input = generate::day_11(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,12 +1,22 @@
 use std::cmp::Ordering;
 use std::fmt;
 
-use aoc_utils::{ascii_int::parse_uint_unchecked, iteration::expect_n};
-use hashbrown::HashMap;
+use aoc_utils::{
+	ascii_int::parse_uint_unchecked, iteration::expect_n, memo::HashMemo, phase::span,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Hash, PartialEq, Eq, Clone)]
 struct PicrossState {
 	// bitmap 0 = not filled or unknown, 1 = filled
@@ -86,13 +96,13 @@ impl PicrossState {
 }
 
 // oh no, it's a hashmap-or-lose day
-type PicrossArrangements = HashMap<PicrossState, usize>;
+type PicrossArrangements = HashMemo<PicrossState, usize>;
 
 fn ends_in_one(num: u128) -> bool {
 	num & 1 == 1
 }
 
-fn sum_up_permutations(mut state: PicrossState, memo: &mut PicrossArrangements) -> usize {
+fn sum_up_permutations(memo: &mut PicrossArrangements, mut state: PicrossState) -> usize {
 	let mut sum = 0;
 
 	loop {
@@ -103,13 +113,7 @@ fn sum_up_permutations(mut state: PicrossState, memo: &mut PicrossArrangements)
 
 		// CHECK IF LAST BIT of filled or unknown IS 1
 		if ends_in_one(possibilities) {
-			sum += if let Some(memoized) = memo.get(&state) {
-				*memoized
-			} else {
-				let result = check_permutation(state.clone(), memo);
-				memo.insert(state.clone(), result);
-				result
-			};
+			sum += memo.call(state.clone(), check_permutation);
 		}
 
 		// CHECK IF LAST BIT of filled IS 1
@@ -129,7 +133,7 @@ fn sum_up_permutations(mut state: PicrossState, memo: &mut PicrossArrangements)
 
 const COUNT_BITS: u128 = 0b1111;
 
-fn check_permutation(mut state: PicrossState, memo: &mut PicrossArrangements) -> usize {
+fn check_permutation(memo: &mut PicrossArrangements, mut state: PicrossState) -> usize {
 	let count = state.counts & COUNT_BITS;
 	state.counts >>= 4;
 
@@ -175,23 +179,28 @@ fn check_permutation(mut state: PicrossState, memo: &mut PicrossArrangements) ->
 			0
 		}
 	} else {
-		sum_up_permutations(state, memo)
+		sum_up_permutations(memo, state)
 	}
 }
 
 pub fn solve(input: &[u8]) -> Solution {
-	let mut memo: PicrossArrangements = HashMap::new();
+	let parsing = span("parse");
+	let states: Vec<_> = input
+		.split(|b| *b == b'\n')
+		.filter(|l| !l.is_empty())
+		.map(PicrossState::parse_from_line_small_big)
+		.collect();
+	parsing.end();
+
+	let _counting = span("count");
+	let mut memo = PicrossArrangements::default();
 
 	let mut small_sum = 0;
 	let mut huge_sum = 0;
 
-	for (small_state, huge_state) in input
-		.split(|b| *b == b'\n')
-		.filter(|l| !l.is_empty())
-		.map(PicrossState::parse_from_line_small_big)
-	{
-		small_sum += sum_up_permutations(small_state, &mut memo);
-		huge_sum += sum_up_permutations(huge_state, &mut memo);
+	for (small_state, huge_state) in states {
+		small_sum += sum_up_permutations(&mut memo, small_state);
+		huge_sum += sum_up_permutations(&mut memo, huge_state);
 	}
 
 	Solution(small_sum, huge_sum)
@@ -200,21 +209,30 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::collections::HashMap;
 
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(21,525152),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(8193,45322533163795),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
 
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
 	#[rstest]
 	#[case(
 		"???.### 1,1,3",
@@ -241,14 +259,66 @@ mod tests {
 		(10, 506250),
 	)]
 	fn count_arrangements_examples(#[case] input: &str, #[case] expected: (usize, usize)) {
-		let mut memo: PicrossArrangements = HashMap::new();
+		let mut memo = PicrossArrangements::default();
 		let (small, big) = PicrossState::parse_from_line_small_big(input.as_bytes());
 		assert_eq!(
 			(
-				sum_up_permutations(small, &mut memo),
-				sum_up_permutations(big, &mut memo)
+				sum_up_permutations(&mut memo, small),
+				sum_up_permutations(&mut memo, big)
 			),
 			expected
 		);
 	}
+
+	/// Counts arrangements spring by spring, remembering the count for every number of
+	/// springs and groups left.
+	fn naive(input: &[u8]) -> Solution {
+		fn arrangements(
+			springs: &[u8],
+			groups: &[usize],
+			memo: &mut HashMap<(usize, usize), usize>,
+		) -> usize {
+			if groups.is_empty() {
+				return usize::from(!springs.contains(&b'#'));
+			}
+			if let Some(&count) = memo.get(&(springs.len(), groups.len())) {
+				return count;
+			}
+			let mut count = 0;
+			if springs.first().is_some_and(|spring| *spring != b'#') {
+				count += arrangements(&springs[1..], groups, memo);
+			}
+			let len = groups[0];
+			if springs.len() >= len
+				&& !springs[..len].contains(&b'.')
+				&& springs.get(len) != Some(&b'#')
+			{
+				count += arrangements(springs.get(len + 1..).unwrap_or(&[]), &groups[1..], memo);
+			}
+			memo.insert((springs.len(), groups.len()), count);
+			count
+		}
+
+		let mut folded = 0;
+		let mut unfolded = 0;
+		for line in std::str::from_utf8(input).unwrap().lines() {
+			let (springs, groups) = line.split_once(' ').unwrap();
+			let groups: Vec<usize> = groups.split(',').map(|n| n.parse().unwrap()).collect();
+			folded += arrangements(springs.as_bytes(), &groups, &mut HashMap::new());
+			unfolded += arrangements(
+				[springs; 5].join("?").as_bytes(),
+				&groups.repeat(5),
+				&mut HashMap::new(),
+			);
+		}
+		Solution(folded, unfolded)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..40_usize) {
+			let input = generate::day_12(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,6 +1,17 @@
+use aoc_utils::trim::trim_end_newline;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 fn find_mirror_point(lines: &[&[u8]]) -> (usize, usize) {
 	let width = lines[0].len();
 	let height = lines.len();
@@ -105,7 +116,11 @@ pub fn solve(input: &[u8]) -> Solution {
 	let mut unsmudged_sum = 0;
 	let mut smudged_sum = 0;
 
-	for line in input.split(|b| *b == b'\n') {
+	// an extra empty line ends the last block as well
+	for line in trim_end_newline(input)
+		.split(|b| *b == b'\n')
+		.chain([&b""[..]])
+	{
 		if line.is_empty() {
 			// end of block;
 			let (unsmudged, smudged) = find_mirror_point(&lines);
@@ -125,6 +140,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -132,11 +150,65 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(405,400),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(28895,31603),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Counts the differences across every possible line of reflection.
+	fn naive(input: &[u8]) -> Solution {
+		let mut perfect = 0;
+		let mut smudged = 0;
+		for pattern in std::str::from_utf8(input).unwrap().split("\n\n") {
+			let rows: Vec<&[u8]> = pattern.lines().map(str::as_bytes).collect();
+			let (width, height) = (rows[0].len(), rows.len());
+
+			let columns = (1..width).map(|column| {
+				let differences = rows
+					.iter()
+					.map(|row| {
+						(0..column.min(width - column))
+							.filter(|i| row[column - 1 - i] != row[column + i])
+							.count()
+					})
+					.sum();
+				(column, differences)
+			});
+			let lines = (1..height).map(|line| {
+				let differences = (0..line.min(height - line))
+					.map(|i| {
+						(0..width)
+							.filter(|&x| rows[line - 1 - i][x] != rows[line + i][x])
+							.count()
+					})
+					.sum();
+				(100 * line, differences)
+			});
+
+			for (value, differences) in columns.chain(lines) {
+				match differences {
+					0 => perfect += value,
+					1 => smudged += value,
+					_ => (),
+				}
+			}
+		}
+		Solution(perfect, smudged)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..20_usize) {
+			let input = generate::day_13(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,9 +1,18 @@
-use hashbrown::HashMap;
+use aoc_utils::{cycle::find_cycle_by, phase::span};
 use std::fmt;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 // Rocks are round, stones are cubic. Obviously.
 const ROCK: u8 = b'O';
 const STONE: u8 = b'#';
@@ -68,7 +77,7 @@ impl Grid {
 			.position(|b| *b == b'\n')
 			.expect("input must have a newline");
 		let line_width = width + 1;
-		let height = input.len() / line_width;
+		let height = input.len().div_ceil(line_width);
 
 		let solids: Vec<Solid> = input
 			.iter()
@@ -179,35 +188,26 @@ impl Grid {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut grid = Grid::parse(input);
+	parsing.end();
 
 	// 1 manual cycle for part 1
+	let tilting = span("tilt");
 	grid.up();
 	let load = grid.get_a_load_of_these_solids();
+	tilting.end();
+
+	let _cycling = span("spin cycles");
 	grid.left();
 	grid.down();
 	grid.right();
 
-	let mut memo: HashMap<Grid, usize> = HashMap::new();
-	let mut remaining: usize = 0;
-
-	for i in 1..1_000_000_000 {
-		grid.cycle();
-		if let Some(last_i) = memo.get(&grid) {
-			let diff = i - last_i;
-			let left_i = 1_000_000_000 - i - 1;
-			remaining = left_i % diff;
-			break;
-		} else {
-			memo.insert(grid.clone(), i);
-		}
-	}
-
-	for _ in 0..remaining {
-		grid.cycle();
-	}
-
-	let end_load = grid.get_a_load_of_these_solids();
+	// the grid after the first spin cycle is state 0, and loads tell most grids apart
+	let history = find_cycle_by(grid, Grid::cycle, Grid::get_a_load_of_these_solids);
+	let end_load = history
+		.state_at(1_000_000_000 - 1)
+		.get_a_load_of_these_solids();
 
 	Solution(load, end_load)
 }
@@ -215,6 +215,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -222,11 +225,83 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(136,64),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(108614,96447),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Rolls the rocks one step at a time until they stop, turning the platform
+	/// instead of tilting it in different directions, and remembers every platform seen
+	/// to find the loop.
+	fn naive(input: &[u8]) -> Solution {
+		type Platform = Vec<Vec<u8>>;
+
+		fn tilt_north(platform: &mut Platform) {
+			let mut moved = true;
+			while moved {
+				moved = false;
+				for y in 1..platform.len() {
+					for x in 0..platform[y].len() {
+						if platform[y][x] == b'O' && platform[y - 1][x] == b'.' {
+							platform[y][x] = b'.';
+							platform[y - 1][x] = b'O';
+							moved = true;
+						}
+					}
+				}
+			}
+		}
+		fn rotate_clockwise(platform: &Platform) -> Platform {
+			(0..platform[0].len())
+				.map(|x| (0..platform.len()).rev().map(|y| platform[y][x]).collect())
+				.collect()
+		}
+		fn load(platform: &Platform) -> usize {
+			platform
+				.iter()
+				.enumerate()
+				.map(|(y, row)| (platform.len() - y) * row.iter().filter(|b| **b == b'O').count())
+				.sum()
+		}
+
+		let mut platform: Platform = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.map(<[u8]>::to_vec)
+			.collect();
+
+		let mut tilted = platform.clone();
+		tilt_north(&mut tilted);
+		let first_load = load(&tilted);
+
+		let mut seen = vec![platform.clone()];
+		loop {
+			for _ in 0..4 {
+				tilt_north(&mut platform);
+				platform = rotate_clockwise(&platform);
+			}
+			if let Some(start) = seen.iter().position(|seen| *seen == platform) {
+				let len = seen.len() - start;
+				let last = start + (1_000_000_000 - start) % len;
+				return Solution(first_load, load(&seen[last]));
+			}
+			seen.push(platform.clone());
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..12_usize) {
+			let input = generate::day_14(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
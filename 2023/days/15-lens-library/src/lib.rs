@@ -1,6 +1,17 @@
+use aoc_utils::{phase::span, trim::trim_end_newline};
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 fn hash_once(a: u8, b: u8) -> u8 {
 	a.wrapping_add(b).wrapping_mul(17)
 }
@@ -26,11 +37,12 @@ fn bytes_to_id(bytes: &[u8]) -> LabelId {
 const EMPTY: Vec<(LabelId, u8)> = vec![];
 
 pub fn solve(input: &[u8]) -> Solution {
+	let steps = span("steps");
 	let mut hash_map: [Vec<(LabelId, u8)>; 256] = [EMPTY; 256];
 
 	let mut hash_sum = 0;
 
-	for part in input[..input.len() - 1].split(|b| *b == b',') {
+	for part in trim_end_newline(input).split(|b| *b == b',') {
 		let len = part.len();
 		let last = part[len - 1];
 		let op_minus = last == b'-';
@@ -53,7 +65,9 @@ pub fn solve(input: &[u8]) -> Solution {
 			}
 		}
 	}
+	steps.end();
 
+	let _focusing = span("focusing power");
 	let focusing_power = hash_map
 		.into_iter()
 		.enumerate()
@@ -73,6 +87,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -80,11 +97,60 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(1320,145),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(516657,210906),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Keeps the boxes as lists of labels and focal lengths.
+	fn naive(input: &[u8]) -> Solution {
+		let hash = |text: &str| text.bytes().fold(0, |acc, b| (acc + b as usize) * 17 % 256);
+		let mut boxes: Vec<Vec<(&str, usize)>> = vec![vec![]; 256];
+		let mut hash_sum = 0;
+
+		for step in std::str::from_utf8(input).unwrap().trim_end().split(',') {
+			hash_sum += hash(step);
+			if let Some(label) = step.strip_suffix('-') {
+				boxes[hash(label)].retain(|(other, _)| *other != label);
+			} else {
+				let (label, focal_length) = step.split_once('=').unwrap();
+				let focal_length = focal_length.parse().unwrap();
+				let lenses = &mut boxes[hash(label)];
+				match lenses.iter_mut().find(|(other, _)| *other == label) {
+					Some(lens) => lens.1 = focal_length,
+					None => lenses.push((label, focal_length)),
+				}
+			}
+		}
+
+		let focusing_power = boxes
+			.iter()
+			.enumerate()
+			.flat_map(|(box_index, lenses)| {
+				lenses
+					.iter()
+					.enumerate()
+					.map(move |(slot, (_, focal_length))| {
+						(box_index + 1) * (slot + 1) * focal_length
+					})
+			})
+			.sum();
+		Solution(hash_sum, focusing_power)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..200_usize) {
+			let input = generate::day_15(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
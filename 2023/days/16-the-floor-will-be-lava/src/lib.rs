@@ -1,8 +1,17 @@
-use aoc_utils::direction::*;
+use aoc_utils::{bits::BitSet, direction::*, phase::span};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Debug)]
 struct Map {
 	tiles: Vec<Tile>,
@@ -40,7 +49,7 @@ impl Map {
 			.position(|b| *b == b'\n')
 			.expect("must have at least 1 newline");
 		let line_width = width + 1;
-		let height = input.len() / line_width;
+		let height = input.len().div_ceil(line_width);
 
 		let mut tiles = Vec::with_capacity(width * height);
 		for y in 0..height {
@@ -56,51 +65,50 @@ impl Map {
 			width,
 			height,
 			e_max: len - 1,
-			s_max: len - width - 1,
+			s_max: len - width,
 		}
 	}
 
 	fn next_in_front_of_me(&self, pos: usize, facing: Direction) -> Option<usize> {
 		match facing {
-			North if pos > self.width => Some(pos - self.width),
-			East if (pos + 1) % self.width != 0 && pos < self.e_max => Some(pos + 1),
+			North if pos >= self.width => Some(pos - self.width),
+			East if !(pos + 1).is_multiple_of(self.width) && pos < self.e_max => Some(pos + 1),
 			South if pos < self.s_max => Some(pos + self.width),
-			West if pos % self.width != 0 => Some(pos - 1),
+			West if !pos.is_multiple_of(self.width) => Some(pos - 1),
 			_ => None,
 		}
 	}
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-struct Breadcrumb(u8);
-
-const BIT_NORTH: u8 = 0b0001;
-const BIT_EAST: u8 = 0b0010;
-const BIT_SOUTH: u8 = 0b0100;
-const BIT_WEST: u8 = 0b1000;
-
-impl Breadcrumb {
-	fn dir_bit(dir: Direction) -> u8 {
-		match dir {
-			North => BIT_NORTH,
-			East => BIT_EAST,
-			South => BIT_SOUTH,
-			West => BIT_WEST,
+/// The tiles beams went through facing every direction, and the tiles any beam went
+/// through.
+#[derive(Debug)]
+struct Breadcrumbs {
+	by_direction: [BitSet; 4],
+	energized: BitSet,
+}
+
+impl Breadcrumbs {
+	fn new(tiles: usize) -> Self {
+		Self {
+			by_direction: std::array::from_fn(|_| BitSet::with_capacity(tiles)),
+			energized: BitSet::with_capacity(tiles),
 		}
 	}
 
-	fn insert(&mut self, dir: Direction) -> bool {
-		let dir_bit = Breadcrumb::dir_bit(dir);
-		if self.0 & dir_bit != 0 {
-			false
-		} else {
-			self.0 |= dir_bit;
-			true
+	fn insert(&mut self, position: usize, dir: Direction) -> bool {
+		if !self.by_direction[dir as usize].insert(position) {
+			return false;
 		}
+		self.energized.insert(position);
+		true
 	}
 
-	fn is_empty(&self) -> bool {
-		self.0 == 0
+	fn count_reset(&mut self) -> usize {
+		let energized = self.energized.len();
+		self.energized.clear();
+		self.by_direction.iter_mut().for_each(BitSet::clear);
+		energized
 	}
 }
 
@@ -115,9 +123,9 @@ impl<'a> MazeRunner<'a> {
 	fn run_to(
 		&mut self,
 		next_position: usize,
-		breadcrumbs: &mut [Breadcrumb],
+		breadcrumbs: &mut Breadcrumbs,
 	) -> Option<Option<Self>> {
-		let put_down = breadcrumbs[next_position].insert(self.facing);
+		let put_down = breadcrumbs.insert(next_position, self.facing);
 		if !put_down {
 			// we can stop, since we went to this spot in this direction already
 			// println!("saved!");
@@ -151,15 +159,13 @@ impl<'a> MazeRunner<'a> {
 		}
 	}
 
-	fn run(&mut self, breadcrumbs: &mut [Breadcrumb]) -> Option<Option<Self>> {
+	fn run(&mut self, breadcrumbs: &mut Breadcrumbs) -> Option<Option<Self>> {
 		let next_position = self.map.next_in_front_of_me(self.position, self.facing)?;
 		self.run_to(next_position, breadcrumbs)
 	}
 }
 
-const EMPTY_BREADCRUMB: Breadcrumb = Breadcrumb(0);
-
-fn follow_to_end(mut runner: MazeRunner, breadcrumbs: &mut [Breadcrumb]) {
+fn follow_to_end(mut runner: MazeRunner, breadcrumbs: &mut Breadcrumbs) {
 	match runner.run(breadcrumbs) {
 		None => (),
 		Some(None) => follow_to_end(runner, breadcrumbs),
@@ -170,7 +176,7 @@ fn follow_to_end(mut runner: MazeRunner, breadcrumbs: &mut [Breadcrumb]) {
 	}
 }
 
-fn run_the_maze(map: &Map, breadcrumbs: &mut [Breadcrumb], start_pos: usize, start_dir: Direction) {
+fn run_the_maze(map: &Map, breadcrumbs: &mut Breadcrumbs, start_pos: usize, start_dir: Direction) {
 	let mut runner = MazeRunner {
 		map,
 		position: start_pos,
@@ -190,43 +196,35 @@ fn run_the_maze(map: &Map, breadcrumbs: &mut [Breadcrumb], start_pos: usize, sta
 	}
 }
 
-fn count_reset_breadcrumbs(crumbs: &mut [Breadcrumb]) -> usize {
-	let mut sum: usize = 0;
-
-	for crumb in crumbs.iter_mut() {
-		if !crumb.is_empty() {
-			sum += 1;
-			crumb.0 = 0;
-		}
-	}
-
-	sum
-}
-
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let map = Map::new(input);
-	let mut breadcrumbs: Vec<Breadcrumb> = vec![EMPTY_BREADCRUMB; map.width * map.height];
+	let mut breadcrumbs = Breadcrumbs::new(map.width * map.height);
+	parsing.end();
 
 	// first run at top left
+	let top_left = span("top left");
 	run_the_maze(&map, &mut breadcrumbs, 0, East);
-	let energized_top_left = count_reset_breadcrumbs(&mut breadcrumbs);
+	let energized_top_left = breadcrumbs.count_reset();
+	top_left.end();
 
+	let _all_edges = span("all edges");
 	// todo skip first and set to energized_top_left
 	let mut energized_max = 0;
 
 	for i in 0..map.height {
 		run_the_maze(&map, &mut breadcrumbs, i * map.width, East);
-		let energized = count_reset_breadcrumbs(&mut breadcrumbs);
+		let energized = breadcrumbs.count_reset();
 		energized_max = energized_max.max(energized);
 
 		run_the_maze(&map, &mut breadcrumbs, (i + 1) * map.width - 1, West);
-		let energized = count_reset_breadcrumbs(&mut breadcrumbs);
+		let energized = breadcrumbs.count_reset();
 		energized_max = energized_max.max(energized);
 	}
 
 	for i in 0..map.width {
 		run_the_maze(&map, &mut breadcrumbs, i, South);
-		let energized = count_reset_breadcrumbs(&mut breadcrumbs);
+		let energized = breadcrumbs.count_reset();
 		energized_max = energized_max.max(energized);
 
 		run_the_maze(
@@ -235,48 +233,92 @@ pub fn solve(input: &[u8]) -> Solution {
 			(map.height - 1) * map.width + i,
 			North,
 		);
-		let energized = count_reset_breadcrumbs(&mut breadcrumbs);
+		let energized = breadcrumbs.count_reset();
 		energized_max = energized_max.max(energized);
 	}
 
-	// for (i, crumb) in breadcrumbs.iter().enumerate() {
-	// 	if (i + 1) % map.width == 0 {
-	// 		if crumb.0 > 0 {
-	// 			println!("#");
-	// 		} else {
-	// 			println!(".")
-	// 		}
-	// 	} else {
-	// 		if crumb.0 > 0 {
-	// 			print!("#");
-	// 		} else {
-	// 			print!(".")
-	// 		}
-
-	// 		// print!("{:>2} ", crumb.0);
-	// 	}
-	// }
-	// //
-	// let energized_top_left = count_reset_breadcrumbs(&mut breadcrumbs);
-
 	Solution(energized_top_left, energized_max)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::collections::HashSet;
 
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(46,51),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(8249,8444),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Follows every beam through the grid, one tile and direction at a time.
+	fn naive(input: &[u8]) -> Solution {
+		let grid: Vec<&[u8]> = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.collect();
+		let (width, height) = (grid[0].len(), grid.len());
+
+		// directions: 0 north, 1 east, 2 south, 3 west
+		let energized = |start: (usize, usize, usize)| {
+			let mut seen = HashSet::new();
+			let mut beams = vec![start];
+			while let Some((x, y, direction)) = beams.pop() {
+				if !seen.insert((x, y, direction)) {
+					continue;
+				}
+				let directions = match (grid[y][x], direction) {
+					(b'/', _) => vec![[1, 0, 3, 2][direction]],
+					(b'\\', _) => vec![[3, 2, 1, 0][direction]],
+					(b'|', 1 | 3) => vec![0, 2],
+					(b'-', 0 | 2) => vec![1, 3],
+					_ => vec![direction],
+				};
+				for direction in directions {
+					let next = match direction {
+						0 => y.checked_sub(1).map(|y| (x, y)),
+						1 => Some((x + 1, y)).filter(|(x, _)| *x < width),
+						2 => Some((x, y + 1)).filter(|(_, y)| *y < height),
+						_ => x.checked_sub(1).map(|x| (x, y)),
+					};
+					if let Some((x, y)) = next {
+						beams.push((x, y, direction));
+					}
+				}
+			}
+			seen.iter()
+				.map(|(x, y, _)| (x, y))
+				.collect::<HashSet<_>>()
+				.len()
+		};
+
+		let starts = (0..height)
+			.flat_map(|y| [(0, y, 1), (width - 1, y, 3)])
+			.chain((0..width).flat_map(|x| [(x, 0, 2), (x, height - 1, 0)]));
+		Solution(energized((0, 0, 1)), starts.map(energized).max().unwrap())
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..25_usize) {
+			let input = generate::day_16(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,66 +1,70 @@
 use std::iter::successors;
 
-use aoc_utils::direction::*;
-use pathfinding::prelude::astar;
+use aoc_utils::{
+	direction::*,
+	phase::span,
+	point::Point2,
+	search::{astar_with, Buckets},
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Point(i16, i16);
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
 
-impl Point {
-	fn distance(&self, other: &Point) -> u16 {
-		self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
-	}
-
-	fn neighbour(&self, direction: &Direction) -> Point {
-		match direction {
-			North => Point(self.0, self.1 - 1),
-			East => Point(self.0 + 1, self.1),
-			South => Point(self.0, self.1 + 1),
-			West => Point(self.0 - 1, self.1),
-		}
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
 	}
 }
 
-fn find_lowest_heat_loss(grid: &Grid, min_step: usize, max_step: usize) -> u16 {
-	let start_point = Point(0, 0);
-	let end_point = Point(grid.width - 1, grid.height - 1);
+type Point = Point2<i16>;
+
+fn find_lowest_heat_loss(grid: &Grid, min_step: usize, max_step: usize) -> usize {
+	let start_point = Point::ORIGIN;
+	let end_point = Point::new(grid.width - 1, grid.height - 1);
 	let start_direction: Option<Direction> = None;
 	let successor_count = max_step - min_step + 1;
 
-	let (_, lowest_loss) = astar(
-		&(start_point, start_direction),
+	// every point with the direction the crucible came from, if any
+	let state_id = |&(Point { x, y }, facing): &(Point, Option<Direction>)| {
+		(y * grid.width + x) as usize * 5 + facing.map_or(0, |dir| dir as usize + 1)
+	};
+
+	let (_, lowest_loss) = astar_with(
+		state_id,
+		Buckets::new(),
+		(start_point, start_direction),
 		|(point, facing)| {
 			// "facing == None" means we're just starting and can go south or east!
 			let (widdershins, clockwise) = facing
 				.map(|dir| (dir.turn_widdershins(), dir.turn_clockwise()))
 				.unwrap_or((South, East));
 
-			let first_widdershins = grid.get_point_and_heat(point.neighbour(&widdershins));
+			let first_widdershins = grid.get_point_and_heat(*point + widdershins);
 
 			let widdershins_points = successors(first_widdershins, move |(point, cost)| {
-				grid.get_point_and_heat(point.neighbour(&widdershins))
+				grid.get_point_and_heat(*point + widdershins)
 					.map(|(next_point, next_cost)| (next_point, cost + next_cost))
 			})
 			.skip(min_step - 1)
 			.take(successor_count)
-			.map(move |(point, cost)| ((point, Some(widdershins)), cost));
+			.map(move |(point, cost)| ((point, Some(widdershins)), cost as usize));
 
-			let first_clockwise = grid.get_point_and_heat(point.neighbour(&clockwise));
+			let first_clockwise = grid.get_point_and_heat(*point + clockwise);
 
 			let clock_points = successors(first_clockwise, move |(point, cost)| {
-				grid.get_point_and_heat(point.neighbour(&clockwise))
+				grid.get_point_and_heat(*point + clockwise)
 					.map(|(next_point, next_cost)| (next_point, cost + next_cost))
 			})
 			.skip(min_step - 1)
 			.take(successor_count)
-			.map(move |(point, cost)| ((point, Some(clockwise)), cost));
+			.map(move |(point, cost)| ((point, Some(clockwise)), cost as usize));
 
 			widdershins_points.chain(clock_points)
 		},
-		|(point, _)| point.distance(&end_point),
+		|(point, _)| point.manhattan_distance(end_point),
 		|(point, _)| *point == end_point,
 	)
 	.expect("must have shortest path");
@@ -82,7 +86,7 @@ impl<'i> Grid<'i> {
 			.position(|b| *b == b'\n')
 			.expect("input has at least one newline");
 		let line_width = width + 1;
-		let height = input.len() / (line_width);
+		let height = input.len().div_ceil(line_width);
 		Self {
 			input,
 			height: (height).try_into().expect("height fits into i16"),
@@ -91,11 +95,11 @@ impl<'i> Grid<'i> {
 		}
 	}
 
-	fn is_valid_point(&self, &Point(x, y): &Point) -> bool {
+	fn is_valid_point(&self, &Point { x, y }: &Point) -> bool {
 		x >= 0 && x < self.width && y >= 0 && y < self.height
 	}
 
-	fn get_heat_loss_at(&self, &Point(x, y): &Point) -> u16 {
+	fn get_heat_loss_at(&self, &Point { x, y }: &Point) -> u16 {
 		let index = (y * self.line_width + x) as usize;
 		(self.input[index] - b'0') as u16
 	}
@@ -113,19 +117,28 @@ impl<'i> Grid<'i> {
 pub fn solve(input: &[u8]) -> Solution {
 	let grid = Grid::parse(input);
 
+	let crucible = span("crucible");
 	let lowest_heat_loss = find_lowest_heat_loss(&grid, 1, 3);
+	crucible.end();
+
+	let ultra_crucible = span("ultra crucible");
 	let lowest_heat_loss_ultra_crucible = find_lowest_heat_loss(&grid, 4, 10);
+	ultra_crucible.end();
 
-	Solution(
-		lowest_heat_loss as usize,
-		lowest_heat_loss_ultra_crucible as usize,
-	)
+	Solution(lowest_heat_loss, lowest_heat_loss_ultra_crucible)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::{
+		cmp::Reverse,
+		collections::{BinaryHeap, HashSet},
+	};
 
 	#[rstest]
 	#[case::example(
@@ -136,11 +149,71 @@ mod tests {
 		include_bytes!("../inputs/example_2.txt"),
 		Solution(59,71),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(686,801),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	#[case::example_2(include_bytes!("../inputs/example_2.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Dijkstra over positions, directions and how far the crucible went straight.
+	fn naive(input: &[u8]) -> Solution {
+		let grid: Vec<&[u8]> = input
+			.split(|b| *b == b'\n')
+			.filter(|line| !line.is_empty())
+			.collect();
+		let (width, height) = (grid[0].len(), grid.len());
+
+		// directions: 0 north, 1 east, 2 south, 3 west
+		let least_heat_loss = |min: usize, max: usize| {
+			let mut visited = HashSet::new();
+			let mut queue = BinaryHeap::from([Reverse((0, 0, 0, 1, 0)), Reverse((0, 0, 0, 2, 0))]);
+			while let Some(Reverse((loss, x, y, direction, straight))) = queue.pop() {
+				if !visited.insert((x, y, direction, straight)) {
+					continue;
+				}
+				if (x, y) == (width - 1, height - 1) && straight >= min {
+					return loss;
+				}
+				for next in 0..4 {
+					let turns = next != direction;
+					if next == (direction + 2) % 4
+						|| (turns && straight < min)
+						|| (!turns && straight == max)
+					{
+						continue;
+					}
+					let position = match next {
+						0 => y.checked_sub(1).map(|y| (x, y)),
+						1 => Some((x + 1, y)).filter(|(x, _)| *x < width),
+						2 => Some((x, y + 1)).filter(|(_, y)| *y < height),
+						_ => x.checked_sub(1).map(|x| (x, y)),
+					};
+					if let Some((x, y)) = position {
+						let loss = loss + (grid[y][x] - b'0') as usize;
+						let straight = if turns { 1 } else { straight + 1 };
+						queue.push(Reverse((loss, x, y, next, straight)));
+					}
+				}
+			}
+			unreachable!("the end is always reachable");
+		};
+
+		Solution(least_heat_loss(1, 3), least_heat_loss(4, 10))
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 5..20_usize) {
+			let input = generate::day_17(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
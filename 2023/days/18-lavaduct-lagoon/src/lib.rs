@@ -1,120 +1,193 @@
 use aoc_utils::{
 	ascii_int::{parse_uint_hex_lowercase_unchecked, parse_uint_unchecked},
+	direction::*,
+	phase::span,
+	point::Point2,
+	polygon::Trace,
 	trim::trim_end_newline,
 };
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
-pub fn solve(input: &[u8]) -> Solution {
-	// current x mostly cancels out in shoelace sum, it's irrelevant to track
-	let mut y: isize = 0;
-	let mut shoelace_sum: isize = 0;
-	let mut points_amount: usize = 0;
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
 
-	let mut y_big: isize = 0;
-	let mut shoelace_sum_big: isize = 0;
-	let mut points_amount_big: usize = 0;
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
+pub fn solve(input: &[u8]) -> Solution {
+	let digging = span("dig");
+	let mut trench = Trace::new(Point2::new(0_i64, 0));
+	let mut big_trench = Trace::new(Point2::new(0_i64, 0));
 
 	for line in trim_end_newline(input).split(|b| *b == b'\n') {
-		let dir = line[0];
 		let line_len = line.len();
 
+		let direction = match line[0] {
+			b'U' => North,
+			b'D' => South,
+			b'L' => West,
+			b'R' => East,
+			_ => panic!("unexpected dir"),
+		};
 		let amount = parse_uint_unchecked(&line[2..(line_len - 10)]);
-
-		points_amount += amount;
-		let amount = amount as isize;
-
-		// generalisations of shoelace sums for <amount> increments in one direction
-		match dir {
-			// Up
-			// Shoelace x part always evaluates to 0, so the product is 0
-			b'U' => {
-				y -= amount;
-			}
-			// Down
-			// Shoelace x part always evaluates to 0, so the product is 0
-			b'D' => {
-				y += amount;
-			}
-			// Left
-			// shoelace sum with diff (-1, 0) per point
-			// (xn - xn1) part always evulates to -1
-			// (yn + yn1) part always evaluates to y + y
-			b'L' => {
-				shoelace_sum += 2 * y * amount;
-			}
-			// Right
-			// shoelace sum with diff (1, 0) per point
-			// (xn - xn1) part always evulates to 1
-			// (yn + yn1) part always evaluates to y + y
-			b'R' => {
-				shoelace_sum -= 2 * y * amount;
-			}
+		trench.step(direction, amount as i64);
+
+		// the colour is the actual instruction: 5 hex digits of length and a direction
+		let direction = match line[line_len - 2] {
+			b'3' => North,
+			b'1' => South,
+			b'2' => West,
+			b'0' => East,
 			_ => panic!("unexpected dir"),
 		};
-
 		let hex = &line[(line_len - 7)..(line_len - 2)];
-		let dir = line[line_len - 2];
-
 		let amount = parse_uint_hex_lowercase_unchecked(hex);
-
-		points_amount_big += amount;
-
-		let amount = amount as isize;
-
-		match dir {
-			// Up
-			b'3' => {
-				y_big -= amount;
-			}
-			// Down
-			b'1' => {
-				y_big += amount;
-			}
-			// Left
-			b'2' => {
-				shoelace_sum_big += 2 * y_big * amount;
-			}
-			// Right
-			b'0' => {
-				shoelace_sum_big -= 2 * y_big * amount;
-			}
-			_ => panic!("unexpected dir"),
-		};
+		big_trench.step(direction, amount as i64);
 	}
-
-	// shoelace wraparound will always evaluate to 0 due to start at 0,0!
-	// so it's enough to add up all the determinants of x0..xN without the additional (xN, x0)
-
-	let area = shoelace_sum.unsigned_abs() / 2;
-	let points_inside = area - points_amount / 2 + 1;
-	let points_total = points_inside + points_amount;
-
-	let area_big = shoelace_sum_big.unsigned_abs() / 2;
-	let points_inside_big = area_big - points_amount_big / 2 + 1;
-	let points_total_big = points_inside_big + points_amount_big;
-
-	// println!("{points_amount_big}");
-
-	Solution(points_total, points_total_big)
+	digging.end();
+
+	let _area = span("area");
+	// the trench is dug a whole cube wide, so all of its points count
+	Solution(
+		trench.enclosed_points() as usize,
+		big_trench.enclosed_points() as usize,
+	)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(62,952408144115),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(40745,90111113594927),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// A direction (`R`, `D`, `L` or `U`) and how far to dig.
+	type Move = (u8, i64);
+
+	/// Digs the trench on a grid compressed to the corners' coordinates and fills
+	/// whatever can't be reached from the outside.
+	fn lagoon(moves: &[Move]) -> usize {
+		let mut corners = vec![(0_i64, 0_i64)];
+		for &(direction, len) in moves {
+			let (x, y) = *corners.last().unwrap();
+			corners.push(match direction {
+				b'R' => (x + len, y),
+				b'L' => (x - len, y),
+				b'D' => (x, y + len),
+				_ => (x, y - len),
+			});
+		}
+
+		// every cell of the compressed grid spans from one edge to the next
+		let edges = |values: Vec<i64>| {
+			let (min, max) = (*values.iter().min().unwrap(), *values.iter().max().unwrap());
+			let mut edges: Vec<i64> = values.iter().flat_map(|v| [*v, v + 1]).collect();
+			edges.extend([min - 1, max + 2]);
+			edges.sort_unstable();
+			edges.dedup();
+			edges
+		};
+		let xs = edges(corners.iter().map(|c| c.0).collect());
+		let ys = edges(corners.iter().map(|c| c.1).collect());
+		let index = |edges: &[i64], value: i64| edges.binary_search(&value).unwrap();
+
+		let mut trench = vec![vec![false; xs.len() - 1]; ys.len() - 1];
+		for pair in corners.windows(2) {
+			let (a, b) = (pair[0], pair[1]);
+			for row in &mut trench[index(&ys, a.1.min(b.1))..index(&ys, a.1.max(b.1) + 1)] {
+				row[index(&xs, a.0.min(b.0))..index(&xs, a.0.max(b.0) + 1)].fill(true);
+			}
+		}
+
+		let mut outside = vec![vec![false; xs.len() - 1]; ys.len() - 1];
+		let mut stack = vec![(0, 0)];
+		while let Some((row, column)) = stack.pop() {
+			if trench[row][column] || outside[row][column] {
+				continue;
+			}
+			outside[row][column] = true;
+			if row > 0 {
+				stack.push((row - 1, column));
+			}
+			if row + 1 < trench.len() {
+				stack.push((row + 1, column));
+			}
+			if column > 0 {
+				stack.push((row, column - 1));
+			}
+			if column + 1 < trench[row].len() {
+				stack.push((row, column + 1));
+			}
+		}
+
+		let mut area = 0;
+		for row in 0..ys.len() - 1 {
+			for column in 0..xs.len() - 1 {
+				if !outside[row][column] {
+					area += ((xs[column + 1] - xs[column]) * (ys[row + 1] - ys[row])) as usize;
+				}
+			}
+		}
+		area
+	}
+
+	/// Fills the lagoons of both plans.
+	fn naive(input: &[u8]) -> Solution {
+		let (plan, colours): (Vec<Move>, Vec<Move>) = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| {
+				let parts: Vec<&str> = line.split(' ').collect();
+				let hex = &parts[2][2..8];
+				(
+					(parts[0].as_bytes()[0], parts[1].parse().unwrap()),
+					(
+						b"RDLU"[hex[5..].parse::<usize>().unwrap()],
+						i64::from_str_radix(&hex[..5], 16).unwrap(),
+					),
+				)
+			})
+			.unzip();
+		Solution(lagoon(&plan), lagoon(&colours))
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..30_usize) {
+			let input = generate::day_18(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
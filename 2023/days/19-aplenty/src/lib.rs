@@ -1,9 +1,20 @@
-use aoc_utils::{ascii_int::parse_uint_unchecked, iteration::expect_n, trim::trim_end_newline};
+use aoc_utils::{
+	ascii_int::parse_uint_unchecked, iteration::expect_n, phase::span, trim::trim_end_newline,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
-// 5 bits per char, (repr. 0-25, lowercase a-z)
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
+// 5 bits per char, (repr. 1-26, lowercase a-z, so a leading 'a' still counts)
 type WorkflowName = usize;
 type PartRating = usize;
 
@@ -79,12 +90,12 @@ impl Instruction {
 
 fn encode_name(name: &[u8]) -> WorkflowName {
 	name.iter().fold(0, |acc, ascii_char| {
-		(acc << 5) | (*ascii_char - b'a') as WorkflowName
+		(acc << 5) | (*ascii_char - b'a' + 1) as WorkflowName
 	})
 }
 
 // "in" encoded according to the scheme above
-const IN_NAME: usize = 0b01000_01101;
+const IN_NAME: usize = 0b01001_01110;
 
 #[derive(Debug)]
 struct Part {
@@ -253,6 +264,7 @@ pub fn solve(input: &[u8]) -> Solution {
 	let mut line_iter = trim_end_newline(input).split(|b| *b == b'\n');
 
 	// part 1, collect instructions
+	let parsing = span("workflows");
 	for line in line_iter.by_ref().take_while(|line| !line.is_empty()) {
 		let brace_pos = line
 			.iter()
@@ -268,7 +280,9 @@ pub fn solve(input: &[u8]) -> Solution {
 				.collect(),
 		);
 	}
+	parsing.end();
 
+	let sorting = span("parts");
 	let mut accepted_sum = 0;
 
 	for part in line_iter.map(Part::from_bytes) {
@@ -316,6 +330,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		}
 	}
 
+	sorting.end();
+
+	let _combining = span("combinations");
 	let part_range = PartRange::new();
 
 	let combinations = find_all_combinations(&workflows, part_range, IN_NAME, 0);
@@ -326,18 +343,132 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::collections::HashMap;
 
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(19114,167409079868000),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(280909,116138474394508),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Runs every part through the workflows. For the combinations, only one part out of
+	/// every block of ratings that all rules treat alike needs to be run.
+	fn naive(input: &[u8]) -> Solution {
+		let text = std::str::from_utf8(input).unwrap();
+		let (workflows, parts) = text.split_once("\n\n").unwrap();
+		let workflows: HashMap<&str, Vec<&str>> = workflows
+			.lines()
+			.map(|line| {
+				let (name, rules) = line[..line.len() - 1].split_once('{').unwrap();
+				(name, rules.split(',').collect())
+			})
+			.collect();
+		let register = |condition: &str| "xmas".find(&condition[..1]).unwrap();
+
+		let accepted = |part: [usize; 4]| {
+			let mut name = "in";
+			loop {
+				for rule in &workflows[name] {
+					let target = match rule.split_once(':') {
+						None => *rule,
+						Some((condition, target)) => {
+							let value: usize = condition[2..].parse().unwrap();
+							let rating = part[register(condition)];
+							let holds = match condition.as_bytes()[1] {
+								b'<' => rating < value,
+								_ => rating > value,
+							};
+							if !holds {
+								continue;
+							}
+							target
+						}
+					};
+					match target {
+						"A" => return true,
+						"R" => return false,
+						next => {
+							name = next;
+							break;
+						}
+					}
+				}
+			}
+		};
+
+		let accepted_sum = parts
+			.lines()
+			.map(|line| {
+				let ratings: Vec<usize> = line[1..line.len() - 1]
+					.split(',')
+					.map(|rating| rating[2..].parse().unwrap())
+					.collect();
+				[ratings[0], ratings[1], ratings[2], ratings[3]]
+			})
+			.filter(|part| accepted(*part))
+			.map(|part| part.iter().sum::<usize>())
+			.sum();
+
+		let mut starts = vec![vec![1, 4001]; 4];
+		for (condition, _) in workflows
+			.values()
+			.flatten()
+			.filter_map(|rule| rule.split_once(':'))
+		{
+			let value: usize = condition[2..].parse().unwrap();
+			starts[register(condition)].push(match condition.as_bytes()[1] {
+				b'<' => value,
+				_ => value + 1,
+			});
+		}
+		let blocks: Vec<Vec<(usize, usize)>> = starts
+			.iter_mut()
+			.map(|starts| {
+				starts.sort_unstable();
+				starts.dedup();
+				starts
+					.windows(2)
+					.map(|pair| (pair[0], pair[1] - pair[0]))
+					.collect()
+			})
+			.collect();
+
+		let mut combinations = 0;
+		for &(x, x_len) in &blocks[0] {
+			for &(m, m_len) in &blocks[1] {
+				for &(a, a_len) in &blocks[2] {
+					for &(s, s_len) in &blocks[3] {
+						if accepted([x, m, a, s]) {
+							combinations += x_len * m_len * a_len * s_len;
+						}
+					}
+				}
+			}
+		}
+		Solution(accepted_sum, combinations)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..8_usize) {
+			let input = generate::day_19(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,11 +1,20 @@
 use std::{collections::VecDeque, fmt::Debug};
 
-use aoc_utils::{lcm::lowest_common_multiple, trim::trim_end_newline};
+use aoc_utils::{modular::lcm_all, phase::span, trim::trim_end_newline};
 use hashbrown::HashMap;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pulse {
 	Hi,
@@ -115,6 +124,7 @@ fn rx_conj_trigger_cables(circuit: &Circuit) -> Vec<usize> {
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut circuit = Circuit::default();
 
 	// cables[0] is broadcast
@@ -177,6 +187,9 @@ pub fn solve(input: &[u8]) -> Solution {
 		}
 	}
 
+	parsing.end();
+
+	let _pressing = span("button presses");
 	let rx_trigger_cables = rx_conj_trigger_cables(&circuit);
 	let cycles_to_find = rx_trigger_cables.len();
 	let mut rx_trigger_cable_cycles: Vec<(usize, Vec<usize>)> = rx_trigger_cables
@@ -291,11 +304,12 @@ pub fn solve(input: &[u8]) -> Solution {
 		panic!("unexpected complex cycles")
 	}
 
-	let min_button_pushed_rx = rx_trigger_cable_cycles
-		.into_iter()
-		.map(|(_, cycle)| cycle[0])
-		.reduce(lowest_common_multiple)
-		.unwrap_or(0);
+	let min_button_pushed_rx = if rx_trigger_cable_cycles.is_empty() {
+		0
+	} else {
+		lcm_all(rx_trigger_cable_cycles.iter().map(|(_, cycle)| cycle[0]))
+			.expect("button presses fit into usize")
+	};
 
 	Solution(lo_x_hi.unwrap(), min_button_pushed_rx)
 }
@@ -303,6 +317,7 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
 	use rstest::rstest;
 
 	#[rstest]
@@ -310,11 +325,16 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(32000000,0),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(731517480,244178746156661),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
 }
@@ -1,8 +1,20 @@
-use aoc_utils::{ascii_int::parse_uint, iteration::expect_n, trim::trim_end_newline};
+use aoc_utils::{
+	ascii_int::parse_uint, box_set::Cuboid, iteration::expect_n, phase::span,
+	trim::trim_end_newline,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Debug)]
 struct SandSlab {
 	z: (usize, usize),
@@ -11,21 +23,8 @@ struct SandSlab {
 	supported_by: Vec<usize>,
 }
 
-#[derive(Debug, Clone)]
-struct Plane {
-	x: (usize, usize),
-	y: (usize, usize),
-}
-
-impl Plane {
-	fn collides(&self, other: &Plane) -> bool {
-		range_collision(self.x, other.x) && range_collision(self.y, other.y)
-	}
-}
-
-fn range_collision<T: Ord>(a: (T, T), b: (T, T)) -> bool {
-	a.0 <= b.1 && a.1 >= b.0
-}
+/// The slab seen from above, in `x` and `y`.
+type Plane = Cuboid<2, usize>;
 
 impl SandSlab {
 	fn from_bytes(bytes: &[u8]) -> Self {
@@ -38,10 +37,7 @@ impl SandSlab {
 		debug_assert!(z1 >= z0);
 
 		SandSlab {
-			plane: Plane {
-				x: (x0, x1),
-				y: (y0, y1),
-			},
+			plane: Plane::new([x0, y0], [x1, y1]),
 			z: (z0, z1),
 			supporting: vec![],
 			supported_by: vec![],
@@ -73,18 +69,21 @@ fn count_all_dependants(
 }
 
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let mut slabs: Vec<_> = trim_end_newline(input)
 		.split(|b| *b == b'\n')
 		.map(SandSlab::from_bytes)
 		.collect();
+	parsing.end();
 
+	let settling = span("settle");
 	let mut x_max: usize = 0;
 	let mut y_max: usize = 0;
 	let mut z_max: usize = 0;
 
 	for slab in slabs.iter() {
-		x_max = x_max.max(slab.plane.x.1);
-		y_max = y_max.max(slab.plane.y.1);
+		x_max = x_max.max(slab.plane.max[0]);
+		y_max = y_max.max(slab.plane.max[1]);
 		z_max = z_max.max(slab.z.1);
 	}
 
@@ -97,7 +96,7 @@ pub fn solve(input: &[u8]) -> Solution {
 		.max()
 		.expect("must have at least one slab");
 
-	let mut settled_planes: Vec<Vec<(Plane, usize)>> = vec![vec![]; z_max];
+	let mut settled_planes: Vec<Vec<(Plane, usize)>> = vec![vec![]; z_max + 1];
 
 	let mut slab_supporting_map: Vec<Vec<usize>> = vec![vec![]; slabs.len()];
 
@@ -112,7 +111,7 @@ pub fn solve(input: &[u8]) -> Solution {
 				let collisions: Vec<usize> = settled_planes[z]
 					.iter()
 					.filter_map(|(plane, other_index)| {
-						if slab.plane.collides(plane) {
+						if slab.plane.intersects(plane) {
 							Some(*other_index)
 						} else {
 							None
@@ -140,13 +139,16 @@ pub fn solve(input: &[u8]) -> Solution {
 		slab.supported_by = collisions;
 
 		// we only need to add "roof" of current slab to the settled planes
-		settled_planes[slab.z.1].push((slab.plane.clone(), slab_index));
+		settled_planes[slab.z.1].push((slab.plane, slab_index));
 	}
 
-	for (slab, supporting) in slabs.iter_mut().zip(slab_supporting_map.into_iter()) {
+	for (slab, supporting) in slabs.iter_mut().zip(slab_supporting_map) {
 		slab.supporting = supporting;
 	}
 
+	settling.end();
+
+	let _disintegrating = span("disintegrate");
 	let mut safely_disintegratable = 0;
 	let mut chain_reaction_sum = 0;
 
@@ -171,18 +173,93 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
+	use std::collections::HashSet;
 
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(5,7),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(403,70189),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	type Brick = ([usize; 3], [usize; 3]);
+
+	/// Lets the bricks fall one step at a time and returns how many of them moved.
+	fn settle(bricks: &mut [Brick]) -> usize {
+		bricks.sort_by_key(|(start, _)| start[2]);
+		let cubes = |(start, end): &Brick| -> Vec<[usize; 3]> {
+			(start[0]..=end[0])
+				.flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+				.flat_map(|(x, y)| (start[2]..=end[2]).map(move |z| [x, y, z]))
+				.collect()
+		};
+
+		let mut occupied = HashSet::new();
+		let mut fallen = 0;
+		for brick in bricks.iter_mut() {
+			let mut fell = false;
+			while brick.0[2] > 1
+				&& cubes(brick)
+					.iter()
+					.all(|&[x, y, z]| !occupied.contains(&[x, y, z - 1]))
+			{
+				brick.0[2] -= 1;
+				brick.1[2] -= 1;
+				fell = true;
+			}
+			occupied.extend(cubes(brick));
+			fallen += usize::from(fell);
+		}
+		fallen
+	}
+
+	/// Settles the bricks, and then again without each one of them.
+	fn naive(input: &[u8]) -> Solution {
+		let mut bricks: Vec<Brick> = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| {
+				let [x0, y0, z0, x1, y1, z1] = expect_n(
+					line.split([',', '~']).map(|n| n.parse().unwrap()),
+					"has 6 coordinates",
+				);
+				([x0, y0, z0], [x1, y1, z1])
+			})
+			.collect();
+		settle(&mut bricks);
+
+		let mut safe = 0;
+		let mut falling = 0;
+		for index in 0..bricks.len() {
+			let mut rest = bricks.clone();
+			rest.remove(index);
+			let fallen = settle(&mut rest);
+			safe += usize::from(fallen == 0);
+			falling += fallen;
+		}
+		Solution(safe, falling)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..40_usize) {
+			let input = generate::day_22(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
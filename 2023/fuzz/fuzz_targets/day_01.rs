@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| day_01_trebuchet::solve(&input.bytes(b"0123456789onetwhrfuivsxg\n")));
+});
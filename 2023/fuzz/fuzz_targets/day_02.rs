@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| {
+		day_02_cube_conundrum::solve(&input.bytes(b"Game 0123456789:;, redgrnblu\n"))
+	});
+});
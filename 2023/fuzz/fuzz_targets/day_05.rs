@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| {
+		day_05_if_you_give_a_seed_a_fertilizer::solve(&input.bytes(b"seds:-tomap 0123456789\n\n"))
+	});
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| day_06_wait_for_it::solve(&input.bytes(b"TimeDstanc:   0123456789\n")));
+});
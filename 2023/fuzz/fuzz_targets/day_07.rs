@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| day_07_camel_cards::solve(&input.bytes(b"23456789TJQKA 0123456789\n")));
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| {
+		day_15_lens_library::solve(&input.bytes(b"abcdefghijklmnopqrstuvwxyz=-0123456789,"))
+	});
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| day_18_lavaduct_lagoon::solve(&input.bytes(b"RDLU 0123456789(#abcdef)\n")));
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {
+	aoc_fuzz::check(|| day_20_pulse_propagation::solve(&input.bytes(b"broadcaster%&-> ,abc\n")));
+});
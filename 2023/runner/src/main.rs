@@ -0,0 +1,147 @@
+use aoc_cli::{Day, Harness};
+use aoc_utils::{allocations, trim};
+use std::process::ExitCode;
+
+const DAYS: &[Day] = &[
+	Day::new(
+		1,
+		"Trebuchet?!",
+		"days/01-trebuchet/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_01_trebuchet::solve(input)),
+	),
+	Day::new(
+		2,
+		"Cube Conundrum",
+		"days/02-cube-conundrum/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_02_cube_conundrum::solve(input)),
+	),
+	Day::new(
+		3,
+		"Gear Ratios",
+		"days/03-gear-ratios/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_03_gear_ratios::solve(input)),
+	),
+	Day::new(
+		4,
+		"Scratchcards",
+		"days/04-scratchcards/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_04_scratchcards::solve(input)),
+	),
+	Day::new(
+		5,
+		"If You Give A Seed A Fertilizer",
+		"days/05-if-you-give-a-seed-a-fertilizer/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_05_if_you_give_a_seed_a_fertilizer::solve(input)),
+	),
+	Day::new(
+		6,
+		"Wait For It",
+		"days/06-wait-for-it/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_06_wait_for_it::solve(input)),
+	),
+	Day::new(
+		7,
+		"Camel Cards",
+		"days/07-camel-cards/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_07_camel_cards::solve(input)),
+	),
+	Day::new(
+		9,
+		"Mirage Maintenance",
+		"days/09-mirage-maintenance/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_09_mirage_maintenance::solve(input)),
+	),
+	Day::new(
+		10,
+		"Pipe Maze",
+		"days/10-pipe-maze/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_10_pipe_maze::shoelace_picks::solve(input)),
+	),
+	Day::new(
+		11,
+		"Cosmic Expansion",
+		"days/11-cosmic-expansion/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_11_cosmic_expansion::solve(input)),
+	),
+	Day::new(
+		12,
+		"Hot Springs",
+		"days/12-hot-springs/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_12_hot_springs::solve(input)),
+	),
+	Day::new(
+		13,
+		"Point of Incidence",
+		"days/13-point-of-incidence/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_13_point_of_incidence::solve(input)),
+	),
+	Day::new(
+		14,
+		"Parabolic Reflector Dish",
+		"days/14-parabolic-reflector-dish/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_14_parabolic_reflector_dish::solve(input)),
+	),
+	Day::new(
+		15,
+		"Lens Library",
+		"days/15-lens-library/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_15_lens_library::solve(input)),
+	),
+	Day::new(
+		16,
+		"The Floor Will Be Lava",
+		"days/16-the-floor-will-be-lava/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_16_the_floor_will_be_lava::solve(input)),
+	),
+	Day::new(
+		17,
+		"Clumsy Crucible",
+		"days/17-clumsy-crucible/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_17_clumsy_crucible::solve(input)),
+	),
+	Day::new(
+		18,
+		"Lavaduct Lagoon",
+		"days/18-lavaduct-lagoon/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_18_lavaduct_lagoon::solve(input)),
+	),
+	Day::new(
+		19,
+		"Aplenty",
+		"days/19-aplenty/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_19_aplenty::solve(input)),
+	),
+	Day::new(
+		20,
+		"Pulse Propagation",
+		"days/20-pulse-propagation/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_20_pulse_propagation::solve(input)),
+	),
+	Day::new(
+		22,
+		"Sand Slabs",
+		"days/22-sand-slabs/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_22_sand_slabs::solve(input)),
+	),
+];
+
+#[global_allocator]
+static ALLOCATOR: allocations::Counting = allocations::Counting;
+
+fn count_allocations(solve: &mut dyn FnMut()) -> String {
+	let ((), allocations) = allocations::count(solve);
+	allocations.to_string()
+}
+
+fn main() -> ExitCode {
+	let harness = Harness {
+		count_allocations: Some(count_allocations),
+		prepare_input: Some(trim::normalize),
+	};
+	aoc_cli::main_with(
+		2023,
+		concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+		DAYS,
+		harness,
+	)
+}
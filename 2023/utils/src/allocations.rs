@@ -0,0 +1,163 @@
+//! Counts the heap allocations of a solver.
+//!
+//! [`Counting`] wraps the system allocator and has to be registered by the binary. It only
+//! keeps count while [`count`] runs, so registering it just in case costs next to nothing.
+//! The year runners do, and report the allocations of `run --allocations`. Days that are
+//! meant to get by without any register it in their tests as well:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: allocations::Counting = allocations::Counting;
+//!
+//! let (solution, allocations) = allocations::count(|| solve(input));
+//! assert_eq!(allocations.count, 0, "day is marked as allocation free");
+//! ```
+//!
+//! Only allocations of the current thread are counted, so tests running in parallel do
+//! not get in each other's way.
+
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	cell::Cell,
+	fmt,
+	hint::black_box,
+	sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+/// The calls of [`count`] running, on any thread.
+static COUNTING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+	static COUNT: Cell<usize> = const { Cell::new(0) };
+	/// Bytes allocated minus bytes freed, since the thread started.
+	static CURRENT: Cell<isize> = const { Cell::new(0) };
+	static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn track(count: usize, bytes: isize) {
+	if COUNTING.load(Relaxed) == 0 {
+		return;
+	}
+	// The counters are gone while the thread shuts down, nothing is measured by then.
+	let _ = COUNT.try_with(|total| total.set(total.get() + count));
+	let _ = CURRENT.try_with(|current| {
+		current.set(current.get() + bytes);
+		let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+	});
+}
+
+/// The system allocator, counting allocations per thread.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		track(1, layout.size() as isize);
+		unsafe { System.alloc(layout) }
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		track(1, layout.size() as isize);
+		unsafe { System.alloc_zeroed(layout) }
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		track(1, new_size as isize - layout.size() as isize);
+		unsafe { System.realloc(ptr, layout, new_size) }
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		track(0, -(layout.size() as isize));
+		unsafe { System.dealloc(ptr, layout) }
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+	/// Allocations and reallocations.
+	pub count: usize,
+	/// Most bytes on the heap at once, on top of what was there before.
+	pub peak_bytes: usize,
+}
+
+impl fmt::Display for Allocations {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let plural = if self.count == 1 { "" } else { "s" };
+		write!(f, "{} allocation{plural}, peak ", self.count)?;
+		match self.peak_bytes {
+			bytes @ ..1024 => write!(f, "{bytes} B"),
+			bytes @ ..1_048_576 => write!(f, "{:.1} KiB", bytes as f64 / 1024.0),
+			bytes => write!(f, "{:.1} MiB", bytes as f64 / 1_048_576.0),
+		}
+	}
+}
+
+/// Runs `solve` and counts its allocations on this thread.
+///
+/// Panics if [`Counting`] is not the global allocator, instead of reporting none.
+pub fn count<T>(solve: impl FnOnce() -> T) -> (T, Allocations) {
+	struct Active;
+	impl Drop for Active {
+		fn drop(&mut self) {
+			COUNTING.fetch_sub(1, Relaxed);
+		}
+	}
+	COUNTING.fetch_add(1, Relaxed);
+	let _active = Active;
+
+	let before = COUNT.get();
+	drop(black_box(Box::new(0_u8)));
+	assert!(
+		COUNT.get() > before,
+		"allocations::Counting must be registered as #[global_allocator]"
+	);
+
+	let count = COUNT.get();
+	let current = CURRENT.get();
+	let outer_peak = PEAK.replace(current);
+
+	let result = solve();
+
+	let allocations = Allocations {
+		count: COUNT.get() - count,
+		peak_bytes: (PEAK.get() - current) as usize,
+	};
+	PEAK.set(PEAK.get().max(outer_peak));
+	(result, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[global_allocator]
+	static ALLOCATOR: Counting = Counting;
+
+	#[test]
+	fn counts_allocations() {
+		let (sum, allocations) = count(|| (1..=10_u64).sum::<u64>());
+		assert_eq!(sum, 55);
+		assert_eq!(allocations, Allocations::default());
+
+		let (_, allocations) = count(|| {
+			let mut numbers: Vec<u64> = Vec::with_capacity(100);
+			numbers.extend(0..100);
+			drop(black_box(numbers));
+			black_box(vec![0_u8; 10])
+		});
+		assert_eq!(
+			allocations,
+			Allocations {
+				count: 2,
+				peak_bytes: 800
+			}
+		);
+	}
+
+	#[test]
+	fn describes_allocations() {
+		let allocations = |count, peak_bytes| Allocations { count, peak_bytes }.to_string();
+		assert_eq!(allocations(0, 0), "0 allocations, peak 0 B");
+		assert_eq!(allocations(3, 20_070), "3 allocations, peak 19.6 KiB");
+		assert_eq!(allocations(1, 5 << 20), "1 allocation, peak 5.0 MiB");
+	}
+}
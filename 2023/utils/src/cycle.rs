@@ -0,0 +1,222 @@
+//! Finding where simulations start repeating themselves, to skip ahead a billion steps.
+//!
+//! States are stepped in place, which suits large states like grids. State 0 is the
+//! start, state `n` the one after `n` steps.
+
+use std::{
+	collections::{hash_map::DefaultHasher, HashMap},
+	hash::{Hash, Hasher},
+};
+
+/// The states before the first repeated one, and how many states repeat after them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+	pub prefix: usize,
+	pub len: usize,
+}
+
+impl Cycle {
+	/// The first step with the same state as `step`, which is less than `prefix + len`.
+	pub fn reduce(&self, step: usize) -> usize {
+		if step < self.prefix {
+			step
+		} else {
+			self.prefix + (step - self.prefix) % self.len
+		}
+	}
+}
+
+/// Brent's algorithm, which keeps only two states and steps about `prefix + 2 * len`
+/// times.
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&mut S)) -> Cycle {
+	// the hare races ahead in powers of two until it meets the tortoise, waiting at
+	// each power, which gives the length
+	let mut power = 1;
+	let mut len = 1;
+	let mut tortoise = start.clone();
+	let mut hare = start.clone();
+	step(&mut hare);
+	while tortoise != hare {
+		if power == len {
+			tortoise.clone_from(&hare);
+			power *= 2;
+			len = 0;
+		}
+		step(&mut hare);
+		len += 1;
+	}
+
+	// with the hare len steps ahead, both meet at the start of the cycle
+	let mut tortoise = start.clone();
+	let mut hare = start.clone();
+	for _ in 0..len {
+		step(&mut hare);
+	}
+	let mut prefix = 0;
+	while tortoise != hare {
+		step(&mut tortoise);
+		step(&mut hare);
+		prefix += 1;
+	}
+
+	Cycle { prefix, len }
+}
+
+/// Floyd's algorithm, which keeps only two states and steps about `3 * (prefix + len)`
+/// times. Mostly slower than [`brent`].
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&mut S)) -> Cycle {
+	// the hare goes twice as fast, so they meet once both are in the cycle
+	let mut tortoise = start.clone();
+	let mut hare = start.clone();
+	loop {
+		step(&mut tortoise);
+		step(&mut hare);
+		step(&mut hare);
+		if tortoise == hare {
+			break;
+		}
+	}
+
+	// the meeting point is as far from the start of the cycle as the start
+	let mut tortoise = start.clone();
+	let mut prefix = 0;
+	while tortoise != hare {
+		step(&mut tortoise);
+		step(&mut hare);
+		prefix += 1;
+	}
+
+	let mut len = 1;
+	step(&mut hare);
+	while tortoise != hare {
+		step(&mut hare);
+		len += 1;
+	}
+
+	Cycle { prefix, len }
+}
+
+/// The states of a simulation up to the end of its first cycle.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+	pub states: Vec<S>,
+	pub cycle: Cycle,
+}
+
+impl<S> History<S> {
+	/// The state after `step` steps, however many that are.
+	pub fn state_at(&self, step: usize) -> &S {
+		&self.states[self.cycle.reduce(step)]
+	}
+}
+
+/// Records every state until one repeats, which steps only `prefix + len` times.
+pub fn find_cycle<S: Clone + Hash + Eq>(start: S, step: impl FnMut(&mut S)) -> History<S> {
+	find_cycle_by(start, step, |state| {
+		let mut hasher = DefaultHasher::new();
+		state.hash(&mut hasher);
+		hasher.finish()
+	})
+}
+
+/// [`find_cycle`], looking states up by a `fingerprint` that is cheaper to compute than
+/// hashing the whole state. States with equal fingerprints are compared, so the
+/// fingerprint need not tell all states apart, it is only slow if it does not.
+pub fn find_cycle_by<S: Clone + PartialEq, K: Hash + Eq>(
+	start: S,
+	mut step: impl FnMut(&mut S),
+	mut fingerprint: impl FnMut(&S) -> K,
+) -> History<S> {
+	// the latest state with every fingerprint, and for every state the one before it
+	// with the same fingerprint
+	let mut latest: HashMap<K, usize> = HashMap::new();
+	let mut earlier: Vec<Option<usize>> = vec![];
+	let mut states = vec![];
+
+	let mut state = start;
+	loop {
+		let index = states.len();
+		let previous = latest.insert(fingerprint(&state), index);
+
+		let mut same_fingerprint = previous;
+		while let Some(other) = same_fingerprint {
+			if states[other] == state {
+				states.truncate(index);
+				return History {
+					states,
+					cycle: Cycle {
+						prefix: other,
+						len: index - other,
+					},
+				};
+			}
+			same_fingerprint = earlier[other];
+		}
+
+		earlier.push(previous);
+		states.push(state.clone());
+		step(&mut state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// The state after `steps` steps the slow way.
+	fn simulate(mut state: u32, step: impl Fn(&mut u32), steps: usize) -> u32 {
+		for _ in 0..steps {
+			step(&mut state);
+		}
+		state
+	}
+
+	#[test]
+	fn finds_cycles() {
+		let squares = |state: &mut u32| *state = (*state * *state + 1) % 1009;
+		let expected = {
+			let mut seen = vec![];
+			let mut state = 3;
+			while !seen.contains(&state) {
+				seen.push(state);
+				squares(&mut state);
+			}
+			let prefix = seen.iter().position(|&seen| seen == state).unwrap();
+			Cycle {
+				prefix,
+				len: seen.len() - prefix,
+			}
+		};
+		assert!(expected.prefix > 0 && expected.len > 1);
+
+		assert_eq!(brent(&3, squares), expected);
+		assert_eq!(floyd(&3, squares), expected);
+		let history = find_cycle(3, squares);
+		assert_eq!(history.cycle, expected);
+		assert_eq!(history.states.len(), expected.prefix + expected.len);
+
+		for step in [0, 5, expected.prefix + expected.len, 1_000_000_000] {
+			let state = simulate(3, squares, expected.reduce(step));
+			assert_eq!(*history.state_at(step), state);
+			if step < 10_000 {
+				assert_eq!(simulate(3, squares, step), state);
+			}
+		}
+	}
+
+	#[test]
+	fn finds_cycles_by_fingerprint() {
+		let counting = |state: &mut u32| *state = (*state + 1) % 10;
+		assert_eq!(brent(&7, counting), Cycle { prefix: 0, len: 10 });
+		assert_eq!(floyd(&7, counting), Cycle { prefix: 0, len: 10 });
+
+		// the parity tells hardly any states apart, but it still works
+		let history = find_cycle_by(7, counting, |state| state % 2);
+		assert_eq!(history.cycle, Cycle { prefix: 0, len: 10 });
+		assert_eq!(*history.state_at(1_000_000_003), 0);
+
+		let fixed = find_cycle(1, |_: &mut u32| {});
+		assert_eq!(fixed.cycle, Cycle { prefix: 0, len: 1 });
+		assert_eq!(brent(&1, |_: &mut u32| {}), fixed.cycle);
+	}
+}
@@ -0,0 +1,684 @@
+//! Random puzzle inputs of configurable size, for property tests against naive
+//! implementations and for benchmarking beyond the personal input.
+//!
+//! Every generator follows the format and the (sometimes implicit) guarantees of the
+//! real puzzle input, `size` roughly scales the input linearly or per side of a grid.
+
+use std::{collections::HashSet, io::Write, ops::Range};
+
+/// Seedable random number generator (SplitMix64), reproducible across platforms.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Rng(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number in `range`, which must not be empty.
+	pub fn range(&mut self, range: Range<usize>) -> usize {
+		assert!(!range.is_empty(), "empty range");
+		range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+	}
+
+	/// `true` with a probability of `numerator / denominator`.
+	pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+		self.range(0..denominator) < numerator
+	}
+
+	pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.range(0..items.len())]
+	}
+
+	/// A number with exactly `digits` digits.
+	pub fn digits(&mut self, digits: u32) -> usize {
+		self.range(10_usize.pow(digits - 1)..10_usize.pow(digits))
+	}
+
+	/// Shuffles `items` in place (Fisher-Yates).
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.range(0..i + 1));
+		}
+	}
+}
+
+const SPELLED: [&str; 9] = [
+	"one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Day 01: `size` lines of lowercase letters, digits and spelled out digits (which may
+/// overlap, like `oneight`), each with at least one actual digit.
+pub fn day_01(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut input = vec![];
+	for _ in 0..size {
+		let mut line = String::new();
+		for _ in 0..rng.range(1..12) {
+			match rng.range(0..10) {
+				0..=4 => line.push((b'a' + rng.range(0..26) as u8) as char),
+				5..=7 => {
+					let word = rng.pick(&SPELLED);
+					line.push_str(word);
+				}
+				_ => line.push((b'0' + rng.range(1..10) as u8) as char),
+			}
+		}
+		if !line.bytes().any(|b| b.is_ascii_digit()) {
+			let at = rng.range(0..line.len() + 1);
+			line.insert(at, (b'0' + rng.range(1..10) as u8) as char);
+		}
+		writeln!(input, "{line}").unwrap();
+	}
+	input
+}
+
+/// Day 02: `size` games of 1 to 6 grabs, each showing up to 20 cubes of every colour.
+pub fn day_02(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut input = vec![];
+	for game in 1..=size {
+		let grabs: Vec<String> = (0..rng.range(1..7))
+			.map(|_| {
+				let mut colours = ["red", "green", "blue"];
+				rng.shuffle(&mut colours);
+				colours[..rng.range(1..4)]
+					.iter()
+					.map(|colour| format!("{} {colour}", rng.range(1..21)))
+					.collect::<Vec<_>>()
+					.join(", ")
+			})
+			.collect();
+		writeln!(input, "Game {game}: {}", grabs.join("; ")).unwrap();
+	}
+	input
+}
+
+/// Day 03: a `size` by `size` engine schematic with numbers of 1 to 3 digits and
+/// symbols scattered between the dots.
+pub fn day_03(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let mut input = vec![];
+	for _ in 0..size {
+		let mut line = vec![];
+		while line.len() < size {
+			match rng.range(0..10) {
+				0..=5 => line.push(b'.'),
+				6 => line.push(*rng.pick(b"**#+$/=%@&-")),
+				_ => {
+					if line.last().is_some_and(u8::is_ascii_digit) {
+						line.push(b'.');
+					}
+					let digits = rng.range(1..4) as u32;
+					line.extend(rng.digits(digits).to_string().bytes());
+				}
+			}
+		}
+		line.truncate(size);
+		input.extend(line);
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 04: `size` scratchcards (at most 400) with distinct numbers of up to 2 digits.
+/// Like in the real input, no card wins copies of cards past the end of the table. Most
+/// cards win nothing, otherwise the number of copies would grow exponentially.
+pub fn day_04(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.clamp(1, 400);
+	let winning_count = rng.range(3..11);
+	let own_count = rng.range(winning_count..26);
+
+	let mut input = vec![];
+	for card in 1..=size {
+		let mut pool: Vec<usize> = (1..100).collect();
+		rng.shuffle(&mut pool);
+		let (winning, rest) = pool.split_at(winning_count);
+
+		let max_wins = winning_count.min(size - card);
+		let wins = if max_wins > 0 && rng.chance(1, 5) {
+			rng.range(1..max_wins + 1)
+		} else {
+			0
+		};
+		let mut own: Vec<usize> = winning[..wins]
+			.iter()
+			.chain(&rest[..own_count - wins])
+			.copied()
+			.collect();
+		rng.shuffle(&mut own);
+
+		write!(input, "Card {card:>3}:").unwrap();
+		for number in winning {
+			write!(input, " {number:>2}").unwrap();
+		}
+		write!(input, " |").unwrap();
+		for number in own {
+			write!(input, " {number:>2}").unwrap();
+		}
+		writeln!(input).unwrap();
+	}
+	input
+}
+
+/// Day 05: `size` seed ranges of up to 100 seeds and 7 maps of about `size` ranges
+/// each, which cover the seeds' span of `100 * size` ids with a few gaps.
+pub fn day_05(rng: &mut Rng, size: usize) -> Vec<u8> {
+	const MAPS: [&str; 7] = [
+		"seed-to-soil",
+		"soil-to-fertilizer",
+		"fertilizer-to-water",
+		"water-to-light",
+		"light-to-temperature",
+		"temperature-to-humidity",
+		"humidity-to-location",
+	];
+	let size = size.max(1);
+	let span = 100 * size;
+
+	let seeds: Vec<String> = (0..size)
+		.map(|_| format!("{} {}", rng.range(0..span), rng.range(1..101)))
+		.collect();
+	let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+	for map in MAPS {
+		let mut ranges = vec![];
+		let mut start = 0;
+		while start < span {
+			let len = rng.range(1..200);
+			if ranges.is_empty() || rng.chance(3, 4) {
+				ranges.push(format!("{} {start} {len}", rng.range(0..span)));
+			}
+			start += len;
+		}
+		rng.shuffle(&mut ranges);
+		input.push_str(&format!("\n{map} map:\n{}\n", ranges.join("\n")));
+	}
+	input.into_bytes()
+}
+
+/// Day 06: `size` races (at most 4) of up to 99 milliseconds, with records that can be
+/// beaten in every race, and in the one long race read from all the digits.
+pub fn day_06(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let races = size.clamp(1, 4);
+	let best = |time: usize| (time / 2) * (time - time / 2);
+	let concat = |numbers: &[usize]| -> usize {
+		numbers
+			.iter()
+			.map(usize::to_string)
+			.collect::<String>()
+			.parse()
+			.unwrap()
+	};
+
+	let (times, records) = loop {
+		let times: Vec<usize> = (0..races).map(|_| rng.range(2..100)).collect();
+		let records: Vec<usize> = times.iter().map(|&time| rng.range(0..best(time))).collect();
+		if concat(&records) < best(concat(&times)) {
+			break (times, records);
+		}
+	};
+
+	let mut time_line = String::from("Time:    ");
+	let mut record_line = String::from("Distance:");
+	for (time, record) in times.into_iter().zip(records) {
+		let width = time.to_string().len().max(record.to_string().len()) + rng.range(1..4);
+		time_line.push_str(&format!("{time:>width$}"));
+		record_line.push_str(&format!("{record:>width$}"));
+	}
+	format!("{time_line}\n{record_line}\n").into_bytes()
+}
+
+/// Day 07: `size` distinct hands (at most 10000) with bids of 1 to 1000.
+pub fn day_07(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut hands = HashSet::new();
+	let mut input = vec![];
+	while hands.len() < size.min(10_000) {
+		// Pairs and better should show up more often than in truly random hands
+		let pool: Vec<u8> = (0..3).map(|_| *rng.pick(b"23456789TJQKA")).collect();
+		let hand: Vec<u8> = (0..5)
+			.map(|_| {
+				if rng.chance(1, 2) {
+					*rng.pick(&pool)
+				} else {
+					*rng.pick(b"23456789TJQKA")
+				}
+			})
+			.collect();
+		if hands.insert(hand.clone()) {
+			input.extend(hand);
+			writeln!(input, " {}", rng.range(1..1001)).unwrap();
+		}
+	}
+	input
+}
+
+/// Day 09: `size` sequences of 21 values of polynomials of up to degree 6.
+pub fn day_09(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut input = vec![];
+	for _ in 0..size {
+		// differences of every level at the current position, the last one stays constant
+		let mut levels: Vec<isize> = (0..rng.range(1..8))
+			.map(|_| rng.range(0..21) as isize - 10)
+			.collect();
+		let mut values = vec![];
+		for _ in 0..21 {
+			values.push(levels[0].to_string());
+			for level in 0..levels.len() - 1 {
+				levels[level] += levels[level + 1];
+			}
+		}
+		writeln!(input, "{}", values.join(" ")).unwrap();
+	}
+	input
+}
+
+/// Day 11: a `size` by `size` image with a galaxy on every 8th spot, and about a sixth
+/// of its rows and columns empty.
+pub fn day_11(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(1, 6)).collect();
+	let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(1, 6)).collect();
+
+	let mut input = vec![];
+	for empty_row in empty_rows {
+		for &empty_column in &empty_columns {
+			let galaxy = !empty_row && !empty_column && rng.chance(1, 8);
+			input.push(if galaxy { b'#' } else { b'.' });
+		}
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 12: `size` rows of at most 20 springs in at most 6 groups of damaged ones,
+/// about half of the springs' conditions unknown.
+pub fn day_12(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut input = vec![];
+	for _ in 0..size {
+		let width = rng.range(3..21);
+		let (springs, groups) = loop {
+			let mut springs = vec![b'.'; width];
+			let mut groups = vec![];
+			let mut position = rng.range(0..4);
+			while position < width && groups.len() < 6 {
+				let len = rng.range(1..(width - position).min(8) + 1);
+				springs[position..position + len].fill(b'#');
+				groups.push(len.to_string());
+				position += len + rng.range(1..5);
+			}
+			if !groups.is_empty() {
+				break (springs, groups);
+			}
+		};
+
+		let springs: Vec<u8> = springs
+			.into_iter()
+			.map(|spring| if rng.chance(1, 2) { b'?' } else { spring })
+			.collect();
+		input.extend(springs);
+		writeln!(input, " {}", groups.join(",")).unwrap();
+	}
+	input
+}
+
+/// A line of reflection, between two columns or between two rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mirror {
+	Vertical(usize),
+	Horizontal(usize),
+}
+
+impl Mirror {
+	fn reflect(
+		self,
+		(x, y): (usize, usize),
+		width: usize,
+		height: usize,
+	) -> Option<(usize, usize)> {
+		match self {
+			Mirror::Vertical(column) => {
+				let x = (2 * column).checked_sub(x + 1)?;
+				(x < width).then_some((x, y))
+			}
+			Mirror::Horizontal(row) => {
+				let y = (2 * row).checked_sub(y + 1)?;
+				(y < height).then_some((x, y))
+			}
+		}
+	}
+}
+
+/// Day 13: `size` patterns of 3 to 17 by 3 to 17 ash and rocks. Every pattern has
+/// exactly one perfect line of reflection, and exactly one other line that is off by a
+/// single smudge.
+pub fn day_13(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let patterns: Vec<String> = (0..size.max(1)).map(|_| mirrored_pattern(rng)).collect();
+	(patterns.join("\n\n") + "\n").into_bytes()
+}
+
+fn mirrored_pattern(rng: &mut Rng) -> String {
+	loop {
+		let width = rng.range(3..18);
+		let height = rng.range(3..18);
+		let mirrors: Vec<Mirror> = (1..width)
+			.map(Mirror::Vertical)
+			.chain((1..height).map(Mirror::Horizontal))
+			.collect();
+		let perfect = *rng.pick(&mirrors);
+		let smudged = *rng.pick(&mirrors);
+		if perfect == smudged {
+			continue;
+		}
+
+		// cells reflected onto each other by either mirror have to be the same
+		let mut classes: Vec<usize> = (0..width * height).collect();
+		fn root(classes: &mut [usize], cell: usize) -> usize {
+			let mut cell = cell;
+			while classes[cell] != cell {
+				classes[cell] = classes[classes[cell]];
+				cell = classes[cell];
+			}
+			cell
+		}
+		for y in 0..height {
+			for x in 0..width {
+				for mirror in [perfect, smudged] {
+					if let Some((rx, ry)) = mirror.reflect((x, y), width, height) {
+						let a = root(&mut classes, y * width + x);
+						let b = root(&mut classes, ry * width + rx);
+						classes[a] = b;
+					}
+				}
+			}
+		}
+		let rocks: Vec<bool> = (0..width * height).map(|_| rng.chance(1, 2)).collect();
+		let mut grid: Vec<bool> = (0..width * height)
+			.map(|cell| rocks[root(&mut classes, cell)])
+			.collect();
+
+		// the smudge is only seen by the smudged mirror
+		let smudges: Vec<usize> = (0..width * height)
+			.filter(|cell| {
+				let position = (cell % width, cell / width);
+				smudged.reflect(position, width, height).is_some()
+					&& perfect.reflect(position, width, height).is_none()
+			})
+			.collect();
+		if smudges.is_empty() {
+			continue;
+		}
+		let smudge = *rng.pick(&smudges);
+		grid[smudge] = !grid[smudge];
+
+		let differences = |mirror: Mirror| {
+			(0..width * height)
+				.filter(|cell| {
+					mirror
+						.reflect((cell % width, cell / width), width, height)
+						.is_some_and(|(x, y)| grid[y * width + x] != grid[*cell])
+				})
+				.count() / 2
+		};
+		let unique = mirrors.iter().all(|&mirror| match differences(mirror) {
+			0 => mirror == perfect,
+			1 => mirror == smudged,
+			_ => true,
+		});
+		if unique && differences(perfect) == 0 && differences(smudged) == 1 {
+			return grid
+				.chunks(width)
+				.map(|row| {
+					row.iter()
+						.map(|&rock| if rock { '#' } else { '.' })
+						.collect::<String>()
+				})
+				.collect::<Vec<_>>()
+				.join("\n");
+		}
+	}
+}
+
+/// Day 14: a `size` by `size` platform, a quarter of it covered in round rocks and a
+/// sixth in cube-shaped ones.
+pub fn day_14(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let mut input = vec![];
+	for _ in 0..size {
+		input.extend((0..size).map(|_| match rng.range(0..12) {
+			0..=2 => b'O',
+			3..=4 => b'#',
+			_ => b'.',
+		}));
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 15: `size` comma separated steps (at least one), on labels of 1 to 6 lowercase
+/// letters that are used by a handful of steps each.
+pub fn day_15(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let labels: Vec<String> = (0..size / 4 + 1)
+		.map(|_| {
+			(0..rng.range(1..7))
+				.map(|_| (b'a' + rng.range(0..26) as u8) as char)
+				.collect()
+		})
+		.collect();
+	let steps: Vec<String> = (0..size)
+		.map(|_| {
+			let label = rng.pick(&labels);
+			if rng.chance(1, 3) {
+				format!("{label}-")
+			} else {
+				format!("{label}={}", rng.range(1..10))
+			}
+		})
+		.collect();
+	format!("{}\n", steps.join(",")).into_bytes()
+}
+
+/// Day 16: a `size` by `size` contraption with a mirror or splitter on every 8th tile.
+pub fn day_16(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let mut input = vec![];
+	for _ in 0..size {
+		input.extend((0..size).map(|_| {
+			if rng.chance(1, 8) {
+				*rng.pick(b"/\\|-")
+			} else {
+				b'.'
+			}
+		}));
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 17: a `size` by `size` map of heat losses from 1 to 9. It's at least 5 by 5, so
+/// that the ultra crucible can reach the end as well.
+pub fn day_17(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(5);
+	let mut input = vec![];
+	for _ in 0..size {
+		input.extend((0..size).map(|_| b'0' + rng.range(1..10) as u8));
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 18: a dig plan around a lagoon of `size` columns with random vertical extent,
+/// overlapping their neighbours so that the trench never touches itself. The colour
+/// codes describe the same shape, stretched to lengths of up to 5 hex digits.
+pub fn day_18(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let columns = size.max(1);
+	let rows = 2 * columns;
+
+	let mut spans: Vec<(usize, usize)> = vec![];
+	while spans.len() < columns {
+		let top = rng.range(0..rows);
+		let bottom = rng.range(top + 1..rows + 1);
+		if spans.last().is_none_or(|&(t, b)| top < b && bottom > t) {
+			spans.push((top, bottom));
+		}
+	}
+
+	// corners on a grid of the columns' edges and the rows, clockwise from the top left
+	let mut corners = vec![(0, spans[0].0)];
+	for (x, &(top, _)) in spans.iter().enumerate() {
+		corners.push((x + 1, top));
+		if let Some(&(next_top, _)) = spans.get(x + 1) {
+			corners.push((x + 1, next_top));
+		}
+	}
+	for (x, &(_, bottom)) in spans.iter().enumerate().rev() {
+		corners.push((x + 1, bottom));
+		corners.push((x, bottom));
+	}
+	corners.dedup();
+	let mut trench: Vec<(usize, usize)> = vec![];
+	for corner in corners {
+		while let [.., a, b] = trench[..] {
+			if (a.0 == b.0 && b.0 == corner.0) || (a.1 == b.1 && b.1 == corner.1) {
+				trench.pop();
+			} else {
+				break;
+			}
+		}
+		trench.push(corner);
+	}
+
+	let mut stretch = |len: usize, max: usize| -> Vec<usize> {
+		let mut positions = vec![0];
+		for _ in 0..len {
+			positions.push(positions.last().unwrap() + rng.range(1..max + 1));
+		}
+		positions
+	};
+	let (xs, ys) = (stretch(columns, 10), stretch(rows, 10));
+	let max = 0xfffff / (rows + 1);
+	let (big_xs, big_ys) = (stretch(columns, max), stretch(rows, max));
+
+	let mut input = vec![];
+	for (i, &(x, y)) in trench.iter().enumerate() {
+		let (next_x, next_y) = trench[(i + 1) % trench.len()];
+		let (direction, code, len, big_len) = if next_x > x {
+			('R', 0, xs[next_x] - xs[x], big_xs[next_x] - big_xs[x])
+		} else if next_x < x {
+			('L', 2, xs[x] - xs[next_x], big_xs[x] - big_xs[next_x])
+		} else if next_y > y {
+			('D', 1, ys[next_y] - ys[y], big_ys[next_y] - big_ys[y])
+		} else {
+			('U', 3, ys[y] - ys[next_y], big_ys[y] - big_ys[next_y])
+		};
+		writeln!(input, "{direction} {len} (#{big_len:05x}{code})").unwrap();
+	}
+	input
+}
+
+/// Day 19: up to `size` workflows with 1 to 4 conditions each, branching out from `in`
+/// like a tree, a blank line and `size` parts.
+pub fn day_19(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let mut names = HashSet::from([String::from("in")]);
+	let mut queue = vec![String::from("in")];
+	let mut workflows = vec![];
+
+	while let Some(name) = queue.pop() {
+		let mut target = |rng: &mut Rng| {
+			if names.len() < size && rng.chance(1, 2) {
+				loop {
+					let name: String = (0..rng.range(2..4))
+						.map(|_| (b'a' + rng.range(0..26) as u8) as char)
+						.collect();
+					if names.insert(name.clone()) {
+						queue.push(name.clone());
+						return name;
+					}
+				}
+			}
+			String::from(*rng.pick(&["A", "R"]))
+		};
+
+		let mut rules: Vec<String> = (0..rng.range(1..5))
+			.map(|_| {
+				let register = rng.pick(&['x', 'm', 'a', 's']);
+				let comparison = rng.pick(&['<', '>']);
+				let value = rng.range(1..4001);
+				format!("{register}{comparison}{value}:{}", target(rng))
+			})
+			.collect();
+		rules.push(target(rng));
+		workflows.push(format!("{name}{{{}}}", rules.join(",")));
+	}
+	rng.shuffle(&mut workflows);
+
+	let mut input = workflows.join("\n") + "\n\n";
+	for _ in 0..size {
+		let [x, m, a, s] = [(); 4].map(|_| rng.range(1..4001));
+		input.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+	}
+	input.into_bytes()
+}
+
+/// Day 22: `size` bricks of up to 4 cubes in a 10 by 10 column, floating at distinct
+/// spots up to a height of `size`.
+pub fn day_22(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let mut bricks: Vec<([usize; 3], [usize; 3])> = vec![];
+
+	while bricks.len() < size {
+		let axis = rng.range(0..3);
+		let len = rng.range(0..4);
+		let mut start = [rng.range(0..10), rng.range(0..10), rng.range(1..size + 2)];
+		if axis < 2 {
+			start[axis] = rng.range(0..10 - len);
+		}
+		let mut end = start;
+		end[axis] += len;
+
+		let overlaps = bricks.iter().any(|(other_start, other_end)| {
+			(0..3).all(|i| start[i] <= other_end[i] && end[i] >= other_start[i])
+		});
+		if !overlaps {
+			bricks.push((start, end));
+		}
+	}
+
+	let mut input = vec![];
+	for ([x0, y0, z0], [x1, y1, z1]) in bricks {
+		writeln!(input, "{x0},{y0},{z0}~{x1},{y1},{z1}").unwrap();
+	}
+	input
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn is_reproducible() {
+		assert_eq!(day_14(&mut Rng::new(7), 10), day_14(&mut Rng::new(7), 10));
+		assert_ne!(day_14(&mut Rng::new(7), 10), day_14(&mut Rng::new(8), 10));
+	}
+
+	#[test]
+	fn generates_puzzle_layouts() {
+		let schematic = String::from_utf8(day_03(&mut Rng::new(1), 12)).unwrap();
+		assert!(schematic.lines().all(|line| line.len() == 12));
+
+		let plan = String::from_utf8(day_18(&mut Rng::new(1), 3)).unwrap();
+		let directions: Vec<char> = plan
+			.lines()
+			.map(|line| line.as_bytes()[0] as char)
+			.collect();
+		assert!(directions.windows(2).all(|pair| pair[0] != pair[1]));
+	}
+}
@@ -0,0 +1,217 @@
+//! Maps that move ranges of integers around, as in 2023 day 5.
+
+use crate::range_set::{IntRangeSet, RangeInt};
+
+/// Translates elements of non-overlapping source ranges, each by its own offset. Elements
+/// outside of all of them map to themselves.
+///
+/// Offsets are kept as the element the start of a range maps to, so unsigned types can
+/// move ranges downwards as well.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<T: RangeInt> {
+	/// Inclusive source ranges and where their starts map to, sorted by source.
+	entries: Vec<((T, T), T)>,
+}
+
+impl<T: RangeInt> IntervalMap<T> {
+	pub const fn new() -> Self {
+		IntervalMap { entries: vec![] }
+	}
+
+	pub fn entries(&self) -> &[((T, T), T)] {
+		&self.entries
+	}
+
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	pub fn clear(&mut self) {
+		self.entries.clear();
+	}
+
+	/// Maps the `source` range onto the one starting at `destination`.
+	pub fn insert(&mut self, source: (T, T), destination: T) {
+		debug_assert!(source.0 <= source.1);
+
+		let index = self
+			.entries
+			.partition_point(|(other, _)| other.1 < source.0);
+		assert!(
+			self.entries
+				.get(index)
+				.is_none_or(|(other, _)| other.0 > source.1),
+			"source ranges must not overlap"
+		);
+		self.entries.insert(index, (source, destination));
+	}
+
+	pub fn get(&self, element: T) -> T {
+		let index = self
+			.entries
+			.partition_point(|(source, _)| source.1 < element);
+		match self.entries.get(index) {
+			Some(&(source, destination)) if source.0 <= element => {
+				destination + (element - source.0)
+			}
+			_ => element,
+		}
+	}
+
+	/// Cuts `range` where the source ranges start and end, handing every piece to `piece`
+	/// together with where its start maps to.
+	fn split(&self, (mut start, end): (T, T), mut piece: impl FnMut((T, T), T)) {
+		let first = self.entries.partition_point(|(source, _)| source.1 < start);
+
+		for &(source, destination) in self.entries[first..]
+			.iter()
+			.take_while(|(source, _)| source.0 <= end)
+		{
+			if source.0 > start {
+				piece((start, source.0 - 1.into()), start);
+				start = source.0;
+			}
+			piece((start, source.1.min(end)), destination + (start - source.0));
+			if source.1 >= end {
+				return;
+			}
+			start = source.1 + 1.into();
+		}
+
+		piece((start, end), start);
+	}
+
+	/// Maps every element of `set`.
+	pub fn apply(&self, set: &IntRangeSet<T>) -> IntRangeSet<T> {
+		let mut mapped = Vec::with_capacity(set.ranges().len());
+		for &range in set.ranges() {
+			self.split(range, |(start, end), destination| {
+				mapped.push((destination, destination + (end - start)));
+			});
+		}
+		mapped.into_iter().collect()
+	}
+
+	/// The map that applies `self` first and `then` to its results.
+	pub fn compose(&self, then: &Self) -> Self {
+		let mut entries = vec![];
+
+		// where self maps something, then maps its destination further
+		for &((start, end), destination) in &self.entries {
+			let image = (destination, destination + (end - start));
+			then.split(image, |(from, to), next| {
+				let source = (start + (from - destination), start + (to - destination));
+				entries.push((source, next));
+			});
+		}
+
+		// everywhere else, only then does anything
+		let mapped: IntRangeSet<T> = self.entries.iter().map(|&(source, _)| source).collect();
+		for &(source, destination) in &then.entries {
+			let untouched = &IntRangeSet::from(source.0..=source.1) - &mapped;
+			for &(start, end) in untouched.ranges() {
+				entries.push(((start, end), destination + (start - source.0)));
+			}
+		}
+
+		entries.retain(|((start, _), destination)| start != destination);
+		entries.sort_unstable_by_key(|((start, _), _)| *start);
+		IntervalMap { entries }
+	}
+
+	/// Maps the destinations back onto their sources.
+	///
+	/// That is only the inverse if the map is a bijection, like the maps of 2023 day 5.
+	/// Panics if two destination ranges overlap, as they become overlapping source ranges.
+	/// A destination range that covers elements outside of all source ranges is accepted
+	/// silently though: those elements map to themselves, but the inverse maps them into
+	/// the source range instead.
+	pub fn invert(&self) -> Self {
+		self.entries
+			.iter()
+			.map(|&((start, end), destination)| ((destination, destination + (end - start)), start))
+			.collect()
+	}
+}
+
+impl<T: RangeInt> Default for IntervalMap<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Collects source ranges and where their starts map to.
+impl<T: RangeInt> FromIterator<((T, T), T)> for IntervalMap<T> {
+	fn from_iter<I: IntoIterator<Item = ((T, T), T)>>(entries: I) -> Self {
+		let mut map = IntervalMap::new();
+		for (source, destination) in entries {
+			map.insert(source, destination);
+		}
+		map
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// seed-to-soil and soil-to-fertilizer of the example of 2023 day 5
+	fn example() -> (IntervalMap<usize>, IntervalMap<usize>) {
+		let seed_to_soil = [((98, 99), 50), ((50, 97), 52)].into_iter().collect();
+		let soil_to_fertilizer = [((15, 51), 0), ((52, 53), 37), ((0, 14), 39)]
+			.into_iter()
+			.collect();
+		(seed_to_soil, soil_to_fertilizer)
+	}
+
+	#[test]
+	fn maps_elements() {
+		let (seed_to_soil, _) = example();
+		assert_eq!(seed_to_soil.entries()[0], ((50, 97), 52));
+		assert_eq!(seed_to_soil.get(79), 81);
+		assert_eq!(seed_to_soil.get(98), 50);
+		assert_eq!(seed_to_soil.get(13), 13);
+		assert_eq!(seed_to_soil.get(100), 100);
+	}
+
+	#[test]
+	fn maps_sets() {
+		let (seed_to_soil, _) = example();
+		let seeds: IntRangeSet<usize> = [(79, 92), (40, 55), (96, 105)].into_iter().collect();
+		let soil = seed_to_soil.apply(&seeds);
+		assert_eq!(soil.ranges(), [(40, 57), (81, 94), (98, 105)]);
+
+		let mut elements: Vec<_> = seeds.iter().map(|seed| seed_to_soil.get(seed)).collect();
+		elements.sort_unstable();
+		assert_eq!(soil.iter().collect::<Vec<_>>(), elements);
+	}
+
+	#[test]
+	fn composes_maps() {
+		let (seed_to_soil, soil_to_fertilizer) = example();
+		let seed_to_fertilizer = seed_to_soil.compose(&soil_to_fertilizer);
+		for seed in 0..120 {
+			assert_eq!(
+				seed_to_fertilizer.get(seed),
+				soil_to_fertilizer.get(seed_to_soil.get(seed)),
+				"seed {seed}"
+			);
+		}
+	}
+
+	#[test]
+	fn inverts_maps() {
+		let (seed_to_soil, soil_to_fertilizer) = example();
+		for map in [seed_to_soil, soil_to_fertilizer] {
+			let inverse = map.invert();
+			for element in 0..120 {
+				assert_eq!(inverse.get(map.get(element)), element);
+			}
+			assert_eq!(inverse.invert(), map);
+		}
+	}
+}
@@ -0,0 +1,308 @@
+//! Memoized recursion, for solvers that count their way through a puzzle by splitting it
+//! into smaller puzzles of the same kind.
+//!
+//! The recursive function stays a plain function. It takes the [`Memo`] as its first
+//! argument and calls itself through [`Memo::call`]:
+//!
+//! ```
+//! use aoc_utils::memo::HashMemo;
+//!
+//! fn fibonacci(memo: &mut HashMemo<u64, u64>, n: u64) -> u64 {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     memo.call(n - 1, fibonacci) + memo.call(n - 2, fibonacci)
+//! }
+//!
+//! let mut memo = HashMemo::default();
+//! assert_eq!(memo.call(90, fibonacci), 2_880_067_194_370_816_120);
+//! ```
+//!
+//! With the `memo-stats` feature enabled (`cargo test --features aoc-utils/memo-stats`),
+//! every memo counts how often its cache had the value and how often it did not, see
+//! [`Memo::stats`].
+
+use std::{
+	collections::HashMap,
+	fmt,
+	hash::{BuildHasher, BuildHasherDefault, Hash},
+	iter,
+};
+
+use crate::hash::FxHasher;
+
+/// Where a [`Memo`] keeps the values it computed.
+pub trait Cache<K, V> {
+	fn get(&self, key: &K) -> Option<&V>;
+	fn insert(&mut self, key: K, value: V);
+	/// The number of values.
+	fn len(&self) -> usize;
+	fn clear(&mut self);
+
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Cache<K, V> for HashMap<K, V, S> {
+	#[inline]
+	fn get(&self, key: &K) -> Option<&V> {
+		HashMap::get(self, key)
+	}
+
+	#[inline]
+	fn insert(&mut self, key: K, value: V) {
+		HashMap::insert(self, key, value);
+	}
+
+	fn len(&self) -> usize {
+		HashMap::len(self)
+	}
+
+	fn clear(&mut self) {
+		HashMap::clear(self);
+	}
+}
+
+/// Values for keys `0..capacity` in a [`Vec`], for states that are easily numbered. Grows
+/// for larger keys.
+#[derive(Debug, Clone)]
+pub struct Dense<V> {
+	values: Vec<Option<V>>,
+	len: usize,
+}
+
+impl<V> Dense<V> {
+	pub fn new() -> Self {
+		Self::with_capacity(0)
+	}
+
+	pub fn with_capacity(capacity: usize) -> Self {
+		Dense {
+			values: iter::repeat_with(|| None).take(capacity).collect(),
+			len: 0,
+		}
+	}
+}
+
+impl<V> Default for Dense<V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<V> Cache<usize, V> for Dense<V> {
+	#[inline]
+	fn get(&self, key: &usize) -> Option<&V> {
+		self.values.get(*key).and_then(Option::as_ref)
+	}
+
+	#[inline]
+	fn insert(&mut self, key: usize, value: V) {
+		if key >= self.values.len() {
+			self.values.resize_with(key + 1, || None);
+		}
+		if self.values[key].replace(value).is_none() {
+			self.len += 1;
+		}
+	}
+
+	fn len(&self) -> usize {
+		self.len
+	}
+
+	fn clear(&mut self) {
+		self.values.fill_with(|| None);
+		self.len = 0;
+	}
+}
+
+/// How often a [`Memo`] found a value in its cache, and how often it had to compute one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+	pub hits: usize,
+	pub misses: usize,
+}
+
+impl Stats {
+	/// The share of calls answered from the cache, 0 if there were none.
+	pub fn hit_rate(&self) -> f64 {
+		match self.hits + self.misses {
+			0 => 0.0,
+			calls => self.hits as f64 / calls as f64,
+		}
+	}
+}
+
+impl fmt::Display for Stats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let hits = if self.hits == 1 { "hit" } else { "hits" };
+		let misses = if self.misses == 1 { "miss" } else { "misses" };
+		write!(
+			f,
+			"{} {hits}, {} {misses}, {:.1}% hit rate",
+			self.hits,
+			self.misses,
+			self.hit_rate() * 100.0
+		)
+	}
+}
+
+/// A recursive function's values so far, kept in the cache `C`.
+#[derive(Debug, Clone, Default)]
+pub struct Memo<C> {
+	cache: C,
+	#[cfg(feature = "memo-stats")]
+	stats: Stats,
+}
+
+/// A [`Memo`] for any keys that can be hashed.
+pub type HashMemo<K, V> = Memo<HashMap<K, V, BuildHasherDefault<FxHasher>>>;
+
+/// A [`Memo`] for keys numbered `0..capacity`, see [`Dense`].
+pub type DenseMemo<V> = Memo<Dense<V>>;
+
+impl<C> Memo<C> {
+	pub fn new(cache: C) -> Self {
+		Memo {
+			cache,
+			#[cfg(feature = "memo-stats")]
+			stats: Stats::default(),
+		}
+	}
+
+	pub fn cache(&self) -> &C {
+		&self.cache
+	}
+
+	pub fn into_cache(self) -> C {
+		self.cache
+	}
+
+	/// The hits and misses of all calls so far. Always zero without the `memo-stats`
+	/// feature.
+	pub fn stats(&self) -> Stats {
+		#[cfg(feature = "memo-stats")]
+		{
+			self.stats
+		}
+		#[cfg(not(feature = "memo-stats"))]
+		{
+			Stats::default()
+		}
+	}
+
+	/// The value of `function` for `key`, from the cache if it was computed before.
+	/// `function` gets this memo back, to call itself through.
+	#[inline]
+	pub fn call<K: Clone, V: Clone>(
+		&mut self,
+		key: K,
+		function: impl FnOnce(&mut Self, K) -> V,
+	) -> V
+	where
+		C: Cache<K, V>,
+	{
+		if let Some(value) = self.cache.get(&key) {
+			#[cfg(feature = "memo-stats")]
+			{
+				self.stats.hits += 1;
+			}
+			return value.clone();
+		}
+		#[cfg(feature = "memo-stats")]
+		{
+			self.stats.misses += 1;
+		}
+
+		let value = function(self, key.clone());
+		self.cache.insert(key, value.clone());
+		value
+	}
+
+	/// The value for `key`, if it was computed before.
+	pub fn get<K, V>(&self, key: &K) -> Option<&V>
+	where
+		C: Cache<K, V>,
+	{
+		self.cache.get(key)
+	}
+
+	/// Forgets all values, but keeps counting the stats.
+	pub fn clear<K, V>(&mut self)
+	where
+		C: Cache<K, V>,
+	{
+		self.cache.clear();
+	}
+}
+
+impl<V> DenseMemo<V> {
+	/// A memo for keys `0..capacity`, which are not cached any slower.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Memo::new(Dense::with_capacity(capacity))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Lattice paths through a grid of `width` by `height` squares.
+	fn paths(memo: &mut HashMemo<(u32, u32), u64>, (width, height): (u32, u32)) -> u64 {
+		if width == 0 || height == 0 {
+			return 1;
+		}
+		memo.call((width - 1, height), paths) + memo.call((width, height - 1), paths)
+	}
+
+	#[test]
+	fn remembers_values() {
+		let mut memo = HashMemo::default();
+		assert_eq!(memo.call((2, 2), paths), 6);
+		assert_eq!(memo.call((16, 16), paths), 601_080_390);
+		assert_eq!(memo.get(&(2, 1)), Some(&3));
+		// all grids but the empty one
+		assert_eq!(memo.cache().len(), 17 * 17 - 1);
+
+		if cfg!(feature = "memo-stats") {
+			// besides the two calls above, every grid that is not flat is computed once
+			// and calls two others
+			let stats = memo.stats();
+			assert_eq!(stats.misses, 17 * 17 - 1);
+			assert_eq!(stats.hits + stats.misses, 2 + 2 * 16 * 16);
+		} else {
+			assert_eq!(memo.stats(), Stats::default());
+		}
+
+		memo.clear();
+		assert!(memo.cache().is_empty());
+		assert_eq!(memo.get(&(2, 1)), None);
+	}
+
+	#[test]
+	fn remembers_values_densely() {
+		// ways to climb `n` stairs taking 1, 2 or 3 at a time
+		fn climbs(memo: &mut DenseMemo<u64>, n: usize) -> u64 {
+			match n {
+				0 => 1,
+				_ => (1..=n.min(3)).map(|step| memo.call(n - step, climbs)).sum(),
+			}
+		}
+
+		let mut memo = DenseMemo::with_capacity(10);
+		assert_eq!(memo.call(4, climbs), 7);
+		assert_eq!(memo.call(50, climbs), 10_562_230_626_642);
+		assert_eq!(memo.cache().len(), 51);
+		assert_eq!(memo.get(&3), Some(&4));
+		assert_eq!(memo.get(&100), None);
+	}
+
+	#[test]
+	fn describes_stats() {
+		let stats = |hits, misses| Stats { hits, misses }.to_string();
+		assert_eq!(stats(0, 0), "0 hits, 0 misses, 0.0% hit rate");
+		assert_eq!(stats(3, 1), "3 hits, 1 miss, 75.0% hit rate");
+		assert_eq!(stats(1, 2), "1 hit, 2 misses, 33.3% hit rate");
+	}
+}
@@ -0,0 +1,300 @@
+//! Divisors, multiples and arithmetic modulo some number, for the puzzles where things
+//! repeat with different periods.
+//!
+//! Everything works for the signed and unsigned integers of any width, `u128` included,
+//! without overflowing on the way. Moduli must be positive, results are always in
+//! `0..modulus`. The exception is `MIN` of a signed type as an argument of [`gcd`],
+//! [`lcm`] or [`extended_gcd`]: its absolute value does not fit into the type.
+//! Residues of [`crt`] can be anything.
+
+use std::{
+	fmt::Debug,
+	ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The primitive integers the functions of this module work with.
+pub trait Integer:
+	Debug
+	+ Copy
+	+ Ord
+	+ Add<Output = Self>
+	+ Sub<Output = Self>
+	+ Mul<Output = Self>
+	+ Div<Output = Self>
+	+ Rem<Output = Self>
+{
+	const ZERO: Self;
+	const ONE: Self;
+
+	fn checked_mul(self, other: Self) -> Option<Self>;
+	fn rem_euclid(self, modulus: Self) -> Self;
+	fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+	(unsigned: $($int:ty),*; signed: $($signed:ty),*) => {
+		$(
+			impl Integer for $int {
+				const ZERO: Self = 0;
+				const ONE: Self = 1;
+
+				#[inline]
+				fn checked_mul(self, other: Self) -> Option<Self> {
+					<$int>::checked_mul(self, other)
+				}
+
+				#[inline]
+				fn rem_euclid(self, modulus: Self) -> Self {
+					self % modulus
+				}
+
+				#[inline]
+				fn abs(self) -> Self {
+					self
+				}
+			}
+		)*
+		$(
+			impl Integer for $signed {
+				const ZERO: Self = 0;
+				const ONE: Self = 1;
+
+				#[inline]
+				fn checked_mul(self, other: Self) -> Option<Self> {
+					<$signed>::checked_mul(self, other)
+				}
+
+				#[inline]
+				fn rem_euclid(self, modulus: Self) -> Self {
+					<$signed>::rem_euclid(self, modulus)
+				}
+
+				#[inline]
+				fn abs(self) -> Self {
+					<$signed>::abs(self)
+				}
+			}
+		)*
+	};
+}
+
+integer!(
+	unsigned: u8, u16, u32, u64, u128, usize;
+	signed: i8, i16, i32, i64, i128, isize
+);
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+///
+/// Overflows if `a` or `b` is `T::MIN` of a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+	let (mut a, mut b) = (a.abs(), b.abs());
+	while b != T::ZERO {
+		(a, b) = (b, a % b);
+	}
+	a
+}
+
+/// The greatest common divisor `g` with `x` and `y` such that `a * x + b * y == g`.
+///
+/// Overflows if `a` or `b` is `T::MIN`.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+	let (mut r, mut next_r) = (a, b);
+	let (mut x, mut next_x) = (T::ONE, T::ZERO);
+	let (mut y, mut next_y) = (T::ZERO, T::ONE);
+
+	while next_r != T::ZERO {
+		let quotient = r / next_r;
+		(r, next_r) = (next_r, r - quotient * next_r);
+		(x, next_x) = (next_x, x - quotient * next_x);
+		(y, next_y) = (next_y, y - quotient * next_y);
+	}
+
+	if r < T::ZERO {
+		(-r, -x, -y)
+	} else {
+		(r, x, y)
+	}
+}
+
+/// The lowest common multiple, unless it overflows. `lcm(0, b)` is 0.
+///
+/// Overflows anyway if `a` or `b` is `T::MIN` of a signed type.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+	if a == T::ZERO || b == T::ZERO {
+		return Some(T::ZERO);
+	}
+	(a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// The lowest common multiple of all numbers, 1 if there are none, unless it overflows.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+	numbers
+		.into_iter()
+		.try_fold(T::ONE, |multiple, number| lcm(multiple, number))
+}
+
+#[inline]
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+	// both are below the modulus, so this never overflows
+	if a >= modulus - b {
+		a - (modulus - b)
+	} else {
+		a + b
+	}
+}
+
+#[inline]
+fn sub_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+	if a >= b {
+		a - b
+	} else {
+		modulus - (b - a)
+	}
+}
+
+/// `a * b % modulus`, even if `a * b` does not fit into `T`.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+	let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+	if let Some(product) = a.checked_mul(b) {
+		return product % modulus;
+	}
+
+	// double and add, halving b
+	let two = T::ONE + T::ONE;
+	let mut product = T::ZERO;
+	while b != T::ZERO {
+		if b % two == T::ONE {
+			product = add_mod(product, a, modulus);
+		}
+		a = add_mod(a, a, modulus);
+		b = b / two;
+	}
+	product
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`.
+pub fn pow_mod<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+	assert!(
+		exponent >= T::ZERO,
+		"negative exponents have no integer result"
+	);
+
+	let two = T::ONE + T::ONE;
+	let (mut base, mut exponent) = (base.rem_euclid(modulus), exponent);
+	let mut power = T::ONE.rem_euclid(modulus);
+	while exponent != T::ZERO {
+		if exponent % two == T::ONE {
+			power = mul_mod(power, base, modulus);
+		}
+		base = mul_mod(base, base, modulus);
+		exponent = exponent / two;
+	}
+	power
+}
+
+/// The `x` with `a * x % modulus == 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+	// extended Euclid, keeping only the coefficient of a, and that modulo modulus, so
+	// it works without negative numbers
+	let (mut r, mut next_r) = (modulus, a.rem_euclid(modulus));
+	let (mut x, mut next_x) = (T::ZERO, T::ONE.rem_euclid(modulus));
+
+	while next_r != T::ZERO {
+		let quotient = r / next_r;
+		(r, next_r) = (next_r, r - quotient * next_r);
+		(x, next_x) = (
+			next_x,
+			sub_mod(x, mul_mod(quotient, next_x, modulus), modulus),
+		);
+	}
+
+	(r == T::ONE).then_some(x)
+}
+
+/// Solves the system of `x ≡ residue (mod modulus)` for all `(residue, modulus)`, returning
+/// the smallest non-negative solution and the lowest common multiple of the moduli, the
+/// period of all solutions.
+///
+/// The moduli need not be coprime. Returns `None` if the congruences contradict each
+/// other, and panics if the period does not fit into `T`.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+	congruences
+		.into_iter()
+		.try_fold((T::ZERO, T::ONE), |(x, period), (residue, modulus)| {
+			assert!(modulus > T::ZERO, "moduli must be positive");
+			let residue = residue.rem_euclid(modulus);
+
+			// x + period * k ≡ residue (mod modulus), solved for k
+			let divisor = gcd(period, modulus);
+			let difference = sub_mod(residue, x.rem_euclid(modulus), modulus);
+			if difference % divisor != T::ZERO {
+				return None;
+			}
+			let reduced = modulus / divisor;
+			let inverse = mod_inverse(period / divisor, reduced).expect("coprime after dividing");
+			let k = mul_mod(difference / divisor, inverse, reduced);
+
+			let next_period = (period / divisor)
+				.checked_mul(modulus)
+				.expect("period of the solutions fits into the integer type");
+			// k < reduced, so this stays below next_period
+			Some((x + period * k, next_period))
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn finds_divisors_and_multiples() {
+		assert_eq!(gcd(12_u32, 18), 6);
+		assert_eq!(gcd(-12_i32, 18), 6);
+		assert_eq!(gcd(0_u8, 7), 7);
+		assert_eq!(gcd(0_u8, 0), 0);
+
+		assert_eq!(lcm(4_u64, 6), Some(12));
+		assert_eq!(lcm(0_u64, 6), Some(0));
+		assert_eq!(lcm(200_u8, 3), None);
+		assert_eq!(lcm_all([3_usize, 4, 5, 6]), Some(60));
+		assert_eq!(lcm_all(Vec::<u16>::new()), Some(1));
+		assert_eq!(lcm_all([u128::MAX, 2]), None);
+
+		for (a, b) in [(240_i64, 46), (-7, 3), (0, 5), (5, 0), (-4, -6)] {
+			let (g, x, y) = extended_gcd(a, b);
+			assert_eq!(g, gcd(a, b));
+			assert_eq!(a * x + b * y, g, "{a}, {b}");
+		}
+	}
+
+	#[test]
+	fn does_modular_arithmetic() {
+		assert_eq!(mul_mod(u128::MAX - 1, u128::MAX - 2, u128::MAX), 2);
+		assert_eq!(mul_mod(-3_i32, 5, 7), 6);
+		assert_eq!(pow_mod(2_u64, 10, 1000), 24);
+		assert_eq!(pow_mod(3_u8, 200, 251), 149);
+		assert_eq!(pow_mod(5_u32, 0, 1), 0);
+		assert_eq!(pow_mod(2_u128, u128::MAX, (1 << 61) - 1), 1 << 11);
+
+		assert_eq!(mod_inverse(3_u8, 11), Some(4));
+		assert_eq!(mod_inverse(-3_i64, 11), Some(7));
+		assert_eq!(mod_inverse(4_u32, 10), None);
+		let prime = (1_u128 << 89) - 1;
+		let inverse = mod_inverse(u128::MAX, prime).unwrap();
+		assert_eq!(mul_mod(inverse, u128::MAX, prime), 1);
+	}
+
+	#[test]
+	fn solves_congruences() {
+		assert_eq!(crt([(2_u32, 3), (3, 5), (2, 7)]), Some((23, 105)));
+		assert_eq!(crt([(-1_i64, 4), (3, 6)]), Some((3, 12)));
+		assert_eq!(crt([(i64::MIN, 3), (i64::MIN, 4)]), Some((4, 12)));
+		assert_eq!(crt([(1_u8, 4), (2, 6)]), None);
+		assert_eq!(crt(Vec::<(u16, u16)>::new()), Some((0, 1)));
+
+		// 2020 day 13: buses 17,x,13,19 leave t + their offset
+		let buses = [(17_u128, 0), (13, 2), (19, 3)];
+		let congruences = buses.map(|(bus, offset)| ((bus - offset % bus) % bus, bus));
+		assert_eq!(crt(congruences), Some((3417, 17 * 13 * 19)));
+	}
+}
@@ -0,0 +1,124 @@
+//! Named phases of a solver, timed separately in the benchmarks.
+//!
+//! Without the `phases` feature a [`Span`] is an empty struct and all of this compiles to
+//! nothing, so solvers can stay instrumented without slowing them down:
+//!
+//! ```
+//! use aoc_utils::phase::span;
+//!
+//! let parsing = span("parse");
+//! let numbers: Vec<u32> = "1 2 3".split(' ').map(|n| n.parse().unwrap()).collect();
+//! parsing.end();
+//!
+//! let _summing = span("sum");
+//! let sum: u32 = numbers.iter().sum();
+//! ```
+//!
+//! With the feature enabled (`cargo bench --features aoc-utils/phases`), every span that
+//! ends inside [`record`] records its time, and the benchmarks measure each phase on its
+//! own. Spans outside of it, e.g. in tests, record nothing and don't allocate.
+
+use std::time::Duration;
+#[cfg(feature = "phases")]
+use std::{cell::RefCell, time::Instant};
+
+#[cfg(feature = "phases")]
+thread_local! {
+	static RECORDED: RefCell<Option<Vec<(&'static str, Duration)>>> = const { RefCell::new(None) };
+}
+
+/// A running phase, which ends when dropped or on [`Span::end`].
+#[must_use = "the phase ends as soon as the span is dropped"]
+pub struct Span {
+	#[cfg(feature = "phases")]
+	name: &'static str,
+	#[cfg(feature = "phases")]
+	start: Instant,
+}
+
+/// Starts the phase `name`.
+#[inline(always)]
+pub fn span(name: &'static str) -> Span {
+	#[cfg(feature = "phases")]
+	{
+		Span {
+			name,
+			start: Instant::now(),
+		}
+	}
+	#[cfg(not(feature = "phases"))]
+	{
+		let _ = name;
+		Span {}
+	}
+}
+
+impl Span {
+	/// Ends the phase before the end of the scope.
+	#[inline(always)]
+	pub fn end(self) {}
+}
+
+#[cfg(feature = "phases")]
+impl Drop for Span {
+	fn drop(&mut self) {
+		let elapsed = self.start.elapsed();
+		RECORDED.with_borrow_mut(|recorded| {
+			if let Some(recorded) = recorded {
+				recorded.push((self.name, elapsed));
+			}
+		});
+	}
+}
+
+/// Runs `solve` and returns the time spent in each of its phases, summed up per name
+/// and in the order they first ended. Always empty without the `phases` feature.
+pub fn record<T>(solve: impl FnOnce() -> T) -> Vec<(&'static str, Duration)> {
+	#[cfg(feature = "phases")]
+	RECORDED.set(Some(vec![]));
+
+	solve();
+
+	#[allow(unused_mut)]
+	let mut phases: Vec<(&'static str, Duration)> = vec![];
+	#[cfg(feature = "phases")]
+	for (name, elapsed) in RECORDED.take().unwrap_or_default() {
+		match phases.iter_mut().find(|(other, _)| *other == name) {
+			Some((_, total)) => *total += elapsed,
+			None => phases.push((name, elapsed)),
+		}
+	}
+	phases
+}
+
+/// Total time spent in the phase `name` over `iters` runs of `solve`, for criterion's
+/// `iter_custom`.
+pub fn time<T>(name: &str, iters: u64, mut solve: impl FnMut() -> T) -> Duration {
+	(0..iters)
+		.flat_map(|_| record(&mut solve))
+		.filter(|(other, _)| *other == name)
+		.map(|(_, elapsed)| elapsed)
+		.sum()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn records_phases() {
+		let phases = record(|| {
+			let _outer = span("outer");
+			span("inner").end();
+			span("inner").end();
+		});
+		let names: Vec<&str> = phases.iter().map(|(name, _)| *name).collect();
+
+		if cfg!(feature = "phases") {
+			assert_eq!(names, ["inner", "outer"]);
+			assert!(phases[0].1 <= phases[1].1);
+		} else {
+			assert!(names.is_empty());
+		}
+	}
+}
@@ -1,15 +1,22 @@
 use std::fmt::Debug;
-use std::ops::{Add, Sub};
+use std::iter::successors;
+use std::ops::{Add, BitAnd, BitOr, BitXor, RangeInclusive, Sub};
 
-#[derive(Debug, Clone)]
-pub struct IntRangeSet<T: Debug + Clone + Add + PartialEq + Eq + Ord + From<u8>> {
-	pub ranges: Vec<(T, T)>,
+/// The integers an [`IntRangeSet`] can hold.
+pub trait RangeInt:
+	Debug + Copy + Add<Output = Self> + Sub<Output = Self> + Ord + From<u8>
+{
 }
 
-impl<
-		T: Debug + Clone + Copy + Add<Output = T> + Sub<Output = T> + PartialEq + Eq + Ord + From<u8>,
-	> IntRangeSet<T>
-{
+impl<T: Debug + Copy + Add<Output = T> + Sub<Output = T> + Ord + From<u8>> RangeInt for T {}
+
+/// A set of integers, stored as sorted inclusive ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntRangeSet<T: RangeInt> {
+	ranges: Vec<(T, T)>,
+}
+
+impl<T: RangeInt> IntRangeSet<T> {
 	pub const fn new() -> Self {
 		IntRangeSet { ranges: vec![] }
 	}
@@ -20,6 +27,10 @@ impl<
 		}
 	}
 
+	pub fn ranges(&self) -> &[(T, T)] {
+		&self.ranges
+	}
+
 	fn start_intersecting(&self, range: (T, T)) -> usize {
 		// reminder that partition points invert the usual find logic
 		// we want to find the first element where this doesn't hold true
@@ -64,6 +75,32 @@ impl<
 		self.add_range((element, element))
 	}
 
+	pub fn remove_range(&mut self, range: (T, T)) {
+		debug_assert!(range.0 <= range.1);
+
+		// unlike when adding, ranges that only touch the removed one stay as they are
+		let start_overlapping = self.ranges.partition_point(|other| other.1 < range.0);
+		let end_overlapping = self.ranges.partition_point(|other| other.0 <= range.1);
+
+		if start_overlapping == end_overlapping {
+			return;
+		}
+
+		let first = self.ranges[start_overlapping];
+		let last = self.ranges[end_overlapping - 1];
+		let before = (first.0 < range.0).then(|| (first.0, range.0 - 1.into()));
+		let after = (last.1 > range.1).then(|| (range.1 + 1.into(), last.1));
+
+		self.ranges.splice(
+			start_overlapping..end_overlapping,
+			before.into_iter().chain(after),
+		);
+	}
+
+	pub fn remove_one(&mut self, element: T) {
+		self.remove_range((element, element))
+	}
+
 	pub fn invert(&mut self, bounds: (T, T)) {
 		let start_intersecting = self.start_intersecting(bounds);
 		let end_intersecting = self.end_intersecting(bounds);
@@ -125,17 +162,237 @@ impl<
 		}
 	}
 
+	pub fn union(&self, other: &Self) -> Self {
+		let mut ranges: Vec<(T, T)> = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+		let mut own = self.ranges.iter().peekable();
+		let mut others = other.ranges.iter().peekable();
+
+		// merges both sorted lists, joining each range with the last one if they touch
+		loop {
+			let next = match (own.peek(), others.peek()) {
+				(Some(a), Some(b)) if a.0 <= b.0 => own.next(),
+				(Some(_), Some(_)) => others.next(),
+				(Some(_), None) => own.next(),
+				(None, _) => others.next(),
+			};
+			let Some(&range) = next else {
+				break;
+			};
+			match ranges.last_mut() {
+				Some(last) if range.0 <= last.1 + 1.into() => last.1 = last.1.max(range.1),
+				_ => ranges.push(range),
+			}
+		}
+
+		IntRangeSet { ranges }
+	}
+
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut ranges = vec![];
+		let (mut own, mut others) = (0, 0);
+
+		while let (Some(a), Some(b)) = (self.ranges.get(own), other.ranges.get(others)) {
+			let overlap = (a.0.max(b.0), a.1.min(b.1));
+			if overlap.0 <= overlap.1 {
+				ranges.push(overlap);
+			}
+			// whichever ends first can't overlap with anything after the other one
+			if a.1 < b.1 {
+				own += 1;
+			} else {
+				others += 1;
+			}
+		}
+
+		IntRangeSet { ranges }
+	}
+
+	/// Elements of `self` that are not in `other`.
+	pub fn difference(&self, other: &Self) -> Self {
+		let mut ranges = vec![];
+		let mut removed = other.ranges.iter().peekable();
+
+		'ranges: for &(mut start, end) in &self.ranges {
+			while removed.next_if(|other| other.1 < start).is_some() {}
+
+			for other in removed.clone().take_while(|other| other.0 <= end) {
+				if other.0 > start {
+					ranges.push((start, other.0 - 1.into()));
+				}
+				if other.1 >= end {
+					continue 'ranges;
+				}
+				start = other.1 + 1.into();
+			}
+
+			ranges.push((start, end));
+		}
+
+		IntRangeSet { ranges }
+	}
+
+	/// Elements in exactly one of the sets.
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		self.difference(other).union(&other.difference(self))
+	}
+
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		let (mut own, mut others) = (0, 0);
+
+		while let (Some(a), Some(b)) = (self.ranges.get(own), other.ranges.get(others)) {
+			if a.0.max(b.0) <= a.1.min(b.1) {
+				return false;
+			}
+			if a.1 < b.1 {
+				own += 1;
+			} else {
+				others += 1;
+			}
+		}
+
+		true
+	}
+
+	pub fn is_subset(&self, other: &Self) -> bool {
+		// as ranges never touch, each of ours has to be inside a single one of the other set
+		self.ranges.iter().all(|range| {
+			let containing = other.ranges.partition_point(|other| other.1 < range.0);
+			other
+				.ranges
+				.get(containing)
+				.is_some_and(|other| other.0 <= range.0 && range.1 <= other.1)
+		})
+	}
+
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
 	pub fn len(&self) -> T {
 		self.ranges
 			.iter()
 			.fold(0.into(), |acc, r| acc + (r.1 - r.0 + 1.into()))
 	}
 
+	pub fn contains(&self, &element: &T) -> bool {
+		// find first range that has an end >= our element
+		let start_intersecting = self.ranges.partition_point(|other| other.1 < element);
+
+		// if none found, element is not contained
+		if start_intersecting == self.ranges.len() {
+			return false;
+		}
+
+		// if range found, element is contained, as long as the range start is <= our element.
+		self.ranges[start_intersecting].0 <= element
+	}
+
 	pub fn is_empty(&self) -> bool {
 		self.len() == 0.into()
 	}
+
+	pub fn first(&self) -> Option<T> {
+		self.ranges.first().map(|range| range.0)
+	}
+
+	pub fn last(&self) -> Option<T> {
+		self.ranges.last().map(|range| range.1)
+	}
+
+	/// The `n`th smallest element, counting from 0.
+	pub fn nth(&self, mut n: T) -> Option<T> {
+		for &(start, end) in &self.ranges {
+			let len = end - start + 1.into();
+			if n < len {
+				return Some(start + n);
+			}
+			n = n - len;
+		}
+		None
+	}
+
+	/// All elements, in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+		self.ranges.iter().flat_map(|&(start, end)| {
+			successors(Some(start), move |&element| {
+				(element < end).then(|| element + 1.into())
+			})
+		})
+	}
+}
+
+impl<T: RangeInt> Default for IntRangeSet<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: RangeInt> From<RangeInclusive<T>> for IntRangeSet<T> {
+	fn from(range: RangeInclusive<T>) -> Self {
+		let (start, end) = range.into_inner();
+		let mut set = IntRangeSet::new();
+		if start <= end {
+			set.add_range((start, end));
+		}
+		set
+	}
 }
 
+/// Collects elements.
+impl<T: RangeInt> FromIterator<T> for IntRangeSet<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(elements: I) -> Self {
+		let mut set = IntRangeSet::new();
+		for element in elements {
+			set.add_one(element);
+		}
+		set
+	}
+}
+
+/// Collects inclusive `(start, end)` ranges, which may overlap.
+impl<T: RangeInt> FromIterator<(T, T)> for IntRangeSet<T> {
+	fn from_iter<I: IntoIterator<Item = (T, T)>>(ranges: I) -> Self {
+		// sorting once beats inserting every range into the middle
+		let mut sorted: Vec<(T, T)> = ranges.into_iter().collect();
+		sorted.sort_unstable();
+
+		let mut ranges: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+		for range in sorted {
+			debug_assert!(range.0 <= range.1);
+			match ranges.last_mut() {
+				Some(last) if range.0 <= last.1 + 1.into() => last.1 = last.1.max(range.1),
+				_ => ranges.push(range),
+			}
+		}
+		IntRangeSet { ranges }
+	}
+}
+
+macro_rules! set_operator {
+	($operator:ident, $method:ident, $operation:ident) => {
+		impl<T: RangeInt> $operator for &IntRangeSet<T> {
+			type Output = IntRangeSet<T>;
+
+			fn $method(self, other: Self) -> IntRangeSet<T> {
+				self.$operation(other)
+			}
+		}
+
+		impl<T: RangeInt> $operator for IntRangeSet<T> {
+			type Output = IntRangeSet<T>;
+
+			fn $method(self, other: Self) -> IntRangeSet<T> {
+				self.$operation(&other)
+			}
+		}
+	};
+}
+
+set_operator!(BitOr, bitor, union);
+set_operator!(BitAnd, bitand, intersection);
+set_operator!(Sub, sub, difference);
+set_operator!(BitXor, bitxor, symmetric_difference);
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -195,4 +452,108 @@ mod tests {
 		set.invert((0, 52));
 		assert_eq!(set.ranges, vec![(21, 21), (41, 43), (45, 49), (51, 52)]);
 	}
+
+	#[test]
+	fn removes_correctly() {
+		let mut set: IntRangeSet<usize> = [(2, 12), (14, 17)].into_iter().collect();
+
+		set.remove_range((5, 6));
+		assert_eq!(set.ranges, vec![(2, 4), (7, 12), (14, 17)]);
+
+		set.remove_range((13, 13));
+		assert_eq!(set.ranges, vec![(2, 4), (7, 12), (14, 17)]);
+
+		set.remove_range((4, 14));
+		assert_eq!(set.ranges, vec![(2, 3), (15, 17)]);
+
+		set.remove_one(17);
+		set.remove_one(2);
+		assert_eq!(set.ranges, vec![(3, 3), (15, 16)]);
+
+		set.remove_range((0, 20));
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	fn contains_correctly() {
+		let set = IntRangeSet::from(2..=4_usize);
+		assert!(!set.contains(&1));
+		assert!(set.contains(&2));
+		assert!(set.contains(&4));
+		assert!(!set.contains(&5));
+	}
+
+	#[test]
+	fn contains_nothing_past_range_ends() {
+		// `contains` used to look for the first range ending at `element - 1` or later, which
+		// counted the element right after every range as contained
+		let set: IntRangeSet<u8> = [(2, 4), (8, 9), (12, 12)].into_iter().collect();
+		for element in [5, 10, 13] {
+			assert!(!set.contains(&element), "{element}");
+		}
+		for element in [4, 9, 12] {
+			assert!(set.contains(&element), "{element}");
+		}
+	}
+
+	#[test]
+	fn combines_sets() {
+		let a: IntRangeSet<i32> = [(-5, 0), (4, 6), (10, 20)].into_iter().collect();
+		let b: IntRangeSet<i32> = [(-2, 5), (8, 8), (12, 13), (19, 25)].into_iter().collect();
+
+		assert_eq!((&a | &b).ranges, vec![(-5, 6), (8, 8), (10, 25)]);
+		assert_eq!((&a & &b).ranges, vec![(-2, 0), (4, 5), (12, 13), (19, 20)]);
+		assert_eq!((&a - &b).ranges, vec![(-5, -3), (6, 6), (10, 11), (14, 18)]);
+		assert_eq!(
+			(&a ^ &b).ranges,
+			vec![
+				(-5, -3),
+				(1, 3),
+				(6, 6),
+				(8, 8),
+				(10, 11),
+				(14, 18),
+				(21, 25)
+			]
+		);
+
+		// every operation agrees with the element-wise one
+		let elements = |set: &IntRangeSet<i32>| set.iter().collect::<Vec<_>>();
+		let filtered = |keep: &dyn Fn(bool, bool) -> bool| {
+			(-10..30)
+				.filter(|element| keep(a.contains(element), b.contains(element)))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(elements(&(&a | &b)), filtered(&|a, b| a || b));
+		assert_eq!(elements(&(&a & &b)), filtered(&|a, b| a && b));
+		assert_eq!(elements(&(&a - &b)), filtered(&|a, b| a && !b));
+		assert_eq!(elements(&(a.clone() ^ b.clone())), filtered(&|a, b| a != b));
+	}
+
+	#[test]
+	fn compares_sets() {
+		let a: IntRangeSet<u8> = [(2, 4), (8, 9)].into_iter().collect();
+		let b: IntRangeSet<u8> = [1, 2, 3, 4, 5, 8, 9].into_iter().collect();
+		let c = IntRangeSet::from(5..=7_u8);
+
+		assert!(a.is_subset(&b));
+		assert!(!b.is_subset(&a));
+		assert!(b.is_superset(&a));
+		assert!(a.is_disjoint(&c));
+		assert!(!b.is_disjoint(&c));
+		assert!(IntRangeSet::new().is_subset(&a));
+	}
+
+	#[test]
+	fn finds_elements() {
+		let set: IntRangeSet<usize> = [(2, 4), (8, 9)].into_iter().collect();
+
+		assert_eq!(set.first(), Some(2));
+		assert_eq!(set.last(), Some(9));
+		assert_eq!(set.iter().collect::<Vec<_>>(), vec![2, 3, 4, 8, 9]);
+		assert_eq!(set.nth(0), Some(2));
+		assert_eq!(set.nth(3), Some(8));
+		assert_eq!(set.nth(5), None);
+		assert_eq!(IntRangeSet::<usize>::new().first(), None);
+	}
 }
@@ -1,3 +1,7 @@
+use std::borrow::Cow;
+
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
 #[inline]
 pub fn trim_end_newline(input: &[u8]) -> &[u8] {
 	if let Some(b'\n') = input.last() {
@@ -6,3 +10,116 @@ pub fn trim_end_newline(input: &[u8]) -> &[u8] {
 		input
 	}
 }
+
+/// Undoes what editors and operating systems like to do to a saved input: strips a byte
+/// order mark and trailing blank lines and turns `\r\n` into `\n`. A final newline is
+/// kept if there was one, solvers have to do without it anyway.
+///
+/// Solvers don't call this themselves, the runners do when they read an input file.
+/// Only allocates if the input has `\r\n` line endings.
+pub fn normalize(input: &[u8]) -> Cow<'_, [u8]> {
+	let input = input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(input);
+
+	// Drops the last line as long as it is blank, `end` being the newline before it.
+	let mut end = input.len();
+	loop {
+		let line_start = input[..end]
+			.iter()
+			.rposition(|&b| b == b'\n')
+			.map_or(0, |i| i + 1);
+		if !input[line_start..end]
+			.iter()
+			.all(|b| b.is_ascii_whitespace())
+		{
+			break;
+		}
+		if line_start == 0 {
+			return Cow::Borrowed(&[]);
+		}
+		end = line_start - 1;
+	}
+
+	let final_newline = end < input.len();
+	let content = &input[..end];
+	if !content.contains(&b'\r') {
+		return Cow::Borrowed(&input[..end + final_newline as usize]);
+	}
+
+	let mut normalized = Vec::with_capacity(content.len() + 1);
+	for (index, line) in content.split(|&b| b == b'\n').enumerate() {
+		if index > 0 {
+			normalized.push(b'\n');
+		}
+		normalized.extend_from_slice(line.strip_suffix(b"\r").unwrap_or(line));
+	}
+	if final_newline {
+		normalized.push(b'\n');
+	}
+	Cow::Owned(normalized)
+}
+
+/// The input as it might have been saved instead: with `\r\n` line endings, a byte order
+/// mark, blank lines or no newline at the end, and all of it at once. After [`normalize`]
+/// every variant should solve like the input itself.
+pub fn variants(input: &[u8]) -> [(&'static str, Vec<u8>); 5] {
+	let mut crlf = Vec::with_capacity(input.len() * 2);
+	for &byte in input {
+		if byte == b'\n' {
+			crlf.push(b'\r');
+		}
+		crlf.push(byte);
+	}
+	let all = [BYTE_ORDER_MARK, &crlf[..], b"\r\n\r\n"].concat();
+
+	[
+		("crlf", crlf),
+		("byte order mark", [BYTE_ORDER_MARK, input].concat()),
+		("blank lines", [input, b"\n\n \n"].concat()),
+		(
+			"no final newline",
+			input.strip_suffix(b"\n").unwrap_or(input).to_vec(),
+		),
+		("all", all),
+	]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn normalizes_inputs() {
+		let normalized = |input: &[u8]| match normalize(input) {
+			Cow::Borrowed(normalized) => (normalized.to_vec(), false),
+			Cow::Owned(normalized) => (normalized, true),
+		};
+		let borrowed = |expected: &[u8]| (expected.to_vec(), false);
+		let owned = |expected: &[u8]| (expected.to_vec(), true);
+
+		assert_eq!(normalized(b"ab\ncd\n"), borrowed(b"ab\ncd\n"));
+		assert_eq!(normalized(b"ab\ncd\n\n \n\t\n"), borrowed(b"ab\ncd\n"));
+		assert_eq!(normalized(b"\xEF\xBB\xBFab  \n"), borrowed(b"ab  \n"));
+		assert_eq!(normalized(b"ab\n\ncd\n"), borrowed(b"ab\n\ncd\n"));
+		assert_eq!(normalized(b"\n \n"), borrowed(b""));
+		assert_eq!(normalized(b""), borrowed(b""));
+		assert_eq!(normalized(b"ab\ncd"), borrowed(b"ab\ncd"));
+		assert_eq!(normalized(b"\xEF\xBB\xBFab\ncd"), borrowed(b"ab\ncd"));
+
+		assert_eq!(normalized(b"ab\r\ncd\r\n\r\n"), owned(b"ab\ncd\n"));
+		assert_eq!(normalized(b"ab\r\n\r\ncd"), owned(b"ab\n\ncd"));
+		assert_eq!(normalized(b"ab\ncd\r\n"), owned(b"ab\ncd\n"));
+	}
+
+	#[test]
+	fn normalizes_variants() {
+		let input = b"ab\ncd\n";
+		for (variant, saved) in variants(input) {
+			let expected: &[u8] = if variant == "no final newline" {
+				b"ab\ncd"
+			} else {
+				input
+			};
+			assert_eq!(&normalize(&saved)[..], expected, "{variant}");
+		}
+	}
+}
@@ -1,9 +1,19 @@
-use aoc_utils::{ascii_int::parse_uint_unchecked, trim::trim_end_newline};
+use aoc_utils::{ascii_int::parse_uint_unchecked, phase::span, trim::trim_end_newline};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 pub fn solve(input: &[u8]) -> Solution {
+	let parsing = span("parse");
 	let input = trim_end_newline(input);
 	let space_width = 3;
 	let num_width = input
@@ -22,10 +32,14 @@ pub fn solve(input: &[u8]) -> Solution {
 			(num_l, num_r)
 		})
 		.collect();
+	parsing.end();
 
+	let sorting = span("sort");
 	left.sort_unstable();
 	right.sort_unstable();
+	sorting.end();
 
+	let _comparing = span("compare");
 	let difference: usize = left.iter().zip(&right).map(|(a, b)| a.abs_diff(*b)).sum();
 
 	let right_run_length: Vec<_> = right
@@ -53,6 +67,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -60,11 +77,45 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(11,31),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(1941353,22539317),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Pairs up sorted lists and counts every left id in the whole right list.
+	fn naive(input: &[u8]) -> Solution {
+		let (mut left, mut right): (Vec<usize>, Vec<usize>) = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| {
+				let (l, r) = line.split_once("   ").unwrap();
+				(l.parse::<usize>().unwrap(), r.parse::<usize>().unwrap())
+			})
+			.unzip();
+		left.sort();
+		right.sort();
+
+		let difference = left.iter().zip(&right).map(|(a, b)| a.abs_diff(*b)).sum();
+		let similarity = left
+			.iter()
+			.map(|id| id * right.iter().filter(|other| *other == id).count())
+			.sum();
+		Solution(difference, similarity)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..200_usize) {
+			let input = generate::day_01(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
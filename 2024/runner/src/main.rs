@@ -0,0 +1,31 @@
+use aoc_cli::{Day, Harness};
+use aoc_utils::{allocations, trim};
+use std::process::ExitCode;
+
+const DAYS: &[Day] = &[Day::new(
+	1,
+	"Historian Hysteria",
+	"days/01-historian-hysteria/inputs/personal.txt",
+	|input, _| aoc_cli::answers(day_01_historian_hysteria::solve(input)),
+)];
+
+#[global_allocator]
+static ALLOCATOR: allocations::Counting = allocations::Counting;
+
+fn count_allocations(solve: &mut dyn FnMut()) -> String {
+	let ((), allocations) = allocations::count(solve);
+	allocations.to_string()
+}
+
+fn main() -> ExitCode {
+	let harness = Harness {
+		count_allocations: Some(count_allocations),
+		prepare_input: Some(trim::normalize),
+	};
+	aoc_cli::main_with(
+		2024,
+		concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+		DAYS,
+		harness,
+	)
+}
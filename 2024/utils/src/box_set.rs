@@ -0,0 +1,223 @@
+//! Axis aligned boxes in any number of dimensions, and sets of points made of them.
+
+use crate::range_set::RangeInt;
+use std::ops::Mul;
+
+/// All points between two corners, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize, T> {
+	pub min: [T; N],
+	pub max: [T; N],
+}
+
+impl<const N: usize, T: RangeInt + Mul<Output = T>> Cuboid<N, T> {
+	pub fn new(min: [T; N], max: [T; N]) -> Self {
+		debug_assert!((0..N).all(|axis| min[axis] <= max[axis]));
+		Cuboid { min, max }
+	}
+
+	pub fn point(point: [T; N]) -> Self {
+		Cuboid {
+			min: point,
+			max: point,
+		}
+	}
+
+	/// The number of points inside.
+	pub fn volume(&self) -> T {
+		(0..N).fold(1.into(), |volume, axis| {
+			volume * (self.max[axis] - self.min[axis] + 1.into())
+		})
+	}
+
+	pub fn contains(&self, point: &[T; N]) -> bool {
+		(0..N).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+	}
+
+	pub fn intersects(&self, other: &Self) -> bool {
+		(0..N).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+	}
+
+	pub fn intersection(&self, other: &Self) -> Option<Self> {
+		self.intersects(other).then(|| Cuboid {
+			min: std::array::from_fn(|axis| self.min[axis].max(other.min[axis])),
+			max: std::array::from_fn(|axis| self.max[axis].min(other.max[axis])),
+		})
+	}
+
+	/// The points of `self` outside of `other`, as at most `2 * N` disjoint cuboids.
+	pub fn subtract(&self, other: &Self) -> Vec<Self> {
+		let mut pieces = vec![];
+		self.subtract_into(other, &mut pieces);
+		pieces
+	}
+
+	fn subtract_into(&self, other: &Self, pieces: &mut Vec<Self>) {
+		let Some(cut) = self.intersection(other) else {
+			pieces.push(*self);
+			return;
+		};
+
+		// slices off what lies below and above the cut along every axis in turn, leaving
+		// the cut itself
+		let mut rest = *self;
+		for axis in 0..N {
+			if rest.min[axis] < cut.min[axis] {
+				let mut below = rest;
+				below.max[axis] = cut.min[axis] - 1.into();
+				pieces.push(below);
+				rest.min[axis] = cut.min[axis];
+			}
+			if rest.max[axis] > cut.max[axis] {
+				let mut above = rest;
+				above.min[axis] = cut.max[axis] + 1.into();
+				pieces.push(above);
+				rest.max[axis] = cut.max[axis];
+			}
+		}
+	}
+}
+
+/// A set of points, kept as disjoint cuboids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxSet<const N: usize, T> {
+	cuboids: Vec<Cuboid<N, T>>,
+}
+
+impl<const N: usize, T: RangeInt + Mul<Output = T>> BoxSet<N, T> {
+	pub const fn new() -> Self {
+		BoxSet { cuboids: vec![] }
+	}
+
+	/// Disjoint cuboids making up the set, in no particular order.
+	pub fn cuboids(&self) -> &[Cuboid<N, T>] {
+		&self.cuboids
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.cuboids.is_empty()
+	}
+
+	pub fn add(&mut self, cuboid: Cuboid<N, T>) {
+		self.remove(cuboid);
+		self.cuboids.push(cuboid);
+	}
+
+	pub fn remove(&mut self, cuboid: Cuboid<N, T>) {
+		let mut kept = Vec::with_capacity(self.cuboids.len());
+		for own in self.cuboids.drain(..) {
+			own.subtract_into(&cuboid, &mut kept);
+		}
+		self.cuboids = kept;
+	}
+
+	/// The number of points in the set.
+	pub fn volume(&self) -> T {
+		self.cuboids
+			.iter()
+			.fold(0.into(), |volume, cuboid| volume + cuboid.volume())
+	}
+
+	pub fn contains(&self, point: &[T; N]) -> bool {
+		self.cuboids.iter().any(|cuboid| cuboid.contains(point))
+	}
+
+	pub fn intersects(&self, cuboid: &Cuboid<N, T>) -> bool {
+		self.cuboids.iter().any(|own| own.intersects(cuboid))
+	}
+
+	/// The points of the set inside of `cuboid`.
+	pub fn intersection(&self, cuboid: &Cuboid<N, T>) -> Self {
+		BoxSet {
+			cuboids: self
+				.cuboids
+				.iter()
+				.filter_map(|own| own.intersection(cuboid))
+				.collect(),
+		}
+	}
+}
+
+impl<const N: usize, T: RangeInt + Mul<Output = T>> Default for BoxSet<N, T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cuts_cuboids() {
+		let cuboid = Cuboid::new([0, 0, 0], [9_i64, 9, 9]);
+		let center = Cuboid::new([3, 3, 3], [5, 5, 5]);
+		let corner = Cuboid::new([-5, -5, 8], [0, 0, 20]);
+
+		assert_eq!(cuboid.volume(), 1000);
+		assert!(cuboid.contains(&[9, 0, 4]));
+		assert!(!cuboid.contains(&[10, 0, 4]));
+		assert_eq!(
+			cuboid.intersection(&corner),
+			Some(Cuboid::new([0, 0, 8], [0, 0, 9]))
+		);
+		assert_eq!(center.intersection(&corner), None);
+
+		let around_center = cuboid.subtract(&center);
+		assert_eq!(around_center.len(), 6);
+		assert_eq!(
+			around_center.iter().map(Cuboid::volume).sum::<i64>(),
+			1000 - 27
+		);
+		assert!(!around_center.iter().any(|piece| piece.intersects(&center)));
+
+		assert_eq!(cuboid.subtract(&corner).len(), 3);
+		assert_eq!(center.subtract(&corner), [center]);
+		assert_eq!(center.subtract(&cuboid), []);
+	}
+
+	#[test]
+	fn reboots_reactor() {
+		// example of 2021 day 22
+		let steps = [
+			(true, [10, 10, 10], [12, 12, 12]),
+			(true, [11, 11, 11], [13, 13, 13]),
+			(false, [9, 9, 9], [11, 11, 11]),
+			(true, [10, 10, 10], [10, 10, 10]),
+		];
+		let mut reactor = BoxSet::<3, i32>::new();
+		let mut volumes = vec![];
+		for (on, min, max) in steps {
+			if on {
+				reactor.add(Cuboid::new(min, max));
+			} else {
+				reactor.remove(Cuboid::new(min, max));
+			}
+			volumes.push(reactor.volume());
+		}
+
+		assert_eq!(volumes, [27, 46, 38, 39]);
+		assert!(reactor.contains(&[10, 10, 10]));
+		assert!(!reactor.contains(&[11, 11, 11]));
+		assert!(reactor.intersects(&Cuboid::point([13, 13, 13])));
+		assert_eq!(
+			reactor
+				.intersection(&Cuboid::new([12, 12, 12], [20, 20, 20]))
+				.volume(),
+			8
+		);
+	}
+
+	#[test]
+	fn works_in_any_dimension() {
+		let mut set = BoxSet::<2, u32>::new();
+		set.add(Cuboid::new([0, 0], [3, 3]));
+		set.remove(Cuboid::point([1, 1]));
+		assert_eq!(set.volume(), 15);
+
+		let mut set = BoxSet::<4, u64>::new();
+		set.add(Cuboid::new([0; 4], [1; 4]));
+		set.add(Cuboid::new([1; 4], [2; 4]));
+		assert_eq!(set.volume(), 31);
+	}
+}
@@ -0,0 +1,73 @@
+//! Random puzzle inputs of configurable size, for property tests against naive
+//! implementations and for benchmarking beyond the personal input.
+//!
+//! Every generator follows the format and the (sometimes implicit) guarantees of the
+//! real puzzle input, `size` roughly scales the input linearly or per side of a grid.
+
+use std::{io::Write, ops::Range};
+
+/// Seedable random number generator (SplitMix64), reproducible across platforms.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Rng(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number in `range`, which must not be empty.
+	pub fn range(&mut self, range: Range<usize>) -> usize {
+		assert!(!range.is_empty(), "empty range");
+		range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+	}
+
+	/// `true` with a probability of `numerator / denominator`.
+	pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+		self.range(0..denominator) < numerator
+	}
+
+	pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.range(0..items.len())]
+	}
+
+	/// A number with exactly `digits` digits.
+	pub fn digits(&mut self, digits: u32) -> usize {
+		self.range(10_usize.pow(digits - 1)..10_usize.pow(digits))
+	}
+}
+
+/// Day 01: `size` pairs of 5 digit location ids, a third of the right ones copied from
+/// the left list.
+pub fn day_01(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let left: Vec<usize> = (0..size).map(|_| rng.digits(5)).collect();
+
+	let mut input = vec![];
+	for &id in &left {
+		let other = if rng.chance(1, 3) {
+			*rng.pick(&left)
+		} else {
+			rng.digits(5)
+		};
+		writeln!(input, "{id}   {other}").unwrap();
+	}
+	input
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn is_reproducible() {
+		assert_eq!(day_01(&mut Rng::new(7), 10), day_01(&mut Rng::new(7), 10));
+		assert_ne!(day_01(&mut Rng::new(7), 10), day_01(&mut Rng::new(8), 10));
+	}
+}
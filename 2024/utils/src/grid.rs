@@ -0,0 +1,482 @@
+//! Rectangular maps, as so many puzzle inputs are.
+//!
+//! [`ByteGrid`] views a newline separated input as it is, so its indices are byte offsets
+//! into the input. [`Grid`] owns its cells without any padding, and can be rotated and
+//! transposed. Both address cells by index as well as by `(x, y)`, with `(0, 0)` in the
+//! top left corner and `y` growing southwards.
+
+use crate::direction::{Direction, East, North, South, West};
+use std::{
+	fmt,
+	ops::{Index, IndexMut},
+	slice::ChunksExact,
+};
+
+/// Offsets of the eight neighbours, in reading order.
+const AROUND: [(isize, isize); 8] = [
+	(-1, -1),
+	(0, -1),
+	(1, -1),
+	(-1, 0),
+	(1, 0),
+	(-1, 1),
+	(0, 1),
+	(1, 1),
+];
+
+/// Size of a grid and the distance between the starts of its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Shape {
+	width: usize,
+	height: usize,
+	line_width: usize,
+}
+
+impl Shape {
+	#[inline]
+	fn index_of(self, x: usize, y: usize) -> Option<usize> {
+		(x < self.width && y < self.height).then(|| y * self.line_width + x)
+	}
+
+	#[inline]
+	fn position(self, index: usize) -> Option<(usize, usize)> {
+		let (x, y) = (index.checked_rem(self.line_width)?, index / self.line_width);
+		(x < self.width && y < self.height).then_some((x, y))
+	}
+
+	#[inline]
+	fn step(self, index: usize, direction: Direction) -> Option<usize> {
+		let (x, y) = self.position(index)?;
+		match direction {
+			North => self.index_of(x, y.checked_sub(1)?),
+			East => self.index_of(x + 1, y),
+			South => self.index_of(x, y + 1),
+			West => self.index_of(x.checked_sub(1)?, y),
+		}
+	}
+
+	fn neighbours(self, index: usize) -> impl Iterator<Item = usize> {
+		[North, East, South, West]
+			.into_iter()
+			.filter_map(move |direction| self.step(index, direction))
+	}
+
+	fn neighbours8(self, index: usize) -> impl Iterator<Item = usize> {
+		let position = self.position(index);
+		AROUND.into_iter().filter_map(move |(dx, dy)| {
+			let (x, y) = position?;
+			self.index_of(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)
+		})
+	}
+}
+
+/// A zero-copy view of newline separated rows of equal width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteGrid<'a> {
+	bytes: &'a [u8],
+	shape: Shape,
+}
+
+impl<'a> ByteGrid<'a> {
+	/// Views `input`, with or without its final newline.
+	pub fn new(input: &'a [u8]) -> Self {
+		let width = input
+			.iter()
+			.position(|&b| b == b'\n')
+			.unwrap_or(input.len());
+		let line_width = width + 1;
+		let height = if input.is_empty() {
+			0
+		} else {
+			(input.len() + 1) / line_width
+		};
+		debug_assert!(
+			input.len().is_multiple_of(line_width) || (input.len() + 1).is_multiple_of(line_width),
+			"rows of a grid must have the same width"
+		);
+
+		ByteGrid {
+			bytes: input,
+			shape: Shape {
+				width,
+				height,
+				line_width,
+			},
+		}
+	}
+
+	pub fn bytes(&self) -> &'a [u8] {
+		self.bytes
+	}
+
+	pub fn width(&self) -> usize {
+		self.shape.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.shape.height
+	}
+
+	/// Width plus the newline, the distance between cells above each other.
+	pub fn line_width(&self) -> usize {
+		self.shape.line_width
+	}
+
+	/// The byte offset of `(x, y)`, if it is on the grid.
+	#[inline]
+	pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+		self.shape.index_of(x, y)
+	}
+
+	/// The `(x, y)` of a byte offset, if it is on the grid and not a newline.
+	#[inline]
+	pub fn position(&self, index: usize) -> Option<(usize, usize)> {
+		self.shape.position(index)
+	}
+
+	#[inline]
+	pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+		self.index_of(x, y).map(|index| self.bytes[index])
+	}
+
+	/// The index one step into `direction`, unless that leaves the grid.
+	#[inline]
+	pub fn step(&self, index: usize, direction: Direction) -> Option<usize> {
+		self.shape.step(index, direction)
+	}
+
+	/// Indices of the up to four cells next to `index`, clockwise from the north.
+	pub fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> {
+		self.shape.neighbours(index)
+	}
+
+	/// Indices of the up to eight cells around `index`, diagonals included, in reading
+	/// order.
+	pub fn neighbours8(&self, index: usize) -> impl Iterator<Item = usize> {
+		self.shape.neighbours8(index)
+	}
+
+	pub fn row(&self, y: usize) -> &'a [u8] {
+		assert!(y < self.height(), "row {y} is out of bounds");
+		let start = y * self.line_width();
+		&self.bytes[start..start + self.width()]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> {
+		let grid = *self;
+		(0..self.height()).map(move |y| grid.row(y))
+	}
+
+	pub fn column(&self, x: usize) -> impl Iterator<Item = u8> + 'a {
+		assert!(x < self.width(), "column {x} is out of bounds");
+		self.bytes
+			.iter()
+			.skip(x)
+			.step_by(self.line_width())
+			.take(self.height())
+			.copied()
+	}
+
+	/// Copies the cells into a [`Grid`], converting every byte with `f`.
+	pub fn map<T>(&self, f: impl FnMut(u8) -> T) -> Grid<T> {
+		Grid {
+			shape: Shape {
+				line_width: self.width(),
+				..self.shape
+			},
+			cells: self.rows().flatten().copied().map(f).collect(),
+		}
+	}
+
+	pub fn to_grid(&self) -> Grid<u8> {
+		self.map(|byte| byte)
+	}
+}
+
+impl Index<usize> for ByteGrid<'_> {
+	type Output = u8;
+
+	#[inline]
+	fn index(&self, index: usize) -> &u8 {
+		&self.bytes[index]
+	}
+}
+
+impl Index<(usize, usize)> for ByteGrid<'_> {
+	type Output = u8;
+
+	#[inline]
+	fn index(&self, (x, y): (usize, usize)) -> &u8 {
+		let index = self.index_of(x, y).expect("position is on the grid");
+		&self.bytes[index]
+	}
+}
+
+impl fmt::Display for ByteGrid<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for row in self.rows() {
+			writeln!(f, "{}", String::from_utf8_lossy(row))?;
+		}
+		Ok(())
+	}
+}
+
+/// Cells of any type, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	shape: Shape,
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	/// A grid of `width` by `height` cells, given row by row.
+	pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+		assert_eq!(
+			cells.len(),
+			width * height,
+			"a {width} by {height} grid needs {} cells",
+			width * height
+		);
+		Grid {
+			shape: Shape {
+				width,
+				height,
+				line_width: width,
+			},
+			cells,
+		}
+	}
+
+	pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+		let cells = (0..height)
+			.flat_map(|y| (0..width).map(move |x| (x, y)))
+			.map(|(x, y)| f(x, y))
+			.collect();
+		Grid::new(width, height, cells)
+	}
+
+	/// Parses newline separated rows, converting every byte with `f`.
+	pub fn parse(input: &[u8], f: impl FnMut(u8) -> T) -> Self {
+		ByteGrid::new(input).map(f)
+	}
+
+	pub fn width(&self) -> usize {
+		self.shape.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.shape.height
+	}
+
+	pub fn cells(&self) -> &[T] {
+		&self.cells
+	}
+
+	pub fn cells_mut(&mut self) -> &mut [T] {
+		&mut self.cells
+	}
+
+	#[inline]
+	pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+		self.shape.index_of(x, y)
+	}
+
+	#[inline]
+	pub fn position(&self, index: usize) -> Option<(usize, usize)> {
+		self.shape.position(index)
+	}
+
+	#[inline]
+	pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+		self.index_of(x, y).map(|index| &self.cells[index])
+	}
+
+	#[inline]
+	pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+		self.index_of(x, y).map(|index| &mut self.cells[index])
+	}
+
+	/// The index one step into `direction`, unless that leaves the grid.
+	#[inline]
+	pub fn step(&self, index: usize, direction: Direction) -> Option<usize> {
+		self.shape.step(index, direction)
+	}
+
+	/// Indices of the up to four cells next to `index`, clockwise from the north.
+	pub fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> {
+		self.shape.neighbours(index)
+	}
+
+	/// Indices of the up to eight cells around `index`, diagonals included, in reading
+	/// order.
+	pub fn neighbours8(&self, index: usize) -> impl Iterator<Item = usize> {
+		self.shape.neighbours8(index)
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		assert!(y < self.height(), "row {y} is out of bounds");
+		&self.cells[y * self.width()..(y + 1) * self.width()]
+	}
+
+	pub fn rows(&self) -> ChunksExact<'_, T> {
+		// An empty grid has no cells to chunk, whatever the size.
+		self.cells.chunks_exact(self.width().max(1))
+	}
+
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+		assert!(x < self.width(), "column {x} is out of bounds");
+		self.cells.iter().skip(x).step_by(self.width())
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+		(0..self.width()).map(move |x| self.column(x))
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid {
+			shape: self.shape,
+			cells: self.cells.iter().map(f).collect(),
+		}
+	}
+
+	/// Mirrors the grid along its diagonal from the top left, turning rows into columns.
+	pub fn transpose(&self) -> Self
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.height(), self.width(), |x, y| self[(y, x)].clone())
+	}
+
+	pub fn rotate_clockwise(&self) -> Self
+	where
+		T: Clone,
+	{
+		let height = self.height();
+		Grid::from_fn(height, self.width(), |x, y| {
+			self[(y, height - 1 - x)].clone()
+		})
+	}
+
+	pub fn rotate_widdershins(&self) -> Self
+	where
+		T: Clone,
+	{
+		let width = self.width();
+		Grid::from_fn(self.height(), width, |x, y| {
+			self[(width - 1 - y, x)].clone()
+		})
+	}
+}
+
+impl<T> Index<usize> for Grid<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, index: usize) -> &T {
+		&self.cells[index]
+	}
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		&mut self.cells[index]
+	}
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		self.get(x, y).expect("position is on the grid")
+	}
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+	#[inline]
+	fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+		self.get_mut(x, y).expect("position is on the grid")
+	}
+}
+
+/// Renders the grid like the puzzle shows it, one character per cell.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for row in self.rows() {
+			let row: String = row.iter().map(|&cell| cell.into()).collect();
+			writeln!(f, "{row}")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const INPUT: &[u8] = b"abc\ndef\n";
+
+	#[test]
+	fn views_bytes() {
+		let grid = ByteGrid::new(INPUT);
+		assert_eq!((grid.width(), grid.height(), grid.line_width()), (3, 2, 4));
+		assert_eq!(ByteGrid::new(b"abc\ndef").height(), 2);
+		assert_eq!(ByteGrid::new(b"").height(), 0);
+
+		assert_eq!(grid.index_of(1, 1), Some(5));
+		assert_eq!(grid.index_of(3, 0), None);
+		assert_eq!(grid.position(5), Some((1, 1)));
+		assert_eq!(grid.position(3), None);
+		assert_eq!(grid.get(2, 1), Some(b'f'));
+		assert_eq!(grid[(0, 1)], b'd');
+
+		assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+		assert_eq!(grid.column(1).collect::<Vec<_>>(), b"be");
+		assert_eq!(grid.to_string(), "abc\ndef\n");
+	}
+
+	#[test]
+	fn finds_neighbours() {
+		let grid = ByteGrid::new(INPUT);
+		assert_eq!(grid.step(1, North), None);
+		assert_eq!(grid.step(2, East), None);
+		assert_eq!(grid.step(4, West), None);
+		assert_eq!(grid.step(1, South), Some(5));
+		assert_eq!(grid.neighbours(1).collect::<Vec<_>>(), [2, 5, 0]);
+		assert_eq!(grid.neighbours8(4).collect::<Vec<_>>(), [0, 1, 5]);
+		assert_eq!(grid.neighbours8(3).count(), 0);
+
+		let grid = grid.to_grid();
+		assert_eq!(grid.neighbours(1).collect::<Vec<_>>(), [2, 4, 0]);
+		assert_eq!(grid.neighbours8(4).collect::<Vec<_>>(), [0, 1, 2, 3, 5]);
+		assert_eq!(grid[grid.step(0, South).unwrap()], b'd');
+	}
+
+	#[test]
+	fn transforms_grids() {
+		let grid = Grid::parse(INPUT, char::from);
+		assert_eq!(grid.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+		assert_eq!(grid.row(1), ['d', 'e', 'f']);
+		assert_eq!(
+			grid.columns()
+				.map(|column| column.collect::<String>())
+				.collect::<Vec<_>>(),
+			["ad", "be", "cf"]
+		);
+
+		assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+		assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+		assert_eq!(grid.rotate_widdershins().to_string(), "cf\nbe\nad\n");
+		assert_eq!(
+			grid.rotate_clockwise().rotate_clockwise(),
+			grid.rotate_widdershins().rotate_widdershins()
+		);
+
+		let mut grid = Grid::from_fn(3, 2, |x, y| x + y);
+		grid[(2, 1)] = 9;
+		grid[0] = 7;
+		assert_eq!(grid.cells(), [7, 1, 2, 1, 2, 9]);
+		assert_eq!(grid.map(|&cell| cell > 1).get(0, 1), Some(&false));
+	}
+}
@@ -0,0 +1,52 @@
+//! A fast hash for the small keys of puzzles, shared by the searches and memos.
+
+use std::hash::Hasher;
+
+/// The multiply and rotate hash of rustc, much faster than the default for the small keys
+/// of searches and memos. Not resistant to collisions anyone provokes, which puzzles
+/// don't.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+	hash: u64,
+}
+
+impl Hasher for FxHasher {
+	#[inline]
+	fn write(&mut self, bytes: &[u8]) {
+		for chunk in bytes.chunks(8) {
+			let mut word = [0; 8];
+			word[..chunk.len()].copy_from_slice(chunk);
+			self.write_u64(u64::from_le_bytes(word));
+		}
+	}
+
+	#[inline]
+	fn write_u64(&mut self, word: u64) {
+		self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+	}
+
+	#[inline]
+	fn write_u8(&mut self, byte: u8) {
+		self.write_u64(byte.into());
+	}
+
+	#[inline]
+	fn write_u16(&mut self, word: u16) {
+		self.write_u64(word.into());
+	}
+
+	#[inline]
+	fn write_u32(&mut self, word: u32) {
+		self.write_u64(word.into());
+	}
+
+	#[inline]
+	fn write_usize(&mut self, word: usize) {
+		self.write_u64(word as u64);
+	}
+
+	#[inline]
+	fn finish(&self) -> u64 {
+		self.hash
+	}
+}
@@ -0,0 +1,289 @@
+//! Hexagonal grids, in axial coordinates.
+//!
+//! A [`Hex`] is addressed by `q`, growing eastwards, and `r`, growing south(-west)wards.
+//! The third cube coordinate `s` is implied by `q + r + s == 0`. Depending on whether
+//! the hexagons have a flat side or a corner at the top, a puzzle names their six
+//! neighbours by [`FlatDirection`] or [`PointyDirection`]. Both turn like
+//! [`Direction`](crate::direction::Direction) does, by 60° per step.
+
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Directions between hexagons with a flat side at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatDirection {
+	North,
+	NorthEast,
+	SouthEast,
+	South,
+	SouthWest,
+	NorthWest,
+}
+
+/// Directions between hexagons with a corner at the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyDirection {
+	NorthEast,
+	East,
+	SouthEast,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+macro_rules! hex_direction {
+	($direction:ident, [$($variant:ident => ($q:literal, $r:literal)),* $(,)?]) => {
+		impl $direction {
+			/// All directions, clockwise from the northernmost.
+			pub const ALL: [$direction; 6] = [$($direction::$variant),*];
+
+			#[inline]
+			#[must_use]
+			pub const fn opposite(&self) -> $direction {
+				self.turn_clockwise_times(3)
+			}
+
+			/// Turns by 60°.
+			#[inline]
+			#[must_use]
+			pub const fn turn_clockwise(&self) -> $direction {
+				self.turn_clockwise_times(1)
+			}
+
+			/// Turns by 60°.
+			#[inline]
+			#[must_use]
+			pub const fn turn_widdershins(&self) -> $direction {
+				self.turn_widdershins_times(1)
+			}
+
+			/// Turns by `n_times` 60°.
+			#[inline]
+			#[must_use]
+			pub const fn turn_clockwise_times(&self, n_times: usize) -> $direction {
+				Self::ALL[(*self as usize + n_times % 6) % 6]
+			}
+
+			/// Turns by `n_times` 60°.
+			#[inline]
+			#[must_use]
+			pub const fn turn_widdershins_times(&self, n_times: usize) -> $direction {
+				Self::ALL[(*self as usize + 6 - n_times % 6) % 6]
+			}
+
+			/// The step in axial coordinates.
+			#[inline]
+			pub const fn offset(&self) -> Hex {
+				match self {
+					$($direction::$variant => Hex::new($q, $r),)*
+				}
+			}
+		}
+
+		impl Neg for $direction {
+			type Output = $direction;
+
+			#[inline]
+			fn neg(self) -> Self::Output {
+				self.opposite()
+			}
+		}
+
+		impl Add<usize> for $direction {
+			type Output = $direction;
+
+			#[inline]
+			fn add(self, rhs: usize) -> Self::Output {
+				self.turn_clockwise_times(rhs)
+			}
+		}
+
+		impl AddAssign<usize> for $direction {
+			#[inline]
+			fn add_assign(&mut self, rhs: usize) {
+				*self = self.turn_clockwise_times(rhs);
+			}
+		}
+
+		impl Sub<usize> for $direction {
+			type Output = $direction;
+
+			#[inline]
+			fn sub(self, rhs: usize) -> Self::Output {
+				self.turn_widdershins_times(rhs)
+			}
+		}
+
+		impl SubAssign<usize> for $direction {
+			#[inline]
+			fn sub_assign(&mut self, rhs: usize) {
+				*self = self.turn_widdershins_times(rhs);
+			}
+		}
+
+		impl Add<$direction> for Hex {
+			type Output = Hex;
+
+			#[inline]
+			fn add(self, direction: $direction) -> Hex {
+				self + direction.offset()
+			}
+		}
+
+		impl AddAssign<$direction> for Hex {
+			#[inline]
+			fn add_assign(&mut self, direction: $direction) {
+				*self += direction.offset();
+			}
+		}
+	};
+}
+
+hex_direction!(
+	FlatDirection,
+	[
+		North => (0, -1),
+		NorthEast => (1, -1),
+		SouthEast => (1, 0),
+		South => (0, 1),
+		SouthWest => (-1, 1),
+		NorthWest => (-1, 0),
+	]
+);
+
+hex_direction!(
+	PointyDirection,
+	[
+		NorthEast => (1, -1),
+		East => (1, 0),
+		SouthEast => (0, 1),
+		SouthWest => (-1, 1),
+		West => (-1, 0),
+		NorthWest => (0, -1),
+	]
+);
+
+/// A hexagon in axial coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+	pub q: isize,
+	pub r: isize,
+}
+
+impl Hex {
+	pub const ORIGIN: Hex = Hex::new(0, 0);
+
+	#[inline]
+	pub const fn new(q: isize, r: isize) -> Self {
+		Hex { q, r }
+	}
+
+	#[inline]
+	pub const fn from_cube(q: isize, r: isize, s: isize) -> Self {
+		debug_assert!(q + r + s == 0, "cube coordinates must add up to 0");
+		Hex { q, r }
+	}
+
+	#[inline]
+	pub const fn s(&self) -> isize {
+		-self.q - self.r
+	}
+
+	#[inline]
+	pub const fn cube(&self) -> (isize, isize, isize) {
+		(self.q, self.r, self.s())
+	}
+
+	/// The number of steps between the hexagons.
+	#[inline]
+	pub const fn distance(&self, other: Hex) -> usize {
+		(self.q.abs_diff(other.q) + self.r.abs_diff(other.r) + self.s().abs_diff(other.s())) / 2
+	}
+}
+
+impl Add for Hex {
+	type Output = Hex;
+
+	#[inline]
+	fn add(self, other: Hex) -> Hex {
+		Hex::new(self.q + other.q, self.r + other.r)
+	}
+}
+
+impl AddAssign for Hex {
+	#[inline]
+	fn add_assign(&mut self, other: Hex) {
+		*self = *self + other;
+	}
+}
+
+impl Sub for Hex {
+	type Output = Hex;
+
+	#[inline]
+	fn sub(self, other: Hex) -> Hex {
+		Hex::new(self.q - other.q, self.r - other.r)
+	}
+}
+
+impl SubAssign for Hex {
+	#[inline]
+	fn sub_assign(&mut self, other: Hex) {
+		*self = *self - other;
+	}
+}
+
+impl Neg for Hex {
+	type Output = Hex;
+
+	#[inline]
+	fn neg(self) -> Hex {
+		Hex::new(-self.q, -self.r)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn turns_six_ways() {
+		assert_eq!(FlatDirection::North + 1, FlatDirection::NorthEast);
+		assert_eq!(FlatDirection::North - 1, FlatDirection::NorthWest);
+		assert_eq!(-FlatDirection::SouthEast, FlatDirection::NorthWest);
+		assert_eq!(PointyDirection::East + 8, PointyDirection::SouthWest);
+		assert_eq!(-PointyDirection::West, PointyDirection::East);
+
+		for direction in FlatDirection::ALL {
+			assert_eq!(direction + 6, direction);
+			assert_eq!(Hex::ORIGIN + direction + -direction, Hex::ORIGIN);
+			assert_eq!(Hex::ORIGIN.distance(Hex::ORIGIN + direction), 1);
+		}
+		for direction in PointyDirection::ALL {
+			assert_eq!(direction - 7 + 1, direction);
+			assert_eq!((-direction).offset(), -direction.offset());
+			assert_eq!(Hex::ORIGIN.distance(Hex::ORIGIN + direction), 1);
+		}
+	}
+
+	#[test]
+	fn measures_distances() {
+		use FlatDirection::*;
+
+		// Examples of 2017 day 11.
+		let walk = |steps: &[FlatDirection]| {
+			let end = steps.iter().fold(Hex::ORIGIN, |hex, &step| hex + step);
+			end.distance(Hex::ORIGIN)
+		};
+		assert_eq!(walk(&[NorthEast, NorthEast, NorthEast]), 3);
+		assert_eq!(walk(&[NorthEast, NorthEast, SouthWest, SouthWest]), 0);
+		assert_eq!(walk(&[NorthEast, NorthEast, South, South]), 2);
+		assert_eq!(
+			walk(&[SouthEast, SouthWest, SouthEast, SouthWest, SouthWest]),
+			3
+		);
+
+		let hex = Hex::from_cube(2, -3, 1);
+		assert_eq!(hex.cube(), (2, -3, 1));
+		assert_eq!(hex.distance(-hex), 6);
+	}
+}
@@ -1,6 +1,25 @@
+#[cfg(feature = "allocations")]
+pub mod allocations;
 pub mod ascii_int;
+pub mod bits;
+pub mod box_set;
+pub mod cycle;
 pub mod direction;
+pub mod disjoint_set;
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod grid;
+pub mod hash;
+pub mod hex;
+pub mod interval_map;
 pub mod iteration;
-pub mod lcm;
+pub mod memo;
+pub mod modular;
+pub mod phase;
+pub mod point;
+pub mod polygon;
 pub mod range_set;
+pub mod search;
 pub mod trim;
+
+pub use aoc_solution as solution;
@@ -3,6 +3,15 @@ use aoc_utils::{ascii_int::parse_uint, trim::trim_end_newline};
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 const NEWLINE: u8 = b'\n';
 
 pub fn solve(input: &[u8]) -> Solution {
@@ -41,18 +50,61 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(3,6),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(1141,6634),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Turns the dial one click at a time.
+	fn naive(input: &[u8]) -> Solution {
+		let mut dial = 50;
+		let (mut stops, mut passes) = (0, 0);
+		for line in trim_end_newline(input).split(|b| *b == NEWLINE) {
+			let step = if line[0] == b'L' { 99 } else { 1 };
+			for _ in 0..parse_uint(&line[1..]) {
+				dial = (dial + step) % 100;
+				if dial == 0 {
+					passes += 1;
+				}
+			}
+			if dial == 0 {
+				stops += 1;
+			}
+		}
+		Solution(stops, passes)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..200_usize) {
+			let input = generate::day_01(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
@@ -3,6 +3,15 @@ use aoc_utils::{ascii_int::parse_uint, trim::trim_end_newline};
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 const COMMA: u8 = b',';
 const DASH: u8 = b'-';
 const TEN: usize = 10;
@@ -183,18 +192,64 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(1227775554,4174379265),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(22062284697,46666175279),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Checks every single id of every range.
+	fn naive(input: &[u8]) -> Solution {
+		let (mut doubled, mut repeated) = (0, 0);
+		for range in trim_end_newline(input).split(|b| *b == COMMA) {
+			let dash = range.iter().position(|b| *b == DASH).unwrap();
+			for id in parse_uint(&range[..dash])..=parse_uint(&range[dash + 1..]) {
+				let digits = id.to_string().into_bytes();
+				let repeats = |len: usize| {
+					digits.len() % len == 0
+						&& digits.chunks(len).all(|chunk| chunk == &digits[..len])
+				};
+				if digits.len() % 2 == 0 && repeats(digits.len() / 2) {
+					doubled += id;
+				}
+				if (1..digits.len()).any(repeats) {
+					repeated += id;
+				}
+			}
+		}
+		Solution(doubled, repeated)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..10_usize) {
+			let input = generate::day_02(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
@@ -3,6 +3,15 @@ use aoc_utils::{ascii_int::parse_uint_unchecked, trim::trim_end_newline};
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 fn get_bank_joltage<const N: usize>(bank: &[u8], width: usize) -> usize {
 	debug_assert!(width >= N, "Bank must have more than N items");
 
@@ -53,18 +62,66 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(357,3121910778619),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(17109,169347417057382),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Remembers the best joltage for every amount of batteries in the rest of the bank,
+	/// going from its end.
+	fn naive(input: &[u8]) -> Solution {
+		let joltage = |bank: &[u8], n: usize| {
+			let mut best = vec![None; n + 1];
+			best[0] = Some(0);
+			for &battery in bank.iter().rev() {
+				for k in (1..=n).rev() {
+					if let Some(rest) = best[k - 1] {
+						let value = (battery - b'0') as usize * 10_usize.pow(k as u32 - 1) + rest;
+						best[k] = best[k].max(Some(value));
+					}
+				}
+			}
+			best[n].unwrap()
+		};
+
+		let banks = trim_end_newline(input).split(|b| *b == b'\n');
+		let (small, big) = banks.fold((0, 0), |(small, big), bank| {
+			(small + joltage(bank, 2), big + joltage(bank, 12))
+		});
+		Solution(small, big)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 2..50_usize) {
+			let input = generate::day_03(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
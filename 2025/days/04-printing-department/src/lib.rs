@@ -1,8 +1,17 @@
-use aoc_utils::trim::trim_end_newline;
+use aoc_utils::{grid::ByteGrid, phase::span};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 struct Roll {
 	index: usize,
 	neighbours: [Option<usize>; 8],
@@ -11,37 +20,26 @@ struct Roll {
 // copy of map, updating using removal
 
 pub fn solve(input: &[u8]) -> Solution {
-	let input = trim_end_newline(input);
-	let width = input
-		.iter()
-		.position(|b| *b == b'\n')
-		.expect("input has multiple lines");
-	let row_width = width + 1;
-	let size = input.len();
-	let is_in_range = |x: &usize| *x < size;
-	let _height = input.len().div_ceil(row_width);
+	let grid = ByteGrid::new(input);
 
+	let parsing = span("parse");
 	let mut leftovers: Vec<_> = input.to_vec();
 
 	let mut rolls: Vec<Roll> = leftovers
 		.iter()
 		.enumerate()
 		.filter(|(_, c)| **c == b'@')
-		.map(|(index, _)| Roll {
-			index,
-			neighbours: [
-				index.checked_sub(row_width + 1),
-				index.checked_sub(row_width),
-				index.checked_sub(row_width - 1),
-				index.checked_sub(1),
-				Some(index + 1).filter(is_in_range),
-				Some(index + row_width - 1).filter(is_in_range),
-				Some(index + row_width).filter(is_in_range),
-				Some(index + row_width + 1).filter(is_in_range),
-			],
+		.map(|(index, _)| {
+			let mut neighbours = [None; 8];
+			for (slot, neighbour) in neighbours.iter_mut().zip(grid.neighbours8(index)) {
+				*slot = Some(neighbour);
+			}
+			Roll { index, neighbours }
 		})
 		.collect();
+	parsing.end();
 
+	let first_step = span("first step");
 	let first_step_removed = rolls
 		.iter()
 		.filter(|roll| {
@@ -52,7 +50,9 @@ pub fn solve(input: &[u8]) -> Solution {
 				.count() < 4
 		})
 		.count();
+	first_step.end();
 
+	let _removing = span("removal");
 	let roll_count = rolls.len();
 
 	let mut current_count = roll_count;
@@ -87,6 +87,12 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		generate::{self, Rng},
+		trim::trim_end_newline,
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -94,11 +100,65 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(13,43),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(1424,8727),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Removes all accessible rolls in waves, looking at the grid in two dimensions.
+	fn naive(input: &[u8]) -> Solution {
+		let mut grid: Vec<Vec<bool>> = trim_end_newline(input)
+			.split(|b| *b == b'\n')
+			.map(|line| line.iter().map(|b| *b == b'@').collect())
+			.collect();
+
+		let accessible = |grid: &Vec<Vec<bool>>| {
+			let mut found = vec![];
+			for y in 0..grid.len() {
+				for x in 0..grid[y].len() {
+					let neighbours = (y.saturating_sub(1)..=(y + 1).min(grid.len() - 1))
+						.flat_map(|ny| {
+							(x.saturating_sub(1)..=(x + 1).min(grid[y].len() - 1))
+								.map(move |nx| (ny, nx))
+						})
+						.filter(|&(ny, nx)| (ny, nx) != (y, x) && grid[ny][nx])
+						.count();
+					if grid[y][x] && neighbours < 4 {
+						found.push((y, x));
+					}
+				}
+			}
+			found
+		};
+
+		let first_step = accessible(&grid).len();
+		let mut removed = 0;
+		loop {
+			let found = accessible(&grid);
+			if found.is_empty() {
+				break;
+			}
+			removed += found.len();
+			for (y, x) in found {
+				grid[y][x] = false;
+			}
+		}
+		Solution(first_step, removed)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 2..40_usize) {
+			let input = generate::day_04(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,9 +1,18 @@
-use aoc_utils::{range_set::IntRangeSet, trim::trim_end_newline};
+use aoc_utils::{phase::span, range_set::IntRangeSet, trim::trim_end_newline};
 use atoi_simd as atoi;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 pub fn solve(input: &[u8]) -> Solution {
 	let input = trim_end_newline(input);
 	let last_dash_pos = input
@@ -19,6 +28,7 @@ pub fn solve(input: &[u8]) -> Solution {
 	let range_input = &input[..break_pos];
 	let item_input = &input[(break_pos + 2)..];
 
+	let ranges = span("ranges");
 	let range_set: IntRangeSet<usize> = range_input
 		.split(|&c| c == b'\n')
 		.map(|range_str| {
@@ -34,11 +44,10 @@ pub fn solve(input: &[u8]) -> Solution {
 				atoi::parse_pos::<usize>(right).expect("valid int"),
 			)
 		})
-		.fold(IntRangeSet::new(), |mut set, range| {
-			set.add_range(range);
-			set
-		});
+		.collect();
+	ranges.end();
 
+	let _items = span("items");
 	let included = item_input
 		.split(|&c| c == b'\n')
 		.map(|item| atoi::parse_pos::<usize>(item).expect("valid uint"))
@@ -51,6 +60,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -58,11 +70,58 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(3,14),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(558,344813017450467),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Checks ids against every range, and counts fresh ids by merging sorted ranges.
+	fn naive(input: &[u8]) -> Solution {
+		let text = std::str::from_utf8(input).unwrap();
+		let (ranges, ids) = text.split_once("\n\n").unwrap();
+		let mut ranges: Vec<(usize, usize)> = ranges
+			.lines()
+			.map(|line| {
+				let (start, end) = line.split_once('-').unwrap();
+				(start.parse().unwrap(), end.parse().unwrap())
+			})
+			.collect();
+
+		let fresh = ids
+			.lines()
+			.map(|id| id.parse::<usize>().unwrap())
+			.filter(|id| {
+				ranges
+					.iter()
+					.any(|(start, end)| (start..=end).contains(&id))
+			})
+			.count();
+
+		ranges.sort();
+		let (mut all_fresh, mut counted_until) = (0, 0);
+		for (start, end) in ranges {
+			let start = start.max(counted_until);
+			if end >= start {
+				all_fresh += end - start + 1;
+				counted_until = end + 1;
+			}
+		}
+		Solution(fresh, all_fresh)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..50_usize) {
+			let input = generate::day_05(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
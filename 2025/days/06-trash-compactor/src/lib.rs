@@ -3,6 +3,15 @@ use aoc_utils::{ascii_int::parse_uint_coerce, trim::trim_end_newline};
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 pub fn solve(input: &[u8]) -> Solution {
 	let input = trim_end_newline(input);
 	let last_line_start = input
@@ -15,6 +24,8 @@ pub fn solve(input: &[u8]) -> Solution {
 	let line_len = op_line.len() + 1; // added newline
 	let line_amt = input[..last_line_start].len() / line_len;
 
+	let is_op = |c: &u8| *c == b'*' || *c == b'+';
+
 	// for binary split comfort we split after the next operator, and start by reading the first.
 	let mut op: u8 = op_line[0];
 	let mut slice_cursor: usize = 0;
@@ -22,8 +33,12 @@ pub fn solve(input: &[u8]) -> Solution {
 	let mut wrong_sum: usize = 0;
 	let mut actual_sum: usize = 0;
 
-	for op_str in op_line[1..].split_inclusive(|&c| c == b'*' || c == b'+') {
-		let is_last = slice_cursor + op_str.len() + 1 == op_line.len();
+	// a one column problem at the end leaves nothing after its operator, an empty chunk
+	// stands in for it.
+	let trailing = is_op(&op_line[op_line.len() - 1]).then_some(&op_line[op_line.len()..]);
+
+	for op_str in op_line[1..].split_inclusive(is_op).chain(trailing) {
+		let is_last = !op_str.last().is_some_and(is_op);
 		let len = op_str.len();
 		let width = if is_last { len + 1 } else { len - 1 };
 
@@ -56,7 +71,9 @@ pub fn solve(input: &[u8]) -> Solution {
 			actual_sum += actual_value_iter.product::<usize>();
 		}
 
-		op = op_str[len - 1];
+		if !is_last {
+			op = op_str[len - 1];
+		}
 		slice_cursor += len;
 	}
 
@@ -66,18 +83,87 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::trim::{normalize, variants};
+	use aoc_utils::{
+		allocations,
+		generate::{self, Rng},
+	};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
+	#[global_allocator]
+	static ALLOCATOR: allocations::Counting = allocations::Counting;
+
 	#[rstest]
 	#[case::example(
 		include_bytes!("../inputs/example.txt"),
 		Solution(4277556, 3263827),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(4722948564882,9581313737063),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
-		assert_eq!(solve(input), expected);
+		let (solution, allocations) = allocations::count(|| solve(input));
+		assert_eq!(solution, expected);
+		assert_eq!(allocations.count, 0, "day is marked as allocation free");
+	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Splits the worksheet at columns of spaces and reads each problem both ways.
+	fn naive(input: &[u8]) -> Solution {
+		let lines: Vec<&[u8]> = trim_end_newline(input).split(|b| *b == b'\n').collect();
+		let (operators, rows) = lines.split_last().unwrap();
+
+		let mut problems = vec![];
+		let mut start = 0;
+		for column in 0..=operators.len() {
+			if column == operators.len() || lines.iter().all(|line| line[column] == b' ') {
+				problems.push(start..column);
+				start = column + 1;
+			}
+		}
+
+		// spaces can end up between the digits of a column, they don't count
+		let number = |digits: Vec<u8>| {
+			String::from_utf8(digits.into_iter().filter(|&c| c != b' ').collect())
+				.unwrap()
+				.parse::<usize>()
+				.unwrap()
+		};
+		let (mut wrong, mut actual) = (0, 0);
+		for columns in problems {
+			let combine = |numbers: Vec<usize>| -> usize {
+				if operators[columns.start] == b'*' {
+					numbers.iter().product()
+				} else {
+					numbers.iter().sum()
+				}
+			};
+			wrong += combine(
+				rows.iter()
+					.map(|row| number(row[columns.clone()].to_vec()))
+					.collect(),
+			);
+			actual += combine(
+				columns
+					.clone()
+					.map(|column| number(rows.iter().map(|row| row[column]).collect()))
+					.collect(),
+			);
+		}
+		Solution(wrong, actual)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..50_usize) {
+			let input = generate::day_06(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
 	}
 }
@@ -3,6 +3,15 @@ use aoc_utils::trim::trim_end_newline;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 pub fn solve(input: &[u8]) -> Solution {
 	let input = trim_end_newline(input);
 	let line_width = input
@@ -56,6 +65,9 @@ pub fn solve(input: &[u8]) -> Solution {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -63,11 +75,46 @@ mod tests {
 		include_bytes!("../inputs/example.txt"),
 		Solution(21,40),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		Solution(1535,4404709551015),
-	)]
 	fn solution(#[case] input: &[u8], #[case] expected: Solution) {
 		assert_eq!(solve(input), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"))]
+	fn input_variants(#[case] input: &[u8]) {
+		let expected = solve(input);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input)), expected, "{variant}");
+		}
+	}
+
+	/// Follows the beams through every line of the manifold.
+	fn naive(input: &[u8]) -> Solution {
+		let lines: Vec<&[u8]> = trim_end_newline(input).split(|&c| c == b'\n').collect();
+		let mut beams: Vec<usize> = lines[0].iter().map(|&c| (c == b'S') as usize).collect();
+		let mut splits = 0;
+
+		for line in &lines[1..] {
+			let mut next = vec![0; beams.len()];
+			for (column, &count) in beams.iter().enumerate().filter(|(_, count)| **count > 0) {
+				if line[column] == b'^' {
+					splits += 1;
+					next[column - 1] += count;
+					next[column + 1] += count;
+				} else {
+					next[column] += count;
+				}
+			}
+			beams = next;
+		}
+		Solution(splits, beams.iter().sum())
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(seed: u64, size in 1..40_usize) {
+			let input = generate::day_07(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input), naive(&input));
+		}
+	}
 }
@@ -1,4 +1,4 @@
-use aoc_utils::trim::trim_end_newline;
+use aoc_utils::{disjoint_set::DisjointSet, phase::span, trim::trim_end_newline};
 use atoi_simd as atoi;
 use itertools::Itertools;
 use std::collections::BinaryHeap;
@@ -6,6 +6,15 @@ use std::collections::BinaryHeap;
 #[derive(Debug, PartialEq, Eq)]
 pub struct Solution(usize, usize);
 
+impl aoc_utils::solution::Solution for Solution {
+	type Part1 = usize;
+	type Part2 = usize;
+
+	fn into_parts(self) -> (usize, usize) {
+		(self.0, self.1)
+	}
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct Node(usize, usize, usize);
 
@@ -29,6 +38,7 @@ impl PartialOrd for Edge {
 }
 
 pub fn solve(input: &[u8], first_n: usize) -> Solution {
+	let parsing = span("parse");
 	let input = trim_end_newline(input);
 
 	let nodes: Vec<_> = input
@@ -37,11 +47,13 @@ pub fn solve(input: &[u8], first_n: usize) -> Solution {
 		.tuples()
 		.map(|(x, y, z)| Node(x, y, z))
 		.collect();
+	parsing.end();
 
 	// 10^6 / 2 edges :(
 	// this is rather brute force, would need to look into algorithms for
 	// caculating the _euclidean minimum spanning tree_ faster
 	// Also I have not tested if the MinHeap is actually faster than a full sort
+	let measuring = span("edges");
 	let mut edges: BinaryHeap<Edge> = nodes
 		.iter()
 		.enumerate()
@@ -56,12 +68,11 @@ pub fn solve(input: &[u8], first_n: usize) -> Solution {
 			}
 		})
 		.collect();
+	measuring.end();
 
-	let mut cycles: Vec<usize> = vec![];
-	let mut cycle_membership: Vec<Option<usize>> = vec![None; nodes.len()];
-	let mut big_3_cycles_after_n: usize = 0;
-
-	let full_cycle_len = nodes.len();
+	let connecting = span("connect");
+	let mut circuits = DisjointSet::new(nodes.len());
+	let mut big_3_circuits_after_n: usize = 0;
 
 	let mut loop_count = 0;
 	let (_iterations, x_coords) = loop {
@@ -71,63 +82,19 @@ pub fn solve(input: &[u8], first_n: usize) -> Solution {
 		} = edges
 			.pop()
 			.expect("must have at least enough edges to build spanning tree");
-		match (cycle_membership[a], cycle_membership[b]) {
-			(None, None) => {
-				let new_cycle = cycles.len();
-				cycles.push(2); // new length of cycle
-				cycle_membership[a] = Some(new_cycle);
-				cycle_membership[b] = Some(new_cycle);
-				// println!("Starting new cycle {new_cycle} for boxes [{a}, {b}]",);
-			}
-			(Some(a_mem), None) => {
-				cycles[a_mem] += 1;
-				if cycles[a_mem] == full_cycle_len {
-					break (loop_count, nodes[a].0 * nodes[b].0);
-				}
-				cycle_membership[b] = Some(a_mem);
-				// println!("Adding box {b} to cycle {a_mem}");
-			}
-			(None, Some(b_mem)) => {
-				cycles[b_mem] += 1;
-				if cycles[b_mem] == full_cycle_len {
-					break (loop_count, nodes[a].0 * nodes[b].0);
-				}
-				cycle_membership[a] = Some(b_mem);
-				// println!("Adding box {a} to cycle {b_mem}");
-			}
-			(Some(a_mem), Some(b_mem)) => {
-				if a_mem == b_mem {
-					// Already in same cycle
-					// println!("same cycle");
-					continue;
-				}
-				let (smaller, bigger) = if cycles[a_mem] < cycles[b_mem] {
-					(a_mem, b_mem)
-				} else {
-					(b_mem, a_mem)
-				};
-				cycles[bigger] += cycles[smaller];
-				if cycles[bigger] == full_cycle_len {
-					break (loop_count, nodes[a].0 * nodes[b].0);
-				}
-				cycles[smaller] = 0; // tombstone value, we don't want index shifts
-				for membership in cycle_membership
-					.iter_mut()
-					.filter(|c| c.is_some_and(|mem| mem == smaller))
-				{
-					membership.replace(bigger);
-				}
-				// println!("Merging cycle {bigger} of box {a} with cycle {smaller} of box {b}");
-			}
+		// connections within a circuit still count towards first_n
+		if circuits.union(a, b) && circuits.count() == 1 {
+			break (loop_count, nodes[a].0 * nodes[b].0);
 		}
 		if loop_count == first_n {
-			// we do not want to disturb the original cycles vec because we're not at our goal yet
-			let mut cycle_clone = cycles.clone();
-			cycle_clone.sort_unstable();
-			// also correct result if less than 3 big cycles, as all "default" cycles are size 1
-			big_3_cycles_after_n = cycle_clone.iter().rev().take(3).product();
+			big_3_circuits_after_n = circuits
+				.largest(3)
+				.into_iter()
+				.map(|(_, size)| size)
+				.product();
 		}
 	};
+	connecting.end();
 
 	#[cfg(test)]
 	println!(
@@ -135,12 +102,15 @@ pub fn solve(input: &[u8], first_n: usize) -> Solution {
 		edges.len()
 	);
 
-	Solution(big_3_cycles_after_n, x_coords)
+	Solution(big_3_circuits_after_n, x_coords)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc_utils::generate::{self, Rng};
+	use aoc_utils::trim::{normalize, variants};
+	use proptest::prelude::*;
 	use rstest::rstest;
 
 	#[rstest]
@@ -149,12 +119,65 @@ mod tests {
 		10,
 		Solution(40,25272),
 	)]
-	#[case::personal(
-		include_bytes!("../inputs/personal.txt"),
-		1000,
-		Solution(98696,2245203960),
-	)]
 	fn solution(#[case] input: &[u8], #[case] first_n: usize, #[case] expected: Solution) {
 		assert_eq!(solve(input, first_n), expected);
 	}
+
+	#[rstest]
+	#[case::example(include_bytes!("../inputs/example.txt"), 10)]
+	fn input_variants(#[case] input: &[u8], #[case] first_n: usize) {
+		let expected = solve(input, first_n);
+		for (variant, input) in variants(input) {
+			assert_eq!(solve(&normalize(&input), first_n), expected, "{variant}");
+		}
+	}
+
+	/// Connects the closest pairs from a fully sorted list, relabeling whole circuits.
+	fn naive(input: &[u8], first_n: usize) -> Solution {
+		let boxes: Vec<Vec<usize>> = std::str::from_utf8(input)
+			.unwrap()
+			.lines()
+			.map(|line| line.split(',').map(|n| n.parse().unwrap()).collect())
+			.collect();
+
+		let mut pairs: Vec<(usize, usize, usize)> = (0..boxes.len())
+			.tuple_combinations()
+			.map(|(a, b)| {
+				let distance = (0..3)
+					.map(|i| boxes[a][i].abs_diff(boxes[b][i]).pow(2))
+					.sum();
+				(distance, a, b)
+			})
+			.collect();
+		pairs.sort();
+
+		let mut circuits: Vec<usize> = (0..boxes.len()).collect();
+		let (mut largest, mut last) = (0, 0);
+		for (connections, &(_, a, b)) in pairs.iter().enumerate() {
+			let (from, to) = (circuits[a], circuits[b]);
+			if from != to {
+				circuits
+					.iter_mut()
+					.filter(|c| **c == from)
+					.for_each(|c| *c = to);
+				last = boxes[a][0] * boxes[b][0];
+			}
+			if connections + 1 == first_n {
+				let sizes = circuits.iter().counts();
+				largest = sizes.values().sorted().rev().take(3).product();
+			}
+		}
+		Solution(largest, last)
+	}
+
+	proptest! {
+		#[test]
+		fn matches_naive(
+			seed: u64,
+			(size, first_n) in (4..60_usize).prop_flat_map(|size| (Just(size), 1..=size / 2)),
+		) {
+			let input = generate::day_08(&mut Rng::new(seed), size);
+			prop_assert_eq!(solve(&input, first_n), naive(&input, first_n));
+		}
+	}
 }
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: (u8, Input)| {
+	let (first_n, input) = data;
+	aoc_fuzz::check(|| day_08_playground::solve(&input.bytes(b"0123456789,\n"), first_n as usize));
+});
@@ -0,0 +1,76 @@
+use aoc_cli::{Day, Harness};
+use aoc_utils::{allocations, trim};
+use std::process::ExitCode;
+
+const DAYS: &[Day] = &[
+	Day::new(
+		1,
+		"Secret Entrance",
+		"days/01-secret-entrance/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_01_secret_entrance::solve(input)),
+	),
+	Day::new(
+		2,
+		"Gift Shop",
+		"days/02-gift-shop/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_02_gift_shop::solve(input)),
+	),
+	Day::new(
+		3,
+		"Lobby",
+		"days/03-lobby/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_03_lobby::solve(input)),
+	),
+	Day::new(
+		4,
+		"Printing Department",
+		"days/04-printing-department/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_04_printing_department::solve(input)),
+	),
+	Day::new(
+		5,
+		"Cafeteria",
+		"days/05-cafeteria/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_05_cafeteria::solve(input)),
+	),
+	Day::new(
+		6,
+		"Trash Compactor",
+		"days/06-trash-compactor/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_06_trash_compactor::solve(input)),
+	),
+	Day::new(
+		7,
+		"Laboratories",
+		"days/07-laboratories/inputs/personal.txt",
+		|input, _| aoc_cli::answers(day_07_laboratories::solve(input)),
+	),
+	Day::new(
+		8,
+		"Playground",
+		"days/08-playground/inputs/personal.txt",
+		|input, params| aoc_cli::answers(day_08_playground::solve(input, params.get("first_n")?)),
+	)
+	.with_params(&[("first_n", "1000")]),
+];
+
+#[global_allocator]
+static ALLOCATOR: allocations::Counting = allocations::Counting;
+
+fn count_allocations(solve: &mut dyn FnMut()) -> String {
+	let ((), allocations) = allocations::count(solve);
+	allocations.to_string()
+}
+
+fn main() -> ExitCode {
+	let harness = Harness {
+		count_allocations: Some(count_allocations),
+		prepare_input: Some(trim::normalize),
+	};
+	aoc_cli::main_with(
+		2025,
+		concat!(env!("CARGO_MANIFEST_DIR"), "/.."),
+		DAYS,
+		harness,
+	)
+}
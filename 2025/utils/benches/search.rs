@@ -0,0 +1,154 @@
+//! The searches of `aoc_utils::search` against the ones of the pathfinding crate, on the
+//! same weighted grids.
+
+use aoc_utils::search::{self, Buckets};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+/// Sides of the square grids searched, from corner to corner.
+const SIDES: [usize; 3] = [50, 200, 500];
+
+type Node = (usize, usize);
+
+/// A square grid where entering a cell costs 1 to 9, like the map of 2023 day 17.
+struct Grid {
+	side: usize,
+	costs: Vec<usize>,
+}
+
+impl Grid {
+	fn new(side: usize) -> Self {
+		// xorshift, so every run searches the same grid
+		let mut state = 0x2545_f491_4f6c_dd1d_u64;
+		let costs = (0..side * side)
+			.map(|_| {
+				state ^= state << 13;
+				state ^= state >> 7;
+				state ^= state << 17;
+				(state % 9) as usize + 1
+			})
+			.collect();
+		Grid { side, costs }
+	}
+
+	fn neighbours(&self, (x, y): Node) -> impl Iterator<Item = (Node, usize)> + '_ {
+		[
+			(x.wrapping_sub(1), y),
+			(x + 1, y),
+			(x, y.wrapping_sub(1)),
+			(x, y + 1),
+		]
+		.into_iter()
+		.filter(|&(x, y)| x < self.side && y < self.side)
+		.map(|(x, y)| ((x, y), self.costs[y * self.side + x]))
+	}
+
+	fn id(&self, &(x, y): &Node) -> usize {
+		y * self.side + x
+	}
+
+	/// Manhattan distance to the goal, every step costing at least 1.
+	fn distance(&self, &(x, y): &Node) -> usize {
+		2 * (self.side - 1) - x - y
+	}
+
+	fn is_goal(&self, &node: &Node) -> bool {
+		node == (self.side - 1, self.side - 1)
+	}
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+	let grids = SIDES.map(Grid::new);
+
+	for grid in &grids {
+		let cost = search::dijkstra(
+			(0, 0),
+			|node| grid.neighbours(*node),
+			|node| grid.is_goal(node),
+		)
+		.map(|(_, cost)| cost);
+		let expected = pathfinding::prelude::dijkstra(
+			&(0, 0),
+			|node| grid.neighbours(*node),
+			|node| grid.is_goal(node),
+		)
+		.map(|(_, cost)| cost);
+		assert_eq!(cost, expected, "both find the cheapest path");
+	}
+
+	let mut group = c.benchmark_group("dijkstra");
+	for grid in &grids {
+		group.bench_with_input(BenchmarkId::new("search", grid.side), grid, |b, grid| {
+			b.iter(|| {
+				search::dijkstra(
+					black_box((0, 0)),
+					|node| grid.neighbours(*node),
+					|node| grid.is_goal(node),
+				)
+			})
+		});
+		group.bench_with_input(
+			BenchmarkId::new("pathfinding", grid.side),
+			grid,
+			|b, grid| {
+				b.iter(|| {
+					pathfinding::prelude::dijkstra(
+						black_box(&(0, 0)),
+						|node| grid.neighbours(*node),
+						|node| grid.is_goal(node),
+					)
+				})
+			},
+		);
+	}
+	group.finish();
+
+	let mut group = c.benchmark_group("astar");
+	for grid in &grids {
+		group.bench_with_input(BenchmarkId::new("search", grid.side), grid, |b, grid| {
+			b.iter(|| {
+				search::astar(
+					black_box((0, 0)),
+					|node| grid.neighbours(*node),
+					|node| grid.distance(node),
+					|node| grid.is_goal(node),
+				)
+			})
+		});
+		// what the days use for grids: numbered nodes and a bucket queue
+		group.bench_with_input(
+			BenchmarkId::new("search dense", grid.side),
+			grid,
+			|b, grid| {
+				b.iter(|| {
+					search::astar_with(
+						|node: &Node| grid.id(node),
+						Buckets::new(),
+						black_box((0, 0)),
+						|node| grid.neighbours(*node),
+						|node| grid.distance(node),
+						|node| grid.is_goal(node),
+					)
+				})
+			},
+		);
+		group.bench_with_input(
+			BenchmarkId::new("pathfinding", grid.side),
+			grid,
+			|b, grid| {
+				b.iter(|| {
+					pathfinding::prelude::astar(
+						black_box(&(0, 0)),
+						|node| grid.neighbours(*node),
+						|node| grid.distance(node),
+						|node| grid.is_goal(node),
+					)
+				})
+			},
+		);
+	}
+	group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,731 @@
+//! Sets of small numbers and grids of flags, one bit each, so they are counted, combined
+//! and shifted a whole word at a time.
+
+use crate::{
+	direction::{Direction, East, North, South, West},
+	grid::ByteGrid,
+};
+use std::{
+	fmt,
+	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+};
+
+const BITS: usize = u64::BITS as usize;
+
+/// The word an element is in, and its bit there.
+#[inline]
+const fn locate(element: usize) -> (usize, u64) {
+	(element / BITS, 1 << (element % BITS))
+}
+
+/// The elements of a set of bits, ascending.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+	words: &'a [u64],
+	index: usize,
+	/// The bits of the current word not yet returned.
+	word: u64,
+}
+
+impl<'a> Iter<'a> {
+	fn new(words: &'a [u64]) -> Self {
+		Iter {
+			words,
+			index: 0,
+			word: words.first().copied().unwrap_or(0),
+		}
+	}
+}
+
+impl Iterator for Iter<'_> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<usize> {
+		while self.word == 0 {
+			self.index += 1;
+			self.word = *self.words.get(self.index)?;
+		}
+		let bit = self.word.trailing_zeros() as usize;
+		self.word &= self.word - 1;
+		Some(self.index * BITS + bit)
+	}
+}
+
+/// The methods both sets share, as they only read the words.
+macro_rules! read_methods {
+	() => {
+		/// The elements as bits, element `i` being bit `i % 64` of word `i / 64`.
+		pub fn words(&self) -> &[u64] {
+			&self.words
+		}
+
+		#[inline]
+		pub fn contains(&self, element: usize) -> bool {
+			let (index, bit) = locate(element);
+			self.words.get(index).is_some_and(|word| word & bit != 0)
+		}
+
+		/// The number of elements.
+		pub fn len(&self) -> usize {
+			self.words
+				.iter()
+				.map(|word| word.count_ones() as usize)
+				.sum()
+		}
+
+		pub fn is_empty(&self) -> bool {
+			self.words.iter().all(|&word| word == 0)
+		}
+
+		pub fn iter(&self) -> Iter<'_> {
+			Iter::new(&self.words)
+		}
+
+		pub fn first(&self) -> Option<usize> {
+			self.iter().next()
+		}
+
+		pub fn is_disjoint(&self, other: &Self) -> bool {
+			self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+		}
+
+		pub fn is_subset(&self, other: &Self) -> bool {
+			let other_words = other.words.iter().chain(std::iter::repeat(&0));
+			self.words.iter().zip(other_words).all(|(a, b)| a & !b == 0)
+		}
+
+		pub fn is_superset(&self, other: &Self) -> bool {
+			other.is_subset(self)
+		}
+
+		pub fn union(&self, other: &Self) -> Self {
+			let mut union = self.clone();
+			union.union_with(other);
+			union
+		}
+
+		pub fn intersection(&self, other: &Self) -> Self {
+			let mut intersection = self.clone();
+			intersection.intersect_with(other);
+			intersection
+		}
+
+		pub fn difference(&self, other: &Self) -> Self {
+			let mut difference = self.clone();
+			difference.difference_with(other);
+			difference
+		}
+
+		pub fn symmetric_difference(&self, other: &Self) -> Self {
+			let mut symmetric_difference = self.clone();
+			symmetric_difference.symmetric_difference_with(other);
+			symmetric_difference
+		}
+	};
+}
+
+/// A set of the numbers below `64 * WORDS`, which is [`Copy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBitSet<const WORDS: usize> {
+	words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> FixedBitSet<WORDS> {
+	/// All elements are below this.
+	pub const CAPACITY: usize = WORDS * BITS;
+
+	pub const fn new() -> Self {
+		FixedBitSet { words: [0; WORDS] }
+	}
+
+	read_methods!();
+
+	/// Adds `element`, returning whether it was new. Panics beyond the capacity.
+	#[inline]
+	pub fn insert(&mut self, element: usize) -> bool {
+		let (index, bit) = locate(element);
+		let new = self.words[index] & bit == 0;
+		self.words[index] |= bit;
+		new
+	}
+
+	/// Removes `element`, returning whether it was there.
+	#[inline]
+	pub fn remove(&mut self, element: usize) -> bool {
+		let (index, bit) = locate(element);
+		match self.words.get_mut(index) {
+			Some(word) if *word & bit != 0 => {
+				*word &= !bit;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	pub fn clear(&mut self) {
+		self.words = [0; WORDS];
+	}
+
+	pub fn union_with(&mut self, other: &Self) {
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a |= b);
+	}
+
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a &= b);
+	}
+
+	pub fn difference_with(&mut self, other: &Self) {
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a &= !b);
+	}
+
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a ^= b);
+	}
+}
+
+impl<const WORDS: usize> Default for FixedBitSet<WORDS> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const WORDS: usize> FromIterator<usize> for FixedBitSet<WORDS> {
+	fn from_iter<I: IntoIterator<Item = usize>>(elements: I) -> Self {
+		let mut set = FixedBitSet::new();
+		for element in elements {
+			set.insert(element);
+		}
+		set
+	}
+}
+
+/// A set of small numbers, growing to fit the largest.
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+	/// May end in empty words.
+	words: Vec<u64>,
+}
+
+impl BitSet {
+	pub const fn new() -> Self {
+		BitSet { words: vec![] }
+	}
+
+	/// A set with room for the numbers below `len` before it grows.
+	pub fn with_capacity(len: usize) -> Self {
+		BitSet {
+			words: vec![0; len.div_ceil(BITS)],
+		}
+	}
+
+	read_methods!();
+
+	/// Adds `element`, returning whether it was new.
+	#[inline]
+	pub fn insert(&mut self, element: usize) -> bool {
+		let (index, bit) = locate(element);
+		if index >= self.words.len() {
+			self.words.resize(index + 1, 0);
+		}
+		let new = self.words[index] & bit == 0;
+		self.words[index] |= bit;
+		new
+	}
+
+	/// Removes `element`, returning whether it was there.
+	#[inline]
+	pub fn remove(&mut self, element: usize) -> bool {
+		let (index, bit) = locate(element);
+		match self.words.get_mut(index) {
+			Some(word) if *word & bit != 0 => {
+				*word &= !bit;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// Removes all elements, keeping the memory.
+	pub fn clear(&mut self) {
+		self.words.fill(0);
+	}
+
+	pub fn union_with(&mut self, other: &Self) {
+		if other.words.len() > self.words.len() {
+			self.words.resize(other.words.len(), 0);
+		}
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a |= b);
+	}
+
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.words.truncate(other.words.len());
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a &= b);
+	}
+
+	pub fn difference_with(&mut self, other: &Self) {
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a &= !b);
+	}
+
+	pub fn symmetric_difference_with(&mut self, other: &Self) {
+		if other.words.len() > self.words.len() {
+			self.words.resize(other.words.len(), 0);
+		}
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, b)| *a ^= b);
+	}
+}
+
+/// Sets are equal with the same elements, however many empty words they end in.
+impl PartialEq for BitSet {
+	fn eq(&self, other: &Self) -> bool {
+		self.is_subset(other) && other.is_subset(self)
+	}
+}
+
+impl Eq for BitSet {}
+
+impl FromIterator<usize> for BitSet {
+	fn from_iter<I: IntoIterator<Item = usize>>(elements: I) -> Self {
+		let mut set = BitSet::new();
+		for element in elements {
+			set.insert(element);
+		}
+		set
+	}
+}
+
+macro_rules! set_operator {
+	($operator:ident, $method:ident, $operation:ident, $assign:ident, $assign_method:ident, $in_place:ident) => {
+		impl<const WORDS: usize> $operator for FixedBitSet<WORDS> {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self {
+				self.$operation(&other)
+			}
+		}
+
+		impl<const WORDS: usize> $assign for FixedBitSet<WORDS> {
+			fn $assign_method(&mut self, other: Self) {
+				self.$in_place(&other);
+			}
+		}
+
+		impl $operator for &BitSet {
+			type Output = BitSet;
+
+			fn $method(self, other: Self) -> BitSet {
+				self.$operation(other)
+			}
+		}
+
+		impl $assign<&BitSet> for BitSet {
+			fn $assign_method(&mut self, other: &BitSet) {
+				self.$in_place(other);
+			}
+		}
+	};
+}
+
+set_operator!(BitOr, bitor, union, BitOrAssign, bitor_assign, union_with);
+set_operator!(
+	BitAnd,
+	bitand,
+	intersection,
+	BitAndAssign,
+	bitand_assign,
+	intersect_with
+);
+set_operator!(Sub, sub, difference, SubAssign, sub_assign, difference_with);
+set_operator!(
+	BitXor,
+	bitxor,
+	symmetric_difference,
+	BitXorAssign,
+	bitxor_assign,
+	symmetric_difference_with
+);
+
+/// `FLAGS` flags for every cell of a grid, like the directions a beam passed a cell in.
+///
+/// Every flag has a plane of its own, stored row by row with every row starting a new
+/// word, so a flag of a whole row moves in a few shifts. The methods without a flag
+/// argument are for the usual grid of one flag per cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid<const FLAGS: usize = 1> {
+	width: usize,
+	height: usize,
+	row_words: usize,
+	words: Vec<u64>,
+}
+
+impl<const FLAGS: usize> BitGrid<FLAGS> {
+	/// A grid of `width` by `height` cells, no flag of them set.
+	pub fn with_flags(width: usize, height: usize) -> Self {
+		let row_words = width.div_ceil(BITS);
+		BitGrid {
+			width,
+			height,
+			row_words,
+			words: vec![0; row_words * height * FLAGS],
+		}
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	fn plane_words(&self) -> usize {
+		self.row_words * self.height
+	}
+
+	/// Flag `flag` of row `y`, cell `x` being bit `x % 64` of word `x / 64`.
+	pub fn flag_row(&self, flag: usize, y: usize) -> &[u64] {
+		let start = flag * self.plane_words() + y * self.row_words;
+		&self.words[start..start + self.row_words]
+	}
+
+	#[inline]
+	fn locate(&self, x: usize, y: usize, flag: usize) -> (usize, u64) {
+		assert!(
+			x < self.width && y < self.height && flag < FLAGS,
+			"flag {flag} of ({x}, {y}) is on the grid"
+		);
+		let (index, bit) = locate(x);
+		(flag * self.plane_words() + y * self.row_words + index, bit)
+	}
+
+	/// Whether flag `flag` of the cell at `(x, y)` is set, which none off the grid are.
+	#[inline]
+	pub fn contains_flag(&self, x: usize, y: usize, flag: usize) -> bool {
+		x < self.width && y < self.height && {
+			let (index, bit) = self.locate(x, y, flag);
+			self.words[index] & bit != 0
+		}
+	}
+
+	/// Sets flag `flag` of the cell at `(x, y)`, returning whether it was not set before.
+	#[inline]
+	pub fn insert_flag(&mut self, x: usize, y: usize, flag: usize) -> bool {
+		let (index, bit) = self.locate(x, y, flag);
+		let new = self.words[index] & bit == 0;
+		self.words[index] |= bit;
+		new
+	}
+
+	/// Unsets flag `flag` of the cell at `(x, y)`, returning whether it was set.
+	#[inline]
+	pub fn remove_flag(&mut self, x: usize, y: usize, flag: usize) -> bool {
+		let (index, bit) = self.locate(x, y, flag);
+		let was_set = self.words[index] & bit != 0;
+		self.words[index] &= !bit;
+		was_set
+	}
+
+	/// The flags of the cell at `(x, y)`, flag `i` being bit `i`.
+	pub fn flags(&self, x: usize, y: usize) -> u64 {
+		(0..FLAGS)
+			.filter(|&flag| self.contains_flag(x, y, flag))
+			.fold(0, |flags, flag| flags | 1 << flag)
+	}
+
+	/// The cells with any flag set.
+	pub fn any(&self) -> BitGrid {
+		let mut any = BitGrid::new(self.width, self.height);
+		if self.plane_words() > 0 {
+			for plane in self.words.chunks_exact(self.plane_words()) {
+				any.words.iter_mut().zip(plane).for_each(|(a, &b)| *a |= b);
+			}
+		}
+		any
+	}
+
+	/// The number of set flags, of all cells.
+	pub fn len(&self) -> usize {
+		self.words
+			.iter()
+			.map(|word| word.count_ones() as usize)
+			.sum()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.words.iter().all(|&word| word == 0)
+	}
+
+	pub fn clear(&mut self) {
+		self.words.fill(0);
+	}
+
+	fn combine(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+		assert_eq!(
+			(self.width, self.height),
+			(other.width, other.height),
+			"grids have the same size"
+		);
+		self.words
+			.iter_mut()
+			.zip(&other.words)
+			.for_each(|(a, &b)| *a = f(*a, b));
+	}
+
+	pub fn union_with(&mut self, other: &Self) {
+		self.combine(other, |a, b| a | b);
+	}
+
+	pub fn intersect_with(&mut self, other: &Self) {
+		self.combine(other, |a, b| a & b);
+	}
+
+	pub fn difference_with(&mut self, other: &Self) {
+		self.combine(other, |a, b| a & !b);
+	}
+
+	/// Moves every set flag a step into `direction`, dropping those that leave the grid.
+	pub fn shift(&mut self, direction: Direction) {
+		let row_words = self.row_words;
+		if row_words == 0 || self.height == 0 {
+			return;
+		}
+		let plane_words = self.plane_words();
+
+		match direction {
+			North => {
+				for plane in self.words.chunks_exact_mut(plane_words) {
+					plane.copy_within(row_words.., 0);
+					plane[plane_words - row_words..].fill(0);
+				}
+			}
+			South => {
+				for plane in self.words.chunks_exact_mut(plane_words) {
+					plane.copy_within(..plane_words - row_words, row_words);
+					plane[..row_words].fill(0);
+				}
+			}
+			East => {
+				let last_mask = match self.width % BITS {
+					0 => u64::MAX,
+					used => (1 << used) - 1,
+				};
+				for row in self.words.chunks_exact_mut(row_words) {
+					let mut carry = 0;
+					for word in row.iter_mut() {
+						(*word, carry) = ((*word << 1) | carry, *word >> (BITS - 1));
+					}
+					row[row_words - 1] &= last_mask;
+				}
+			}
+			West => {
+				for row in self.words.chunks_exact_mut(row_words) {
+					let mut carry = 0;
+					for word in row.iter_mut().rev() {
+						(*word, carry) = ((*word >> 1) | (carry << (BITS - 1)), *word & 1);
+					}
+				}
+			}
+		}
+	}
+
+	/// A copy with every set flag a step further into `direction`.
+	pub fn shifted(&self, direction: Direction) -> Self {
+		let mut shifted = self.clone();
+		shifted.shift(direction);
+		shifted
+	}
+}
+
+impl BitGrid {
+	/// A grid of `width` by `height` cells, none of them set.
+	pub fn new(width: usize, height: usize) -> Self {
+		BitGrid::with_flags(width, height)
+	}
+
+	/// Parses newline separated rows, setting the cells `f` is true for.
+	pub fn parse(input: &[u8], mut f: impl FnMut(u8) -> bool) -> Self {
+		let bytes = ByteGrid::new(input);
+		let mut grid = BitGrid::new(bytes.width(), bytes.height());
+		for (y, row) in bytes.rows().enumerate() {
+			for (x, &b) in row.iter().enumerate() {
+				if f(b) {
+					grid.insert(x, y);
+				}
+			}
+		}
+		grid
+	}
+
+	/// The flags of row `y`, cell `x` being bit `x % 64` of word `x / 64`.
+	pub fn row(&self, y: usize) -> &[u64] {
+		self.flag_row(0, y)
+	}
+
+	/// Whether the cell at `(x, y)` is set, which none off the grid are.
+	#[inline]
+	pub fn contains(&self, x: usize, y: usize) -> bool {
+		self.contains_flag(x, y, 0)
+	}
+
+	/// Sets the cell at `(x, y)`, returning whether it was not set before.
+	#[inline]
+	pub fn insert(&mut self, x: usize, y: usize) -> bool {
+		self.insert_flag(x, y, 0)
+	}
+
+	/// Unsets the cell at `(x, y)`, returning whether it was set.
+	#[inline]
+	pub fn remove(&mut self, x: usize, y: usize) -> bool {
+		self.remove_flag(x, y, 0)
+	}
+
+	/// The positions of all set cells, row by row.
+	pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+		(0..self.height).flat_map(move |y| Iter::new(self.row(y)).map(move |x| (x, y)))
+	}
+}
+
+/// Set cells as `#`, others as `.`.
+impl fmt::Display for BitGrid {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for y in 0..self.height {
+			for x in 0..self.width {
+				write!(f, "{}", if self.contains(x, y) { '#' } else { '.' })?;
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn combines_sets() {
+		let mut a: BitSet = [1, 5, 64, 200].into_iter().collect();
+		let b: BitSet = [5, 63, 64].into_iter().collect();
+		assert_eq!(a.len(), 4);
+		assert!(a.contains(200) && !a.contains(199) && !a.contains(100_000));
+		assert_eq!(a.iter().collect::<Vec<_>>(), [1, 5, 64, 200]);
+
+		assert_eq!((&a | &b).iter().collect::<Vec<_>>(), [1, 5, 63, 64, 200]);
+		assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [5, 64]);
+		assert_eq!((&a - &b).iter().collect::<Vec<_>>(), [1, 200]);
+		assert_eq!((&a ^ &b).iter().collect::<Vec<_>>(), [1, 63, 200]);
+		assert!((&a & &b).is_subset(&b));
+		assert!(!a.is_disjoint(&b));
+
+		assert!(a.remove(200));
+		assert!(!a.remove(200));
+		assert_eq!(a, [1, 5, 64].into_iter().collect());
+		a -= &b;
+		assert_eq!(a.first(), Some(1));
+		a.clear();
+		assert!(a.is_empty());
+		assert_eq!(a, BitSet::new());
+	}
+
+	#[test]
+	fn combines_fixed_sets() {
+		// like the winning numbers and numbers you have of 2023 day 4
+		let winning: FixedBitSet<2> = [41, 48, 83, 86, 17].into_iter().collect();
+		let have: FixedBitSet<2> = [83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect();
+		assert_eq!((winning & have).len(), 4);
+		assert_eq!(FixedBitSet::<2>::CAPACITY, 128);
+
+		let mut set = FixedBitSet::<1>::new();
+		assert!(set.insert(63));
+		assert!(!set.insert(63));
+		set |= [0, 1].into_iter().collect();
+		assert_eq!(set.iter().collect::<Vec<_>>(), [0, 1, 63]);
+		assert!(set.is_superset(&[0, 63].into_iter().collect()));
+	}
+
+	#[test]
+	fn shifts_grids() {
+		let mut grid = BitGrid::new(70, 3);
+		grid.insert(0, 0);
+		grid.insert(63, 1);
+		grid.insert(69, 2);
+		assert_eq!(grid.row(1), [1 << 63, 0]);
+
+		let east = grid.shifted(East);
+		assert_eq!(east.iter().collect::<Vec<_>>(), [(1, 0), (64, 1)]);
+		let west = grid.shifted(West);
+		assert_eq!(west.iter().collect::<Vec<_>>(), [(62, 1), (68, 2)]);
+		let south = grid.shifted(South);
+		assert_eq!(south.iter().collect::<Vec<_>>(), [(0, 1), (63, 2)]);
+		let north = grid.shifted(North);
+		assert_eq!(north.iter().collect::<Vec<_>>(), [(63, 0), (69, 1)]);
+	}
+
+	#[test]
+	fn keeps_flags_apart() {
+		// the directions beams passed a cell in, like 2023 day 16
+		let mut beams = BitGrid::<4>::with_flags(70, 3);
+		assert!(beams.insert_flag(65, 1, 2));
+		assert!(!beams.insert_flag(65, 1, 2));
+		assert!(beams.insert_flag(65, 1, 0));
+		beams.insert_flag(3, 2, 3);
+		assert_eq!(beams.flags(65, 1), 0b101);
+		assert!(!beams.contains_flag(65, 1, 1) && !beams.contains_flag(70, 1, 2));
+		assert_eq!(beams.flag_row(2, 1), [0, 1 << 1]);
+		assert_eq!(beams.len(), 3);
+
+		let south = beams.shifted(South);
+		assert_eq!((south.flags(65, 2), south.flags(3, 2)), (0b101, 0));
+		assert!(beams.remove_flag(65, 1, 0));
+		assert_eq!(beams.any().iter().collect::<Vec<_>>(), [(65, 1), (3, 2)]);
+	}
+
+	#[test]
+	fn floods_grids() {
+		let input = b"#######\n#..#..#\n#..#..#\n#.....#\n#######\n";
+		let walls = BitGrid::parse(input, |b| b == b'#');
+		assert_eq!(walls.to_string().as_bytes(), input);
+
+		// spreads to all neighbours at once, until nothing changes
+		let mut flooded = BitGrid::new(walls.width(), walls.height());
+		flooded.insert(1, 1);
+		loop {
+			let mut next = flooded.clone();
+			for direction in [North, East, South, West] {
+				next.union_with(&flooded.shifted(direction));
+			}
+			next.difference_with(&walls);
+			if next == flooded {
+				break;
+			}
+			flooded = next;
+		}
+		assert_eq!(flooded.len(), 13);
+		assert!(flooded.contains(5, 1));
+		assert!(!flooded.contains(3, 1));
+	}
+}
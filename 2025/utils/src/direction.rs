@@ -14,6 +14,9 @@ pub enum Direction {
 pub use Direction::*;
 
 impl Direction {
+	/// All directions, clockwise from the north.
+	pub const ALL: [Direction; 4] = [North, East, South, West];
+
 	#[inline]
 	#[must_use]
 	pub const fn opposite(&self) -> Direction {
@@ -132,3 +135,239 @@ impl Debug for Direction {
 		}
 	}
 }
+
+/// The four directions of [`Direction`] and the diagonals between them.
+///
+/// Its variants are not re-exported, as they would clash with the ones of [`Direction`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+	North,
+	NorthEast,
+	East,
+	SouthEast,
+	South,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+impl Direction8 {
+	/// All directions, clockwise from the north.
+	pub const ALL: [Direction8; 8] = [
+		Direction8::North,
+		Direction8::NorthEast,
+		Direction8::East,
+		Direction8::SouthEast,
+		Direction8::South,
+		Direction8::SouthWest,
+		Direction8::West,
+		Direction8::NorthWest,
+	];
+
+	pub const CARDINAL: [Direction8; 4] = [
+		Direction8::North,
+		Direction8::East,
+		Direction8::South,
+		Direction8::West,
+	];
+
+	pub const DIAGONAL: [Direction8; 4] = [
+		Direction8::NorthEast,
+		Direction8::SouthEast,
+		Direction8::SouthWest,
+		Direction8::NorthWest,
+	];
+
+	#[inline]
+	#[must_use]
+	pub const fn opposite(&self) -> Direction8 {
+		self.turn_clockwise_times(4)
+	}
+
+	/// Turns by 45°.
+	#[inline]
+	#[must_use]
+	pub const fn turn_clockwise(&self) -> Direction8 {
+		self.turn_clockwise_times(1)
+	}
+
+	/// Turns by 45°.
+	#[inline]
+	#[must_use]
+	pub const fn turn_widdershins(&self) -> Direction8 {
+		self.turn_widdershins_times(1)
+	}
+
+	/// Turns by `n_times` 45°.
+	#[inline]
+	#[must_use]
+	pub const fn turn_clockwise_times(&self, n_times: usize) -> Direction8 {
+		Self::ALL[(*self as usize + n_times % 8) % 8]
+	}
+
+	/// Turns by `n_times` 45°.
+	#[inline]
+	#[must_use]
+	pub const fn turn_widdershins_times(&self, n_times: usize) -> Direction8 {
+		Self::ALL[(*self as usize + 8 - n_times % 8) % 8]
+	}
+
+	#[inline]
+	pub const fn is_diagonal(&self) -> bool {
+		*self as usize % 2 == 1
+	}
+
+	/// The step in `x` and `y`, with `y` growing southwards.
+	#[inline]
+	pub const fn offset(&self) -> (isize, isize) {
+		match self {
+			Direction8::North => (0, -1),
+			Direction8::NorthEast => (1, -1),
+			Direction8::East => (1, 0),
+			Direction8::SouthEast => (1, 1),
+			Direction8::South => (0, 1),
+			Direction8::SouthWest => (-1, 1),
+			Direction8::West => (-1, 0),
+			Direction8::NorthWest => (-1, -1),
+		}
+	}
+
+	/// The [`Direction`], unless this is a diagonal.
+	#[inline]
+	pub const fn to_direction(&self) -> Option<Direction> {
+		match self {
+			Direction8::North => Some(North),
+			Direction8::East => Some(East),
+			Direction8::South => Some(South),
+			Direction8::West => Some(West),
+			_ => None,
+		}
+	}
+}
+
+impl From<Direction> for Direction8 {
+	#[inline]
+	fn from(direction: Direction) -> Self {
+		match direction {
+			North => Direction8::North,
+			East => Direction8::East,
+			South => Direction8::South,
+			West => Direction8::West,
+		}
+	}
+}
+
+impl TryFrom<Direction8> for Direction {
+	/// The diagonal that has no [`Direction`].
+	type Error = Direction8;
+
+	#[inline]
+	fn try_from(direction: Direction8) -> Result<Self, Direction8> {
+		direction.to_direction().ok_or(direction)
+	}
+}
+
+impl Neg for Direction8 {
+	type Output = Direction8;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		self.opposite()
+	}
+}
+
+impl Add<usize> for Direction8 {
+	type Output = Direction8;
+
+	#[inline]
+	fn add(self, rhs: usize) -> Self::Output {
+		self.turn_clockwise_times(rhs)
+	}
+}
+
+impl AddAssign<usize> for Direction8 {
+	#[inline]
+	fn add_assign(&mut self, rhs: usize) {
+		*self = self.turn_clockwise_times(rhs);
+	}
+}
+
+impl Sub<usize> for Direction8 {
+	type Output = Direction8;
+
+	#[inline]
+	fn sub(self, rhs: usize) -> Self::Output {
+		self.turn_widdershins_times(rhs)
+	}
+}
+
+impl SubAssign<usize> for Direction8 {
+	#[inline]
+	fn sub_assign(&mut self, rhs: usize) {
+		*self = self.turn_widdershins_times(rhs);
+	}
+}
+
+impl Debug for Direction8 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if f.alternate() {
+			match self {
+				Direction8::North => write!(f, "N ↑"),
+				Direction8::NorthEast => write!(f, "NE ↗"),
+				Direction8::East => write!(f, "E →"),
+				Direction8::SouthEast => write!(f, "SE ↘"),
+				Direction8::South => write!(f, "S ↓"),
+				Direction8::SouthWest => write!(f, "SW ↙"),
+				Direction8::West => write!(f, "W ←"),
+				Direction8::NorthWest => write!(f, "NW ↖"),
+			}
+		} else {
+			match self {
+				Direction8::North => write!(f, "North"),
+				Direction8::NorthEast => write!(f, "NorthEast"),
+				Direction8::East => write!(f, "East"),
+				Direction8::SouthEast => write!(f, "SouthEast"),
+				Direction8::South => write!(f, "South"),
+				Direction8::SouthWest => write!(f, "SouthWest"),
+				Direction8::West => write!(f, "West"),
+				Direction8::NorthWest => write!(f, "NorthWest"),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn turns_eight_ways() {
+		assert_eq!(Direction8::North + 1, Direction8::NorthEast);
+		assert_eq!(Direction8::North - 1, Direction8::NorthWest);
+		assert_eq!(Direction8::West + 3, Direction8::NorthEast);
+		assert_eq!(Direction8::SouthEast - 13, Direction8::West);
+		assert_eq!(-Direction8::SouthWest, Direction8::NorthEast);
+
+		for direction in Direction8::ALL {
+			assert_eq!(direction + 8, direction);
+			assert_eq!(direction + 2 - 2, direction);
+			let (x, y) = direction.offset();
+			assert_eq!((-direction).offset(), (-x, -y));
+		}
+	}
+
+	#[test]
+	fn converts_to_four_ways() {
+		for direction in Direction::ALL {
+			let direction8 = Direction8::from(direction);
+			assert!(!direction8.is_diagonal());
+			assert_eq!(Direction::try_from(direction8), Ok(direction));
+			assert_eq!(Direction8::from(direction + 1), direction8 + 2);
+		}
+		assert_eq!(Direction8::CARDINAL, Direction::ALL.map(Direction8::from));
+		for direction in Direction8::DIAGONAL {
+			assert!(direction.is_diagonal());
+			assert_eq!(Direction::try_from(direction), Err(direction));
+		}
+	}
+}
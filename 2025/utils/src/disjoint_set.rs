@@ -0,0 +1,175 @@
+//! Union-find, for puzzles that keep joining things into groups.
+
+/// Elements `0..len` in disjoint components, each named by one of its elements, its
+/// root.
+///
+/// Finding roots compresses paths and unions hang smaller components under larger
+/// ones, so all operations take nearly constant time.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+	parents: Vec<usize>,
+	/// Only meaningful for roots.
+	sizes: Vec<usize>,
+	count: usize,
+}
+
+impl DisjointSet {
+	/// `len` elements, each in its own component.
+	pub fn new(len: usize) -> Self {
+		DisjointSet {
+			parents: (0..len).collect(),
+			sizes: vec![1; len],
+			count: len,
+		}
+	}
+
+	/// The number of elements.
+	pub fn len(&self) -> usize {
+		self.parents.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.parents.is_empty()
+	}
+
+	/// The number of components.
+	pub fn count(&self) -> usize {
+		self.count
+	}
+
+	/// Adds an element in its own component, returning it.
+	pub fn push(&mut self) -> usize {
+		let element = self.parents.len();
+		self.parents.push(element);
+		self.sizes.push(1);
+		self.count += 1;
+		element
+	}
+
+	/// The root of the component of `element`.
+	pub fn find(&mut self, element: usize) -> usize {
+		let mut root = element;
+		while self.parents[root] != root {
+			root = self.parents[root];
+		}
+
+		// point everything on the way straight at the root
+		let mut element = element;
+		while self.parents[element] != root {
+			element = std::mem::replace(&mut self.parents[element], root);
+		}
+		root
+	}
+
+	/// Joins the components of `a` and `b`, returning whether they were apart.
+	pub fn union(&mut self, a: usize, b: usize) -> bool {
+		let (a, b) = (self.find(a), self.find(b));
+		if a == b {
+			return false;
+		}
+
+		let (smaller, larger) = if self.sizes[a] < self.sizes[b] {
+			(a, b)
+		} else {
+			(b, a)
+		};
+		self.parents[smaller] = larger;
+		self.sizes[larger] += self.sizes[smaller];
+		self.count -= 1;
+		true
+	}
+
+	pub fn same(&mut self, a: usize, b: usize) -> bool {
+		self.find(a) == self.find(b)
+	}
+
+	/// The number of elements in the component of `element`.
+	pub fn size(&mut self, element: usize) -> usize {
+		let root = self.find(element);
+		self.sizes[root]
+	}
+
+	/// The roots of all components.
+	pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+		(0..self.len()).filter(|&element| self.parents[element] == element)
+	}
+
+	/// The roots and sizes of the `k` largest components, largest first.
+	pub fn largest(&self, k: usize) -> Vec<(usize, usize)> {
+		let mut components: Vec<_> = self.roots().map(|root| (root, self.sizes[root])).collect();
+		let by_size = |a: &(usize, usize), b: &(usize, usize)| b.1.cmp(&a.1);
+		if k < components.len() {
+			components.select_nth_unstable_by(k, by_size);
+			components.truncate(k);
+		}
+		components.sort_unstable_by(by_size);
+		components
+	}
+
+	/// The elements of every component, in no particular order.
+	pub fn components(&mut self) -> Vec<Vec<usize>> {
+		let mut indices = vec![usize::MAX; self.len()];
+		let mut components = Vec::with_capacity(self.count);
+		for element in 0..self.len() {
+			let root = self.find(element);
+			if indices[root] == usize::MAX {
+				indices[root] = components.len();
+				components.push(Vec::with_capacity(self.sizes[root]));
+			}
+			components[indices[root]].push(element);
+		}
+		components
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn joins_components() {
+		let mut set = DisjointSet::new(8);
+		assert_eq!(set.count(), 8);
+
+		assert!(set.union(0, 1));
+		assert!(set.union(2, 3));
+		assert!(set.union(1, 3));
+		assert!(!set.union(0, 2));
+		assert!(set.union(5, 6));
+
+		assert_eq!(set.count(), 4);
+		assert!(set.same(3, 0));
+		assert!(!set.same(4, 5));
+		assert_eq!(set.size(2), 4);
+		assert_eq!(set.size(4), 1);
+
+		let new = set.push();
+		set.union(new, 6);
+		assert_eq!(set.size(5), 3);
+		assert_eq!(set.count(), 4);
+	}
+
+	#[test]
+	fn lists_components() {
+		let mut set = DisjointSet::new(7);
+		for (a, b) in [(0, 6), (6, 3), (1, 4)] {
+			set.union(a, b);
+		}
+
+		let largest = set.largest(2);
+		assert_eq!(
+			largest.iter().map(|&(_, size)| size).collect::<Vec<_>>(),
+			[3, 2]
+		);
+		assert_eq!(set.size(largest[0].0), 3);
+		assert!(set.same(largest[0].0, 6));
+		assert_eq!(set.largest(10).len(), 4);
+
+		let mut components = set.components();
+		components
+			.iter_mut()
+			.for_each(|component| component.sort_unstable());
+		components.sort_unstable();
+		assert_eq!(components, [vec![0, 3, 6], vec![1, 4], vec![2], vec![5]]);
+	}
+}
@@ -0,0 +1,238 @@
+//! Random puzzle inputs of configurable size, for property tests against naive
+//! implementations and for benchmarking beyond the personal input.
+//!
+//! Every generator follows the format and the (sometimes implicit) guarantees of the
+//! real puzzle input, `size` roughly scales the input linearly or per side of a grid.
+
+use std::{collections::HashSet, io::Write, ops::Range};
+
+/// Seedable random number generator (SplitMix64), reproducible across platforms.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Rng(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// A number in `range`, which must not be empty.
+	pub fn range(&mut self, range: Range<usize>) -> usize {
+		assert!(!range.is_empty(), "empty range");
+		range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+	}
+
+	/// `true` with a probability of `numerator / denominator`.
+	pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+		self.range(0..denominator) < numerator
+	}
+
+	pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.range(0..items.len())]
+	}
+
+	/// A number with exactly `digits` digits.
+	pub fn digits(&mut self, digits: u32) -> usize {
+		self.range(10_usize.pow(digits - 1)..10_usize.pow(digits))
+	}
+}
+
+/// Day 01: `size` rotations like `L68`, turning the dial by 1 to 999 clicks.
+pub fn day_01(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut input = vec![];
+	for _ in 0..size {
+		let direction = *rng.pick(&['L', 'R']);
+		writeln!(input, "{direction}{}", rng.range(1..1000)).unwrap();
+	}
+	input
+}
+
+/// Day 02: `size` comma separated id ranges like `998-1012`, of up to 10 digits and
+/// spanning at most 10000 ids each.
+pub fn day_02(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let ranges: Vec<String> = (0..size)
+		.map(|_| {
+			let digits = rng.range(1..11) as u32;
+			let start = rng.digits(digits);
+			format!("{start}-{}", start + rng.range(0..10_000))
+		})
+		.collect();
+	format!("{}\n", ranges.join(",")).into_bytes()
+}
+
+/// Day 03: `size` banks of batteries rated 1 to 9, all of the same width of 12 to 100.
+pub fn day_03(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let width = rng.range(12..101);
+	let mut input = vec![];
+	for _ in 0..size.max(2) {
+		input.extend((0..width).map(|_| b'0' + rng.range(1..10) as u8));
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 04: a `size` by `size` grid with rolls of paper (`@`) on about 60% of the spots.
+pub fn day_04(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(2);
+	let mut input = vec![];
+	for _ in 0..size {
+		input.extend((0..size).map(|_| if rng.chance(3, 5) { b'@' } else { b'.' }));
+		input.push(b'\n');
+	}
+	input
+}
+
+/// Day 05: `size` (often overlapping) fresh id ranges, a blank line and `size` ids,
+/// half of which are picked from one of the ranges.
+pub fn day_05(rng: &mut Rng, size: usize) -> Vec<u8> {
+	const SPAN: usize = 1_000_000_000_000_000;
+	let size = size.max(1);
+
+	let ranges: Vec<(usize, usize)> = (0..size)
+		.map(|_| {
+			let start = rng.range(1..SPAN);
+			(start, start + rng.range(0..SPAN / size))
+		})
+		.collect();
+
+	let mut input = vec![];
+	for (start, end) in &ranges {
+		writeln!(input, "{start}-{end}").unwrap();
+	}
+	input.push(b'\n');
+	for _ in 0..size {
+		let id = if rng.chance(1, 2) {
+			let (start, end) = *rng.pick(&ranges);
+			rng.range(start..end + 1)
+		} else {
+			rng.range(1..SPAN)
+		};
+		writeln!(input, "{id}").unwrap();
+	}
+	input
+}
+
+/// Day 06: a worksheet of `size` problems side by side, each with 2 to 4 numbers of up
+/// to 4 digits, left or right aligned in their column, and the operator below.
+pub fn day_06(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let rows = rng.range(2..5);
+	let mut lines = vec![String::new(); rows + 1];
+
+	for problem in 0..size.max(1) {
+		let width = rng.range(1..5);
+		let numbers: Vec<usize> = (0..rows)
+			.map(|row| {
+				let digits = if row == 0 {
+					width
+				} else {
+					rng.range(1..width + 1)
+				};
+				rng.digits(digits as u32)
+			})
+			.collect();
+		let right_aligned = rng.chance(1, 2);
+
+		for (line, number) in lines.iter_mut().zip(numbers) {
+			if problem > 0 {
+				line.push(' ');
+			}
+			if right_aligned {
+				line.push_str(&format!("{number:>width$}"));
+			} else {
+				line.push_str(&format!("{number:<width$}"));
+			}
+		}
+		if problem > 0 {
+			lines[rows].push(' ');
+		}
+		lines[rows].push_str(&format!("{:<width$}", rng.pick(&['+', '*'])));
+	}
+
+	(lines.join("\n") + "\n").into_bytes()
+}
+
+/// Day 07: a manifold with `size` rows of splitters below the start `S`. Splitters can
+/// only be placed where a beam could reach them, which keeps them apart from each other
+/// and from the borders.
+pub fn day_07(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let size = size.max(1);
+	let width = 2 * size + 1;
+	let empty = ".".repeat(width);
+
+	let mut lines = vec![
+		format!("{}S{}", &empty[..size], &empty[..size]),
+		empty.clone(),
+	];
+	for row in 1..=size {
+		let mut line = empty.clone().into_bytes();
+		for column in (size + 1 - row..size + row).step_by(2) {
+			if rng.chance(4, 5) {
+				line[column] = b'^';
+			}
+		}
+		lines.push(String::from_utf8(line).unwrap());
+		lines.push(empty.clone());
+	}
+
+	(lines.join("\n") + "\n").into_bytes()
+}
+
+/// Day 08: `size` junction boxes with coordinates below 100000, all pairwise
+/// distances distinct so that the order of connections is unambiguous.
+pub fn day_08(rng: &mut Rng, size: usize) -> Vec<u8> {
+	let mut boxes: Vec<[usize; 3]> = vec![];
+	let mut distances = HashSet::new();
+
+	while boxes.len() < size.max(2) {
+		let new = [(); 3].map(|_| rng.range(0..100_000));
+		let new_distances: Vec<usize> = boxes
+			.iter()
+			.map(|old| (0..3).map(|i| old[i].abs_diff(new[i]).pow(2)).sum())
+			.collect();
+		let unique: HashSet<_> = new_distances.iter().collect();
+		if unique.len() == new_distances.len()
+			&& new_distances.iter().all(|d| !distances.contains(d))
+		{
+			distances.extend(new_distances);
+			boxes.push(new);
+		}
+	}
+
+	let mut input = vec![];
+	for [x, y, z] in boxes {
+		writeln!(input, "{x},{y},{z}").unwrap();
+	}
+	input
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn is_reproducible() {
+		assert_eq!(day_04(&mut Rng::new(7), 10), day_04(&mut Rng::new(7), 10));
+		assert_ne!(day_04(&mut Rng::new(7), 10), day_04(&mut Rng::new(8), 10));
+	}
+
+	#[test]
+	fn generates_puzzle_layouts() {
+		assert_eq!(
+			String::from_utf8(day_07(&mut Rng::new(1), 2))
+				.unwrap()
+				.lines()
+				.count(),
+			6
+		);
+		let worksheet = String::from_utf8(day_06(&mut Rng::new(1), 5)).unwrap();
+		let widths: HashSet<usize> = worksheet.lines().map(str::len).collect();
+		assert_eq!(widths.len(), 1);
+	}
+}
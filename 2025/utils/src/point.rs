@@ -0,0 +1,349 @@
+//! Points on a plane, moving around by [`Direction`].
+//!
+//! Like the grids in [`crate::grid`], `y` grows southwards: stepping [`North`] takes one
+//! off `y`.
+
+use crate::direction::{Direction, Direction8, East, North, South, West};
+use std::{
+	fmt::Debug,
+	hash::Hash,
+	ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// The primitive integers a [`Point2`] can be made of.
+pub trait Coordinate:
+	Debug + Copy + Ord + Hash + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+	const ZERO: Self;
+	const ONE: Self;
+
+	fn checked_add(self, other: Self) -> Option<Self>;
+	fn checked_sub(self, other: Self) -> Option<Self>;
+	fn wrapping_add(self, other: Self) -> Self;
+	fn wrapping_sub(self, other: Self) -> Self;
+	/// The distance between two values, which fits into a `usize` for all of them.
+	fn distance(self, other: Self) -> usize;
+	fn to_usize(self) -> Option<usize>;
+	/// Converts like `as` does, wrapping values that do not fit.
+	fn from_usize(value: usize) -> Self;
+}
+
+macro_rules! coordinate {
+	($($int:ty),*) => {
+		$(
+			impl Coordinate for $int {
+				const ZERO: Self = 0;
+				const ONE: Self = 1;
+
+				#[inline]
+				fn checked_add(self, other: Self) -> Option<Self> {
+					<$int>::checked_add(self, other)
+				}
+
+				#[inline]
+				fn checked_sub(self, other: Self) -> Option<Self> {
+					<$int>::checked_sub(self, other)
+				}
+
+				#[inline]
+				fn wrapping_add(self, other: Self) -> Self {
+					<$int>::wrapping_add(self, other)
+				}
+
+				#[inline]
+				fn wrapping_sub(self, other: Self) -> Self {
+					<$int>::wrapping_sub(self, other)
+				}
+
+				#[inline]
+				fn distance(self, other: Self) -> usize {
+					self.abs_diff(other) as usize
+				}
+
+				#[inline]
+				fn to_usize(self) -> Option<usize> {
+					usize::try_from(self).ok()
+				}
+
+				#[inline]
+				fn from_usize(value: usize) -> Self {
+					value as $int
+				}
+			}
+		)*
+	};
+}
+
+coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T> Point2<T> {
+	#[inline]
+	pub const fn new(x: T, y: T) -> Self {
+		Point2 { x, y }
+	}
+}
+
+impl<T: Coordinate> Point2<T> {
+	pub const ORIGIN: Self = Point2::new(T::ZERO, T::ZERO);
+
+	/// The neighbour into `direction`, unless a coordinate overflows.
+	#[inline]
+	#[must_use]
+	pub fn checked_step(self, direction: Direction) -> Option<Self> {
+		let Point2 { x, y } = self;
+		Some(match direction {
+			North => Point2::new(x, y.checked_sub(T::ONE)?),
+			East => Point2::new(x.checked_add(T::ONE)?, y),
+			South => Point2::new(x, y.checked_add(T::ONE)?),
+			West => Point2::new(x.checked_sub(T::ONE)?, y),
+		})
+	}
+
+	/// The neighbour into `direction`, wrapping around at the bounds of `T`.
+	#[inline]
+	#[must_use]
+	pub fn wrapping_step(self, direction: Direction) -> Self {
+		let Point2 { x, y } = self;
+		match direction {
+			North => Point2::new(x, y.wrapping_sub(T::ONE)),
+			East => Point2::new(x.wrapping_add(T::ONE), y),
+			South => Point2::new(x, y.wrapping_add(T::ONE)),
+			West => Point2::new(x.wrapping_sub(T::ONE), y),
+		}
+	}
+
+	/// The up to four neighbours that do not overflow, clockwise from the north.
+	pub fn neighbours(self) -> impl Iterator<Item = Self> {
+		[North, East, South, West]
+			.into_iter()
+			.filter_map(move |direction| self.checked_step(direction))
+	}
+
+	#[inline]
+	pub fn manhattan_distance(self, other: Self) -> usize {
+		self.x.distance(other.x) + self.y.distance(other.y)
+	}
+
+	/// The number of king moves between the points, diagonals included.
+	#[inline]
+	pub fn chebyshev_distance(self, other: Self) -> usize {
+		self.x.distance(other.x).max(self.y.distance(other.y))
+	}
+
+	/// The index of the point in a grid of `width` by `height` cells stored row by row,
+	/// like [`Grid`](crate::grid::Grid), if it is on the grid.
+	#[inline]
+	pub fn to_index(self, width: usize, height: usize) -> Option<usize> {
+		let (x, y) = (self.x.to_usize()?, self.y.to_usize()?);
+		(x < width && y < height).then(|| y * width + x)
+	}
+
+	/// The point at `index` in a grid with rows of `width` cells.
+	#[inline]
+	pub fn from_index(index: usize, width: usize) -> Self {
+		Point2::new(T::from_usize(index % width), T::from_usize(index / width))
+	}
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+	#[inline]
+	fn from((x, y): (T, T)) -> Self {
+		Point2 { x, y }
+	}
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+	#[inline]
+	fn from(Point2 { x, y }: Point2<T>) -> Self {
+		(x, y)
+	}
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self {
+		Point2::new(self.x + other.x, self.y + other.y)
+	}
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+	#[inline]
+	fn add_assign(&mut self, other: Self) {
+		self.x += other.x;
+		self.y += other.y;
+	}
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self {
+		Point2::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+	#[inline]
+	fn sub_assign(&mut self, other: Self) {
+		self.x -= other.x;
+		self.y -= other.y;
+	}
+}
+
+/// Scales both coordinates.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, factor: T) -> Self {
+		Point2::new(self.x * factor, self.y * factor)
+	}
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self {
+		Point2::new(-self.x, -self.y)
+	}
+}
+
+/// Steps one cell into the direction, overflowing like the integers do.
+impl<T: Coordinate> Add<Direction> for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, direction: Direction) -> Self {
+		let Point2 { x, y } = self;
+		match direction {
+			North => Point2::new(x, y - T::ONE),
+			East => Point2::new(x + T::ONE, y),
+			South => Point2::new(x, y + T::ONE),
+			West => Point2::new(x - T::ONE, y),
+		}
+	}
+}
+
+/// Steps one cell into the direction, diagonals included.
+impl<T: Coordinate> Add<Direction8> for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, direction: Direction8) -> Self {
+		let Point2 { x, y } = match direction.to_direction() {
+			Some(direction) => return self + direction,
+			None => self,
+		};
+		match direction {
+			Direction8::NorthEast => Point2::new(x + T::ONE, y - T::ONE),
+			Direction8::SouthEast => Point2::new(x + T::ONE, y + T::ONE),
+			Direction8::SouthWest => Point2::new(x - T::ONE, y + T::ONE),
+			_ => Point2::new(x - T::ONE, y - T::ONE),
+		}
+	}
+}
+
+impl<T: Coordinate> AddAssign<Direction> for Point2<T> {
+	#[inline]
+	fn add_assign(&mut self, direction: Direction) {
+		*self = *self + direction;
+	}
+}
+
+/// Steps one cell away from the direction.
+impl<T: Coordinate> Sub<Direction> for Point2<T> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, direction: Direction) -> Self {
+		self + -direction
+	}
+}
+
+impl<T: Coordinate> SubAssign<Direction> for Point2<T> {
+	#[inline]
+	fn sub_assign(&mut self, direction: Direction) {
+		*self = *self - direction;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn does_arithmetic() {
+		let mut point = Point2::new(3_i32, -2);
+		assert_eq!(point + Point2::new(1, 1), Point2::new(4, -1));
+		assert_eq!(point - Point2::new(1, 1), Point2::new(2, -3));
+		assert_eq!(point * 3, Point2::new(9, -6));
+		assert_eq!(-point, Point2::new(-3, 2));
+
+		point += Point2::new(-3, 2);
+		assert_eq!(point, Point2::ORIGIN);
+		point -= Point2::from((1, 1));
+		assert_eq!(<(i32, i32)>::from(point), (-1, -1));
+	}
+
+	#[test]
+	fn steps_into_directions() {
+		let mut point = Point2::new(5_i16, 5);
+		assert_eq!(point + North, Point2::new(5, 4));
+		assert_eq!(point + East, Point2::new(6, 5));
+		assert_eq!(point + South, Point2::new(5, 6));
+		assert_eq!(point + West, Point2::new(4, 5));
+		assert_eq!(point - North, point + South);
+
+		point += East;
+		point -= East + 1;
+		assert_eq!(point, Point2::new(6, 4));
+
+		let around = Direction8::ALL.map(|direction| point + direction);
+		assert!(
+			around
+				.iter()
+				.all(|&other| point.chebyshev_distance(other) == 1)
+		);
+		assert_eq!(around[1], Point2::new(7, 3));
+		assert_eq!(around[5], Point2::new(5, 5));
+	}
+
+	#[test]
+	fn steps_at_the_bounds() {
+		let corner = Point2::new(0_usize, 0);
+		assert_eq!(corner.checked_step(North), None);
+		assert_eq!(corner.checked_step(South), Some(Point2::new(0, 1)));
+		assert_eq!(corner.wrapping_step(West), Point2::new(usize::MAX, 0));
+		assert_eq!(
+			corner.neighbours().collect::<Vec<_>>(),
+			[Point2::new(1, 0), Point2::new(0, 1)]
+		);
+		assert_eq!(Point2::new(u8::MAX, 0).checked_step(East), None);
+	}
+
+	#[test]
+	fn measures_distances() {
+		let (a, b) = (Point2::new(-3_i8, 4), Point2::new(120, -5));
+		assert_eq!(a.manhattan_distance(b), 132);
+		assert_eq!(a.chebyshev_distance(b), 123);
+		assert_eq!(b.manhattan_distance(b), 0);
+	}
+
+	#[test]
+	fn converts_to_grid_indices() {
+		assert_eq!(Point2::new(2_i64, 1).to_index(3, 2), Some(5));
+		assert_eq!(Point2::new(3_i64, 1).to_index(3, 2), None);
+		assert_eq!(Point2::new(-1_i64, 1).to_index(3, 2), None);
+		assert_eq!(Point2::<u32>::from_index(5, 3), Point2::new(2, 1));
+	}
+}
@@ -0,0 +1,286 @@
+//! Polygons with their corners on whole coordinates, like the loops walked in 2023 day 10
+//! or dug in 2023 day 18.
+//!
+//! Like the rest of the crate, `y` grows southwards. Areas are sums of products of
+//! coordinates, so `i64` suits coordinates up to about a billion, `i128` anything larger.
+
+use crate::{
+	direction::{Direction, East, North, South, West},
+	modular::{Integer, gcd},
+	point::Point2,
+};
+
+/// Measures a polygon while tracing its outline corner by corner, without keeping the
+/// corners. The outline is closed by a straight line back to the start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trace<T> {
+	start: Point2<T>,
+	position: Point2<T>,
+	/// The shoelace sum of all lines so far, in its trapezoid form.
+	twice_area: T,
+	boundary: T,
+}
+
+impl<T: Integer> Trace<T> {
+	pub fn new(start: Point2<T>) -> Self {
+		Trace {
+			start,
+			position: start,
+			twice_area: T::ZERO,
+			boundary: T::ZERO,
+		}
+	}
+
+	pub fn start(&self) -> Point2<T> {
+		self.start
+	}
+
+	pub fn position(&self) -> Point2<T> {
+		self.position
+	}
+
+	/// Moves `length` into `direction`.
+	#[inline]
+	pub fn step(&mut self, direction: Direction, length: T) {
+		debug_assert!(length >= T::ZERO);
+		let Point2 { x, y } = self.position;
+		// only lines going east or west span a trapezoid
+		match direction {
+			North => self.position.y = y - length,
+			South => self.position.y = y + length,
+			East => {
+				self.position.x = x + length;
+				self.twice_area = self.twice_area - (y + y) * length;
+			}
+			West => {
+				self.position.x = x - length;
+				self.twice_area = self.twice_area + (y + y) * length;
+			}
+		}
+		self.boundary = self.boundary + length;
+	}
+
+	/// Moves in a straight line to `corner`.
+	#[inline]
+	pub fn line_to(&mut self, corner: Point2<T>) {
+		let (from, to) = (self.position, corner);
+		self.twice_area = self.twice_area + trapezoid(from, to);
+		self.boundary = self.boundary + lattice_points(from, to);
+		self.position = corner;
+	}
+
+	/// Twice the area, as a lattice polygon may cover half a square. Positive if the
+	/// outline goes clockwise, with `y` growing southwards.
+	pub fn twice_signed_area(&self) -> T {
+		self.twice_area + trapezoid(self.position, self.start)
+	}
+
+	/// The area, rounded down if it ends in a half.
+	pub fn area(&self) -> T {
+		self.twice_signed_area().abs() / (T::ONE + T::ONE)
+	}
+
+	/// The number of whole points on the outline, which is its length for axis aligned
+	/// lines.
+	pub fn boundary_points(&self) -> T {
+		self.boundary + lattice_points(self.position, self.start)
+	}
+
+	/// The number of whole points inside the outline, not on it, by Pick's theorem.
+	///
+	/// That only holds if the outline does not cross or touch itself.
+	pub fn interior_points(&self) -> T {
+		let two = T::ONE + T::ONE;
+		(self.twice_signed_area().abs() - self.boundary_points() + two) / two
+	}
+
+	/// The number of whole points inside the outline or on it, like the cells of a grid
+	/// that a loop walks through and around.
+	pub fn enclosed_points(&self) -> T {
+		self.interior_points() + self.boundary_points()
+	}
+}
+
+/// Twice the signed area between the line from `from` to `to` and `y` = 0.
+#[inline]
+fn trapezoid<T: Integer>(from: Point2<T>, to: Point2<T>) -> T {
+	(from.x - to.x) * (from.y + to.y)
+}
+
+/// The number of whole points on the line from `from` to `to`, counting only one end.
+fn lattice_points<T: Integer>(from: Point2<T>, to: Point2<T>) -> T {
+	gcd(to.x - from.x, to.y - from.y)
+}
+
+/// A polygon given by its corners, in order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Polygon<T> {
+	corners: Vec<Point2<T>>,
+}
+
+impl<T: Integer> Polygon<T> {
+	pub const fn new() -> Self {
+		Polygon { corners: vec![] }
+	}
+
+	pub fn corners(&self) -> &[Point2<T>] {
+		&self.corners
+	}
+
+	pub fn push(&mut self, corner: Point2<T>) {
+		self.corners.push(corner);
+	}
+
+	/// Adds the corner `length` into `direction` of the last one, or of the origin if
+	/// there is none.
+	pub fn step(&mut self, direction: Direction, length: T) {
+		let Point2 { x, y } = self
+			.corners
+			.last()
+			.copied()
+			.unwrap_or(Point2::new(T::ZERO, T::ZERO));
+		self.corners.push(match direction {
+			North => Point2::new(x, y - length),
+			East => Point2::new(x + length, y),
+			South => Point2::new(x, y + length),
+			West => Point2::new(x - length, y),
+		});
+	}
+
+	/// Traces the corners, to measure the polygon.
+	pub fn trace(&self) -> Trace<T> {
+		let Some(&start) = self.corners.first() else {
+			return Trace::new(Point2::new(T::ZERO, T::ZERO));
+		};
+		let mut trace = Trace::new(start);
+		for &corner in &self.corners[1..] {
+			trace.line_to(corner);
+		}
+		trace
+	}
+
+	fn lines(&self) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+		let next = self.corners.iter().skip(1).chain(self.corners.first());
+		self.corners.iter().copied().zip(next.copied())
+	}
+
+	pub fn on_boundary(&self, point: Point2<T>) -> bool {
+		self.lines().any(|(a, b)| {
+			cross(a, b, point) == T::ZERO
+				&& a.x.min(b.x) <= point.x
+				&& point.x <= a.x.max(b.x)
+				&& a.y.min(b.y) <= point.y
+				&& point.y <= a.y.max(b.y)
+		})
+	}
+
+	/// Whether `point` is inside the polygon or on its outline.
+	///
+	/// Casts a ray eastwards and counts the lines it crosses. A line only counts if it
+	/// reaches below the ray, so corners on the ray count once or twice as they should.
+	pub fn contains(&self, point: Point2<T>) -> bool {
+		if self.on_boundary(point) {
+			return true;
+		}
+
+		let mut inside = false;
+		for (a, b) in self.lines() {
+			if (a.y > point.y) != (b.y > point.y) {
+				// whether the line passes east of the point, without dividing
+				let east = (cross(a, b, point) > T::ZERO) == (b.y > a.y);
+				inside ^= east;
+			}
+		}
+		inside
+	}
+}
+
+impl<T: Integer> FromIterator<Point2<T>> for Polygon<T> {
+	fn from_iter<I: IntoIterator<Item = Point2<T>>>(corners: I) -> Self {
+		Polygon {
+			corners: corners.into_iter().collect(),
+		}
+	}
+}
+
+/// Positive if `point` is to one side of the line through `a` and `b`, negative if to
+/// the other, zero if on it.
+#[inline]
+fn cross<T: Integer>(a: Point2<T>, b: Point2<T>, point: Point2<T>) -> T {
+	(b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn measures_dug_loops() {
+		// example of 2023 day 18
+		let steps = [
+			(East, 6),
+			(South, 5),
+			(West, 2),
+			(South, 2),
+			(East, 2),
+			(South, 2),
+			(West, 5),
+			(North, 2),
+			(West, 1),
+			(North, 2),
+			(East, 2),
+			(North, 3),
+			(West, 2),
+			(North, 2),
+		];
+		let mut trace = Trace::new(Point2::new(0_i64, 0));
+		let mut polygon = Polygon::new();
+		polygon.push(Point2::new(0, 0));
+		for (direction, length) in steps {
+			trace.step(direction, length);
+			polygon.step(direction, length);
+		}
+		polygon.corners.pop();
+
+		assert_eq!(trace.position(), trace.start());
+		assert_eq!(trace.twice_signed_area(), 84);
+		assert_eq!(trace.boundary_points(), 38);
+		assert_eq!(trace.enclosed_points(), 62);
+		assert_eq!(polygon.trace().twice_signed_area(), 84);
+		assert_eq!(polygon.trace().enclosed_points(), 62);
+
+		let mut enclosed = 0;
+		for y in -1..=10 {
+			for x in -1..=7 {
+				enclosed += polygon.contains(Point2::new(x, y)) as i64;
+			}
+		}
+		assert_eq!(enclosed, 62);
+	}
+
+	#[test]
+	fn measures_slanted_lines() {
+		// a triangle with corners on rays and slanted sides
+		let triangle: Polygon<i128> = [(0, 0), (6, -3), (2, 4)]
+			.map(|(x, y)| Point2::new(x, y))
+			.into_iter()
+			.collect();
+		let trace = triangle.trace();
+		assert_eq!(trace.twice_signed_area(), 30);
+		assert_eq!(trace.area(), 15);
+		assert_eq!(trace.boundary_points(), 3 + 1 + 2);
+
+		let (mut interior, mut boundary) = (0, 0);
+		for y in -5..=5 {
+			for x in -1..=7 {
+				let point = Point2::new(x, y);
+				boundary += triangle.on_boundary(point) as i128;
+				interior += (triangle.contains(point) && !triangle.on_boundary(point)) as i128;
+			}
+		}
+		assert_eq!(boundary, trace.boundary_points());
+		assert_eq!(interior, trace.interior_points());
+		assert!(triangle.contains(Point2::new(3, 0)));
+		assert!(!triangle.contains(Point2::new(6, 0)));
+	}
+}
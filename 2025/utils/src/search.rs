@@ -0,0 +1,494 @@
+//! Shortest paths through graphs that only exist as closures giving the neighbours of a
+//! node.
+//!
+//! Every search returns the path it found, start and goal included. Nodes are told apart
+//! by [`NodeIds`], hashing them by default, and waiting nodes are kept in a [`Frontier`],
+//! a binary heap by default.
+//!
+//! `cargo bench -p aoc-utils --bench search` compares the searches with the ones of the
+//! pathfinding crate.
+
+use std::{
+	cmp::Reverse,
+	collections::{BinaryHeap, HashMap, VecDeque},
+	fmt::Debug,
+	hash::{BuildHasherDefault, Hash},
+	ops::Add,
+};
+
+use crate::hash::FxHasher;
+
+/// The costs of paths, the primitive integers.
+pub trait Cost: Debug + Copy + Ord + Add<Output = Self> {
+	const ZERO: Self;
+}
+
+macro_rules! cost {
+	($($int:ty),*) => {
+		$(
+			impl Cost for $int {
+				const ZERO: Self = 0;
+			}
+		)*
+	};
+}
+
+cost!(
+	u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Numbers the nodes of a search, so what it knows about them can be kept in a [`Vec`].
+pub trait NodeIds<N> {
+	fn id(&mut self, node: &N) -> usize;
+}
+
+/// Numbers nodes in the order they are found, for any node that can be hashed.
+#[derive(Debug, Clone)]
+pub struct Interned<N> {
+	ids: HashMap<N, usize, BuildHasherDefault<FxHasher>>,
+}
+
+impl<N> Interned<N> {
+	pub fn new() -> Self {
+		Interned {
+			ids: HashMap::default(),
+		}
+	}
+}
+
+impl<N> Default for Interned<N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<N: Hash + Eq + Clone> NodeIds<N> for Interned<N> {
+	#[inline]
+	fn id(&mut self, node: &N) -> usize {
+		if let Some(&id) = self.ids.get(node) {
+			return id;
+		}
+		let id = self.ids.len();
+		self.ids.insert(node.clone(), id);
+		id
+	}
+}
+
+/// Nodes that are small numbers already, or easily turned into one, like positions in a
+/// grid. The numbers should be dense, as the search allocates up to the largest.
+impl<N, F: FnMut(&N) -> usize> NodeIds<N> for F {
+	#[inline]
+	fn id(&mut self, node: &N) -> usize {
+		self(node)
+	}
+}
+
+/// The nodes an A* search has yet to look at, best estimate first.
+pub trait Frontier<C> {
+	fn push(&mut self, estimate: C, cost: C, id: usize);
+	/// The cost and id of a node with the lowest estimate.
+	fn pop(&mut self) -> Option<(C, usize)>;
+}
+
+/// A binary heap, for any costs. Among equal estimates, the longest path goes first.
+#[derive(Debug, Clone)]
+pub struct Heap<C> {
+	heap: BinaryHeap<(Reverse<C>, C, usize)>,
+}
+
+impl<C: Ord> Heap<C> {
+	pub fn new() -> Self {
+		Heap {
+			heap: BinaryHeap::new(),
+		}
+	}
+}
+
+impl<C: Ord> Default for Heap<C> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C: Ord> Frontier<C> for Heap<C> {
+	#[inline]
+	fn push(&mut self, estimate: C, cost: C, id: usize) {
+		self.heap.push((Reverse(estimate), cost, id));
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<(C, usize)> {
+		self.heap.pop().map(|(_, cost, id)| (cost, id))
+	}
+}
+
+/// A bucket queue with one bucket per estimate, for small `usize` costs. Estimates must
+/// never drop below the one popped last, which holds for consistent heuristics.
+#[derive(Debug, Clone, Default)]
+pub struct Buckets {
+	buckets: Vec<Vec<(usize, usize)>>,
+	lowest: usize,
+}
+
+impl Buckets {
+	pub const fn new() -> Self {
+		Buckets {
+			buckets: vec![],
+			lowest: 0,
+		}
+	}
+}
+
+impl Frontier<usize> for Buckets {
+	#[inline]
+	fn push(&mut self, estimate: usize, cost: usize, id: usize) {
+		debug_assert!(estimate >= self.lowest, "estimates never drop");
+		if estimate >= self.buckets.len() {
+			self.buckets.resize_with(estimate + 1, Vec::new);
+		}
+		self.buckets[estimate].push((cost, id));
+	}
+
+	#[inline]
+	fn pop(&mut self) -> Option<(usize, usize)> {
+		loop {
+			let bucket = self.buckets.get_mut(self.lowest)?;
+			if let Some(entry) = bucket.pop() {
+				return Some(entry);
+			}
+			self.lowest += 1;
+		}
+	}
+}
+
+/// What a search knows about a node: the node itself, how it got there and at what cost.
+struct Record<N, C> {
+	node: N,
+	parent: usize,
+	cost: C,
+	done: bool,
+}
+
+struct Records<N, C> {
+	records: Vec<Option<Record<N, C>>>,
+}
+
+impl<N: Clone, C> Records<N, C> {
+	fn new() -> Self {
+		Records { records: vec![] }
+	}
+
+	#[inline]
+	fn get(&self, id: usize) -> Option<&Record<N, C>> {
+		self.records.get(id).and_then(Option::as_ref)
+	}
+
+	#[inline]
+	fn set(&mut self, id: usize, record: Record<N, C>) {
+		if id >= self.records.len() {
+			self.records.resize_with(id + 1, || None);
+		}
+		self.records[id] = Some(record);
+	}
+
+	fn path_to(&self, mut id: usize) -> Vec<N> {
+		let mut path = vec![];
+		loop {
+			let record = self.get(id).expect("every parent has a record");
+			path.push(record.node.clone());
+			if record.parent == id {
+				break;
+			}
+			id = record.parent;
+		}
+		path.reverse();
+		path
+	}
+}
+
+/// The path with the fewest steps from `start` to a node that is a `success`.
+pub fn bfs<N, I>(
+	start: N,
+	neighbours: impl FnMut(&N) -> I,
+	success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+	N: Hash + Eq + Clone,
+	I: IntoIterator<Item = N>,
+{
+	bfs_with(Interned::new(), start, neighbours, success)
+}
+
+/// [`bfs`], telling nodes apart by `ids`.
+pub fn bfs_with<N, I>(
+	mut ids: impl NodeIds<N>,
+	start: N,
+	mut neighbours: impl FnMut(&N) -> I,
+	mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+	N: Clone,
+	I: IntoIterator<Item = N>,
+{
+	let mut records = Records::new();
+	let start_id = ids.id(&start);
+	records.set(
+		start_id,
+		Record {
+			node: start,
+			parent: start_id,
+			cost: (),
+			done: true,
+		},
+	);
+
+	let mut queue = VecDeque::from([start_id]);
+	while let Some(id) = queue.pop_front() {
+		let node = &records.get(id).expect("queued nodes have a record").node;
+		if success(node) {
+			return Some(records.path_to(id));
+		}
+
+		for next in neighbours(node) {
+			let next_id = ids.id(&next);
+			if records.get(next_id).is_none() {
+				records.set(
+					next_id,
+					Record {
+						node: next,
+						parent: id,
+						cost: (),
+						done: true,
+					},
+				);
+				queue.push_back(next_id);
+			}
+		}
+	}
+
+	None
+}
+
+/// The cheapest path from `start` to a node that is a `success`, and its cost.
+pub fn dijkstra<N, C, I>(
+	start: N,
+	neighbours: impl FnMut(&N) -> I,
+	success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+	N: Hash + Eq + Clone,
+	C: Cost,
+	I: IntoIterator<Item = (N, C)>,
+{
+	astar_with(
+		Interned::new(),
+		Heap::new(),
+		start,
+		neighbours,
+		|_| C::ZERO,
+		success,
+	)
+}
+
+/// The cheapest path from `start` to a node that is a `success`, and its cost, looking at
+/// the nodes that seem closest to a success first.
+///
+/// The `heuristic` must never overestimate the cost left, and must not drop by more than
+/// the cost of a step, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+	start: N,
+	neighbours: impl FnMut(&N) -> I,
+	heuristic: impl FnMut(&N) -> C,
+	success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+	N: Hash + Eq + Clone,
+	C: Cost,
+	I: IntoIterator<Item = (N, C)>,
+{
+	astar_with(
+		Interned::new(),
+		Heap::new(),
+		start,
+		neighbours,
+		heuristic,
+		success,
+	)
+}
+
+/// [`astar`], telling nodes apart by `ids` and keeping them in `frontier`. Dijkstra's
+/// algorithm is this with a heuristic of zero.
+pub fn astar_with<N, C, I>(
+	mut ids: impl NodeIds<N>,
+	mut frontier: impl Frontier<C>,
+	start: N,
+	mut neighbours: impl FnMut(&N) -> I,
+	mut heuristic: impl FnMut(&N) -> C,
+	mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+	N: Clone,
+	C: Cost,
+	I: IntoIterator<Item = (N, C)>,
+{
+	let mut records = Records::new();
+	let start_id = ids.id(&start);
+	frontier.push(heuristic(&start), C::ZERO, start_id);
+	records.set(
+		start_id,
+		Record {
+			node: start,
+			parent: start_id,
+			cost: C::ZERO,
+			done: false,
+		},
+	);
+
+	while let Some((cost, id)) = frontier.pop() {
+		let record = records.records[id]
+			.as_mut()
+			.expect("queued nodes have a record");
+		// the node may have been queued again since, at a lower cost
+		if record.done || cost > record.cost {
+			continue;
+		}
+		record.done = true;
+
+		let node = &record.node;
+		if success(node) {
+			return Some((records.path_to(id), cost));
+		}
+
+		for (next, step) in neighbours(node) {
+			let next_cost = cost + step;
+			let next_id = ids.id(&next);
+			if records
+				.get(next_id)
+				.is_some_and(|known| known.done || known.cost <= next_cost)
+			{
+				continue;
+			}
+			frontier.push(next_cost + heuristic(&next), next_cost, next_id);
+			records.set(
+				next_id,
+				Record {
+					node: next,
+					parent: id,
+					cost: next_cost,
+					done: false,
+				},
+			);
+		}
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MAZE: &[&[u8]] = &[
+		b"S..#....",
+		b".#.#.##.",
+		b".#...#..",
+		b".####.#.",
+		b"......#E",
+	];
+
+	fn maze_neighbours(&(x, y): &(usize, usize)) -> Vec<(usize, usize)> {
+		[
+			(x.wrapping_sub(1), y),
+			(x + 1, y),
+			(x, y.wrapping_sub(1)),
+			(x, y + 1),
+		]
+		.into_iter()
+		.filter(|&(x, y)| {
+			MAZE.get(y)
+				.and_then(|row| row.get(x))
+				.is_some_and(|&b| b != b'#')
+		})
+		.collect()
+	}
+
+	#[test]
+	fn finds_fewest_steps() {
+		let path = bfs((0, 0), maze_neighbours, |&node| node == (7, 4)).unwrap();
+		assert_eq!(path.len(), 16);
+		assert_eq!(path[0], (0, 0));
+		assert_eq!(path[15], (7, 4));
+		for pair in path.windows(2) {
+			assert!(maze_neighbours(&pair[0]).contains(&pair[1]));
+		}
+
+		let by_index = bfs_with(
+			|&(x, y): &(usize, usize)| y * 8 + x,
+			(0, 0),
+			maze_neighbours,
+			|&node| node == (7, 4),
+		);
+		assert_eq!(by_index.map(|path| path.len()), Some(16));
+
+		assert_eq!(
+			bfs((0, 0), maze_neighbours, |&node| node == (0, 0)),
+			Some(vec![(0, 0)])
+		);
+		assert_eq!(bfs((0, 0), maze_neighbours, |&node| node == (3, 0)), None);
+	}
+
+	/// Costs to step onto every cell.
+	const COSTS: [[u32; 5]; 4] = [
+		[1, 9, 1, 1, 1],
+		[1, 9, 1, 9, 1],
+		[1, 1, 1, 9, 1],
+		[9, 9, 9, 9, 1],
+	];
+
+	fn cost_neighbours(&(x, y): &(usize, usize)) -> Vec<((usize, usize), u32)> {
+		[
+			(x.wrapping_sub(1), y),
+			(x + 1, y),
+			(x, y.wrapping_sub(1)),
+			(x, y + 1),
+		]
+		.into_iter()
+		.filter_map(|(x, y)| Some(((x, y), *COSTS.get(y)?.get(x)?)))
+		.collect()
+	}
+
+	#[test]
+	fn finds_cheapest_paths() {
+		let end = (4, 3);
+		let (path, cost) = dijkstra((0, 0), cost_neighbours, |&node| node == end).unwrap();
+		assert_eq!(cost, 11);
+		assert_eq!(path.len(), 12);
+		assert_eq!(
+			path.iter().skip(1).map(|&(x, y)| COSTS[y][x]).sum::<u32>(),
+			cost
+		);
+
+		let distance =
+			|&(x, y): &(usize, usize)| x.abs_diff(end.0) as u32 + y.abs_diff(end.1) as u32;
+		assert_eq!(
+			astar((0, 0), cost_neighbours, distance, |&node| node == end),
+			Some((path.clone(), cost))
+		);
+
+		let with_buckets = astar_with(
+			|&(x, y): &(usize, usize)| y * 5 + x,
+			Buckets::new(),
+			(0, 0),
+			|node| {
+				cost_neighbours(node)
+					.into_iter()
+					.map(|(next, cost)| (next, cost as usize))
+			},
+			|node| distance(node) as usize,
+			|&node| node == end,
+		);
+		assert_eq!(with_buckets.map(|(_, cost)| cost), Some(11));
+
+		assert_eq!(dijkstra((0, 0), cost_neighbours, |_| false), None);
+	}
+}
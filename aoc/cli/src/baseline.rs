@@ -0,0 +1,535 @@
+//! Named baselines of benchmark times, to catch regressions across all years.
+//!
+//! `aoc bench save <name>` runs the benchmarks of every year and keeps their medians in
+//! `baselines/<machine>/<name>.json`. `aoc bench compare <name>` runs them again and lists
+//! every benchmark that got slower or faster than the threshold. Times only compare on
+//! the same hardware, so baselines are kept per machine: `$AOC_MACHINE`, or the host name.
+
+use crate::readme::{self, Measurement};
+use aoc_cli::format_duration;
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+	process::Command,
+	time::Duration,
+};
+
+const USAGE: &str = "\
+usage:
+	aoc bench save <name> [<year>]... [--no-run]
+	aoc bench compare <name> [<year>]... [--no-run] [--threshold <percent>]";
+
+/// Changes smaller than this many percent are taken for noise.
+const DEFAULT_THRESHOLD_PERCENT: u32 = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+	pub year: u16,
+	pub day: u8,
+	pub function: String,
+	pub input: String,
+	/// Median time of a single run, in nanoseconds.
+	pub median: f64,
+}
+
+impl Entry {
+	fn new(year: u16, measurement: Measurement) -> Self {
+		Entry {
+			year,
+			day: measurement.day,
+			function: measurement.function,
+			input: measurement.input,
+			median: measurement.median,
+		}
+	}
+
+	fn same_benchmark(&self, other: &Entry) -> bool {
+		self.year == other.year
+			&& self.day == other.day
+			&& self.function == other.function
+			&& self.input == other.input
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+	Save,
+	Compare { threshold_percent: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchArgs {
+	pub action: Action,
+	pub name: String,
+	/// Years to benchmark, all of them if empty.
+	pub years: Vec<u16>,
+	/// Use the latest criterion results instead of running the benchmarks first.
+	pub no_run: bool,
+}
+
+impl BenchArgs {
+	pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+		let action = match args.next().as_deref() {
+			Some("save") => Action::Save,
+			Some("compare") => Action::Compare {
+				threshold_percent: DEFAULT_THRESHOLD_PERCENT,
+			},
+			_ => return Err(USAGE.to_string()),
+		};
+		let name = args.next().ok_or(USAGE)?;
+		let valid_name = !name.is_empty()
+			&& name
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+			&& !name.starts_with('.');
+		if !valid_name {
+			return Err(format!("invalid baseline name `{name}`"));
+		}
+
+		let mut bench = BenchArgs {
+			action,
+			name,
+			years: vec![],
+			no_run: false,
+		};
+
+		while let Some(arg) = args.next() {
+			match (arg.as_str(), &mut bench.action) {
+				("--no-run", _) => bench.no_run = true,
+				("--threshold", Action::Compare { threshold_percent }) => {
+					let percent = args.next().ok_or("--threshold needs a percentage")?;
+					*threshold_percent = percent
+						.trim_end_matches('%')
+						.parse()
+						.map_err(|_| format!("invalid threshold `{percent}`"))?;
+				}
+				(year, _) => bench.years.push(
+					year.parse()
+						.map_err(|_| format!("unexpected argument `{year}`"))?,
+				),
+			}
+		}
+
+		Ok(bench)
+	}
+}
+
+/// The machine baselines are stored for.
+fn machine() -> String {
+	env::var("AOC_MACHINE")
+		.ok()
+		.or_else(|| {
+			let output = Command::new("hostname").output().ok()?;
+			String::from_utf8(output.stdout).ok()
+		})
+		.map(|name| name.trim().replace(['/', '\\'], "-"))
+		.filter(|name| !name.is_empty())
+		.unwrap_or_else(|| "unknown".to_string())
+}
+
+fn escape(text: &str) -> String {
+	text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Writes a baseline, one benchmark per line.
+pub fn to_json(machine: &str, entries: &[Entry]) -> String {
+	let benchmarks: Vec<String> = entries
+		.iter()
+		.map(|entry| {
+			format!(
+				"\t\t{{\"year\": {}, \"day\": {}, \"function\": \"{}\", \"input\": \"{}\", \"median\": {}}}",
+				entry.year,
+				entry.day,
+				escape(&entry.function),
+				escape(&entry.input),
+				entry.median
+			)
+		})
+		.collect();
+
+	format!(
+		"{{\n\t\"machine\": \"{}\",\n\t\"benchmarks\": [\n{}\n\t]\n}}\n",
+		escape(machine),
+		benchmarks.join(",\n")
+	)
+}
+
+/// Reads a baseline written by [`to_json`].
+pub fn from_json(json: &str) -> Vec<Entry> {
+	json.lines()
+		.map(|line| line.trim().trim_end_matches(','))
+		.filter(|line| line.starts_with('{') && line.ends_with('}'))
+		.filter_map(|benchmark| {
+			Some(Entry {
+				year: readme::json_number(benchmark, "year")? as u16,
+				day: readme::json_number(benchmark, "day")? as u8,
+				function: readme::json_string(benchmark, "function")?,
+				input: readme::json_string(benchmark, "input")?,
+				median: readme::json_number(benchmark, "median")?,
+			})
+		})
+		.collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+	pub year: u16,
+	pub day: u8,
+	pub benchmark: String,
+	pub before: f64,
+	pub after: f64,
+}
+
+impl Change {
+	/// Relative change of the time, in percent.
+	fn percent(&self) -> f64 {
+		(self.after / self.before - 1.0) * 100.0
+	}
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Comparison {
+	pub regressions: Vec<Change>,
+	pub improvements: Vec<Change>,
+	pub unchanged: usize,
+	/// Benchmarks of the current run which the baseline does not know about.
+	pub new: usize,
+	/// Benchmarks of the baseline which were not run this time.
+	pub missing: usize,
+}
+
+/// Sorts every benchmark of the current run into regressions, improvements and changes
+/// within `threshold_percent` in either direction.
+pub fn compare(baseline: &[Entry], current: &[Entry], threshold_percent: u32) -> Comparison {
+	let mut comparison = Comparison {
+		missing: baseline
+			.iter()
+			.filter(|before| !current.iter().any(|after| after.same_benchmark(before)))
+			.count(),
+		..Comparison::default()
+	};
+
+	for after in current {
+		let Some(before) = baseline.iter().find(|before| before.same_benchmark(after)) else {
+			comparison.new += 1;
+			continue;
+		};
+		let change = Change {
+			year: after.year,
+			day: after.day,
+			benchmark: format!("{}/{}", after.function, after.input),
+			before: before.median,
+			after: after.median,
+		};
+
+		let percent = change.percent();
+		if percent > threshold_percent as f64 {
+			comparison.regressions.push(change);
+		} else if percent < -(threshold_percent as f64) {
+			comparison.improvements.push(change);
+		} else {
+			comparison.unchanged += 1;
+		}
+	}
+
+	for changes in [&mut comparison.regressions, &mut comparison.improvements] {
+		changes.sort_by(|a, b| (a.year, a.day, &a.benchmark).cmp(&(b.year, b.day, &b.benchmark)));
+	}
+	comparison
+}
+
+fn time(nanos: f64) -> String {
+	format_duration(Duration::from_secs_f64(nanos / 1e9))
+}
+
+/// Renders changes as a table, one row per benchmark, ordered by year and day.
+pub fn render_changes(changes: &[Change]) -> Vec<String> {
+	let header = ["Day", "Benchmark", "Before", "After", "Change"].map(String::from);
+	let rows: Vec<[String; 5]> = changes
+		.iter()
+		.map(|change| {
+			[
+				format!("{} {:02}", change.year, change.day),
+				change.benchmark.clone(),
+				time(change.before),
+				time(change.after),
+				format!("{:+.1}%", change.percent()),
+			]
+		})
+		.collect();
+
+	// Day and benchmark are aligned left, the times right.
+	let widths: [usize; 5] = std::array::from_fn(|column| {
+		rows.iter()
+			.chain([&header])
+			.map(|row| row[column].chars().count())
+			.max()
+			.unwrap_or(0)
+	});
+	let separator = std::array::from_fn(|column| match column {
+		0 | 1 => format!(":{}", "-".repeat(widths[column] - 1)),
+		_ => format!("{}:", "-".repeat(widths[column] - 1)),
+	});
+	let line = |cells: &[String; 5]| {
+		let cells: Vec<String> = cells
+			.iter()
+			.zip(widths)
+			.enumerate()
+			.map(|(column, (cell, width))| match column {
+				0 | 1 => format!("{cell:<width$}"),
+				_ => format!("{cell:>width$}"),
+			})
+			.collect();
+		format!("| {} |", cells.join(" | "))
+	};
+
+	[&header, &separator]
+		.into_iter()
+		.chain(&rows)
+		.map(line)
+		.collect()
+}
+
+fn run_benches(root: &Path, year: u16) -> Result<(), String> {
+	let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+		.arg("bench")
+		.arg("--manifest-path")
+		.arg(root.join(year.to_string()).join("Cargo.toml"))
+		.arg("--workspace")
+		.status()
+		.map_err(|e| format!("could not start cargo: {e}"))?;
+
+	if status.success() {
+		Ok(())
+	} else {
+		Err(format!("benchmarks of {year} failed ({status})"))
+	}
+}
+
+fn path(root: &Path, machine: &str, name: &str) -> PathBuf {
+	root.join("baselines")
+		.join(machine)
+		.join(format!("{name}.json"))
+}
+
+/// Runs the benchmarks of `years` (unless told not to) and collects their latest results.
+fn current(root: &Path, years: &[u16], no_run: bool) -> Result<Vec<Entry>, String> {
+	let mut entries = vec![];
+	for &year in years {
+		if !no_run {
+			run_benches(root, year)?;
+		}
+		let criterion = root.join(year.to_string()).join("target/criterion");
+		entries.extend(
+			readme::measurements(&criterion)
+				.into_iter()
+				.map(|measurement| Entry::new(year, measurement)),
+		);
+	}
+
+	if entries.is_empty() {
+		return Err("no benchmark results found".to_string());
+	}
+	entries.sort_by(|a, b| {
+		(a.year, a.day, &a.function, &a.input).cmp(&(b.year, b.day, &b.function, &b.input))
+	});
+	Ok(entries)
+}
+
+pub fn bench(root: &Path, all_years: &[u16], args: &BenchArgs) -> Result<(), String> {
+	if let Some(year) = args.years.iter().find(|year| !all_years.contains(year)) {
+		return Err(format!("no runner for year {year}"));
+	}
+	let years = if args.years.is_empty() {
+		all_years
+	} else {
+		&args.years
+	};
+	let machine = machine();
+	let path = path(root, &machine, &args.name);
+
+	match args.action {
+		Action::Save => {
+			let entries = current(root, years, args.no_run)?;
+			if let Some(dir) = path.parent() {
+				fs::create_dir_all(dir)
+					.map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+			}
+			fs::write(&path, to_json(&machine, &entries))
+				.map_err(|e| format!("could not write {}: {e}", path.display()))?;
+			println!("saved {} benchmarks as {}", entries.len(), path.display());
+			Ok(())
+		}
+		Action::Compare { threshold_percent } => {
+			let json = fs::read_to_string(&path)
+				.map_err(|e| format!("could not read {}: {e}", path.display()))?;
+			let baseline: Vec<Entry> = from_json(&json)
+				.into_iter()
+				.filter(|entry| years.contains(&entry.year))
+				.collect();
+			let comparison = compare(
+				&baseline,
+				&current(root, years, args.no_run)?,
+				threshold_percent,
+			);
+
+			for (title, changes) in [
+				("Regressions", &comparison.regressions),
+				("Improvements", &comparison.improvements),
+			] {
+				if !changes.is_empty() {
+					println!("{title} (more than {threshold_percent}%):\n");
+					for line in render_changes(changes) {
+						println!("{line}");
+					}
+					println!();
+				}
+			}
+			println!(
+				"{} regressed, {} improved, {} unchanged, {} new, {} missing",
+				comparison.regressions.len(),
+				comparison.improvements.len(),
+				comparison.unchanged,
+				comparison.new,
+				comparison.missing
+			);
+
+			if comparison.regressions.is_empty() {
+				Ok(())
+			} else {
+				Err(format!(
+					"regressed against baseline `{}`: {} of {} benchmarks",
+					args.name,
+					comparison.regressions.len(),
+					comparison.regressions.len()
+						+ comparison.improvements.len()
+						+ comparison.unchanged
+				))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn entry(year: u16, day: u8, input: &str, median: f64) -> Entry {
+		Entry {
+			year,
+			day,
+			function: "solve".to_string(),
+			input: input.to_string(),
+			median,
+		}
+	}
+
+	fn args(args: &[&str]) -> Result<BenchArgs, String> {
+		BenchArgs::parse(args.iter().map(|arg| arg.to_string()))
+	}
+
+	#[test]
+	fn parses_args() {
+		assert_eq!(
+			args(&["compare", "m1", "2023", "--threshold", "10%", "--no-run"]),
+			Ok(BenchArgs {
+				action: Action::Compare {
+					threshold_percent: 10
+				},
+				name: "m1".to_string(),
+				years: vec![2023],
+				no_run: true,
+			})
+		);
+		assert_eq!(
+			args(&["save", "before-range-set"]).map(|bench| bench.action),
+			Ok(Action::Save)
+		);
+	}
+
+	#[rstest]
+	#[case(&[])]
+	#[case(&["load", "m1"])]
+	#[case(&["save", "../m1"])]
+	#[case(&["save", "m1", "--threshold", "10"])]
+	#[case(&["compare", "m1", "--threshold", "lots"])]
+	#[case(&["save", "m1", "twenty"])]
+	fn rejects_invalid_args(#[case] invalid: &[&str]) {
+		assert!(args(invalid).is_err());
+	}
+
+	#[test]
+	fn round_trips_json() {
+		let entries = [
+			entry(2023, 5, "personal", 17_900.0),
+			Entry {
+				function: "raycast \"fast\"".to_string(),
+				..entry(2023, 10, "example_1", 0.25)
+			},
+			entry(2025, 8, "1000", 5.5e6),
+		];
+
+		let json = to_json("m1", &entries);
+		assert!(
+			json.starts_with(
+				"{\n\t\"machine\": \"m1\",\n\t\"benchmarks\": [\n\t\t{\"year\": 2023,"
+			)
+		);
+		assert_eq!(from_json(&json), entries);
+	}
+
+	#[test]
+	fn compares_against_threshold() {
+		let baseline = [
+			entry(2023, 5, "personal", 100.0),
+			entry(2023, 6, "personal", 100.0),
+			entry(2025, 4, "personal", 100.0),
+			entry(2025, 5, "personal", 100.0),
+		];
+		let current = [
+			entry(2023, 5, "personal", 104.0),
+			entry(2023, 6, "personal", 80.0),
+			entry(2025, 4, "personal", 130.0),
+			entry(2025, 8, "personal", 100.0),
+		];
+
+		let comparison = compare(&baseline, &current, 5);
+		assert_eq!(
+			comparison.regressions,
+			[Change {
+				year: 2025,
+				day: 4,
+				benchmark: "solve/personal".to_string(),
+				before: 100.0,
+				after: 130.0,
+			}]
+		);
+		assert_eq!(comparison.improvements.len(), 1);
+		assert_eq!(comparison.improvements[0].day, 6);
+		assert_eq!(
+			(comparison.unchanged, comparison.new, comparison.missing),
+			(1, 1, 1)
+		);
+	}
+
+	#[test]
+	fn renders_changes() {
+		let changes = [Change {
+			year: 2025,
+			day: 4,
+			benchmark: "solve/personal".to_string(),
+			before: 1_200_000.0,
+			after: 1_500_000.0,
+		}];
+
+		assert_eq!(
+			render_changes(&changes),
+			[
+				"| Day     | Benchmark      | Before |  After | Change |",
+				"| :------ | :------------- | -----: | -----: | -----: |",
+				"| 2025 04 | solve/personal | 1.2 ms | 1.5 ms | +25.0% |",
+			]
+		);
+	}
+}
@@ -0,0 +1,154 @@
+use aoc_solution::Answer;
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+pub type RunResult = Result<[Answer; 2], Box<dyn Error>>;
+
+/// A single solver as seen by a year runner.
+///
+/// The `run` function adapts the day's own `solve` signature (string or byte
+/// input, extra parameters, const generics, fallible results) to one shape.
+pub struct Day {
+	pub day: u8,
+	pub name: &'static str,
+	/// Default input file, relative to the year's workspace root.
+	pub input: &'static str,
+	/// Extra solver parameters with their values for the default input.
+	pub params: &'static [(&'static str, &'static str)],
+	pub run: fn(&[u8], &Params) -> RunResult,
+}
+
+impl Day {
+	pub const fn new(
+		day: u8,
+		name: &'static str,
+		input: &'static str,
+		run: fn(&[u8], &Params) -> RunResult,
+	) -> Self {
+		Day {
+			day,
+			name,
+			input,
+			params: &[],
+			run,
+		}
+	}
+
+	pub const fn with_params(self, params: &'static [(&'static str, &'static str)]) -> Self {
+		Day { params, ..self }
+	}
+
+	/// Path of a named input, relative to the year's workspace root.
+	///
+	/// `personal` is the default input. Other names are looked up next to it, as
+	/// `inputs/<name>.txt` or, for years keeping inputs as `assets/08.txt`, as
+	/// `assets/08_<name>.txt`.
+	pub fn input_path(&self, name: &str) -> PathBuf {
+		let default = PathBuf::from(self.input);
+		if name == "personal" {
+			return default;
+		}
+
+		match default.file_stem().and_then(|stem| stem.to_str()) {
+			Some("personal") | None => default.with_file_name(format!("{name}.txt")),
+			Some(stem) => default.with_file_name(format!("{stem}_{name}.txt")),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Params {
+	values: Vec<(&'static str, String)>,
+}
+
+impl Params {
+	/// Starts out with the day's defaults and applies `key=value` overrides.
+	pub fn new(day: &Day, overrides: &[String]) -> Result<Self, String> {
+		let mut values: Vec<_> = day
+			.params
+			.iter()
+			.map(|&(key, value)| (key, value.to_string()))
+			.collect();
+
+		for assignment in overrides {
+			let (key, value) = assignment
+				.split_once('=')
+				.ok_or_else(|| format!("expected key=value, got `{assignment}`"))?;
+			let slot = values
+				.iter_mut()
+				.find(|(known, _)| *known == key)
+				.ok_or_else(|| format!("day {:02} has no parameter `{key}`", day.day))?;
+			slot.1 = value.to_string();
+		}
+
+		Ok(Params { values })
+	}
+
+	pub fn get<T>(&self, key: &str) -> Result<T, Box<dyn Error>>
+	where
+		T: FromStr,
+		T::Err: Error + 'static,
+	{
+		let (_, value) = self
+			.values
+			.iter()
+			.find(|(known, _)| *known == key)
+			.ok_or_else(|| format!("missing parameter `{key}`"))?;
+
+		Ok(value.parse()?)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.values
+			.iter()
+			.map(|(key, value)| (*key, value.as_str()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DAY: Day = Day::new(8, "Playground", "", |_, _| {
+		Ok([Answer::Missing, Answer::Missing])
+	})
+	.with_params(&[("first_n", "1000")]);
+
+	#[test]
+	fn input_paths_sit_next_to_the_default() {
+		assert_eq!(DAY.input_path("personal"), PathBuf::from(""));
+
+		let day = Day::new(8, "", "days/08-playground/inputs/personal.txt", DAY.run);
+		assert_eq!(
+			day.input_path("personal"),
+			PathBuf::from("days/08-playground/inputs/personal.txt")
+		);
+		assert_eq!(
+			day.input_path("example_2"),
+			PathBuf::from("days/08-playground/inputs/example_2.txt")
+		);
+
+		let day = Day::new(8, "", "assets/08.txt", DAY.run);
+		assert_eq!(
+			day.input_path("sample"),
+			PathBuf::from("assets/08_sample.txt")
+		);
+	}
+
+	#[test]
+	fn params_use_defaults() {
+		let params = Params::new(&DAY, &[]).unwrap();
+		assert_eq!(params.get::<usize>("first_n").unwrap(), 1000);
+	}
+
+	#[test]
+	fn params_apply_overrides() {
+		let params = Params::new(&DAY, &["first_n=10".to_string()]).unwrap();
+		assert_eq!(params.get::<usize>("first_n").unwrap(), 10);
+	}
+
+	#[test]
+	fn params_reject_unknown_keys() {
+		assert!(Params::new(&DAY, &["nope=10".to_string()]).is_err());
+		assert!(Params::new(&DAY, &["first_n".to_string()]).is_err());
+	}
+}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+const UNITS: [&str; 4] = ["ns", "μs", "ms", "s"];
+
+/// Formats a duration the way the README tables do, e.g. `20.9 ms` or `161 ns`.
+pub fn format_duration(duration: Duration) -> String {
+	let mut value = duration.as_nanos() as f64;
+	let mut unit = 0;
+
+	while value >= 1000.0 && unit < UNITS.len() - 1 {
+		value /= 1000.0;
+		unit += 1;
+	}
+
+	if unit == 0 || value >= 100.0 {
+		format!("{value:.0} {}", UNITS[unit])
+	} else {
+		format!("{value:.1} {}", UNITS[unit])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(Duration::from_nanos(161), "161 ns")]
+	#[case(Duration::from_nanos(22_600), "22.6 μs")]
+	#[case(Duration::from_micros(210), "210 μs")]
+	#[case(Duration::from_micros(20_900), "20.9 ms")]
+	#[case(Duration::from_millis(4_500), "4.5 s")]
+	#[case(Duration::from_secs(1_200), "1200 s")]
+	fn formats_like_readme(#[case] duration: Duration, #[case] expected: &str) {
+		assert_eq!(format_duration(duration), expected);
+	}
+}
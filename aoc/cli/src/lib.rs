@@ -0,0 +1,211 @@
+mod day;
+mod duration;
+mod registry;
+mod verify;
+
+pub use aoc_solution::{Answer, Solution};
+pub use day::{Day, Params, RunResult};
+pub use duration::format_duration;
+
+use std::{borrow::Cow, fs, path::PathBuf, process::ExitCode, time::Instant};
+
+const USAGE: &str = "\
+usage:
+	list
+	run [<day>] [--input <path>] [--param <key>=<value>]... [--allocations]
+	verify [<day>]";
+
+/// Runs the given solver and describes what it measured.
+pub type Measure = fn(&mut dyn FnMut()) -> String;
+
+/// Turns an input file into what the solvers expect, copying it only if needed.
+pub type Prepare = for<'a> fn(&'a [u8]) -> Cow<'a, [u8]>;
+
+/// Optional hooks of a year runner around its solvers, see [`main_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Harness {
+	/// Runs a solver and describes its heap allocations, which `run --allocations` prints
+	/// after the time it took. Only works with a counting `#[global_allocator]` in the
+	/// runner.
+	pub count_allocations: Option<Measure>,
+	/// Applied to every input file before it is solved, by `run` as well as `verify`.
+	/// E.g. to undo `\r\n` line endings, which the solvers don't handle themselves.
+	pub prepare_input: Option<Prepare>,
+}
+
+impl Harness {
+	fn prepare<'a>(&self, input: &'a [u8]) -> Cow<'a, [u8]> {
+		match self.prepare_input {
+			Some(prepare) => prepare(input),
+			None => Cow::Borrowed(input),
+		}
+	}
+}
+
+/// Wraps a day's `Solution` as the result of a [`Day::run`] function.
+pub fn answers(solution: impl Solution) -> RunResult {
+	Ok(solution.into_answers())
+}
+
+struct RunArgs {
+	day: Option<u8>,
+	input: Option<PathBuf>,
+	params: Vec<String>,
+	allocations: bool,
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+	let mut run = RunArgs {
+		day: None,
+		input: None,
+		params: vec![],
+		allocations: false,
+	};
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--input" | "-i" => {
+				let path = args.next().ok_or("--input needs a path")?;
+				run.input = Some(path.into());
+			}
+			"--param" | "-p" => {
+				let param = args.next().ok_or("--param needs a key=value pair")?;
+				run.params.push(param);
+			}
+			"--allocations" | "-a" => run.allocations = true,
+			day if run.day.is_none() => {
+				run.day = Some(day.parse().map_err(|_| format!("invalid day `{day}`"))?);
+			}
+			other => return Err(format!("unexpected argument `{other}`")),
+		}
+	}
+
+	if run.day.is_none() && (run.input.is_some() || !run.params.is_empty()) {
+		return Err("--input and --param need a specific day".to_string());
+	}
+
+	Ok(run)
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<Option<u8>, String> {
+	let day = args
+		.next()
+		.map(|day| day.parse().map_err(|_| format!("invalid day `{day}`")))
+		.transpose()?;
+
+	match args.next() {
+		Some(other) => Err(format!("unexpected argument `{other}`")),
+		None => Ok(day),
+	}
+}
+
+fn run_day(
+	year: u16,
+	root: &str,
+	day: &Day,
+	args: &RunArgs,
+	harness: Harness,
+) -> Result<(), String> {
+	let path = args
+		.input
+		.clone()
+		.unwrap_or_else(|| [root, day.input].iter().collect());
+	let input = fs::read(&path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+	let input = harness.prepare(&input);
+	let params = Params::new(day, &args.params)?;
+	let count_allocations = match harness.count_allocations {
+		_ if !args.allocations => None,
+		Some(count) => Some(count),
+		None => return Err(format!("the {year} runner does not count allocations")),
+	};
+
+	println!("{year} day {:02}: {}", day.day, day.name);
+	for (key, value) in params.iter() {
+		println!("  {key} = {value}");
+	}
+
+	// timed inside, leaving out the setup of counting allocations around it
+	let mut answers = None;
+	let mut elapsed = Default::default();
+	let mut solve = || {
+		let start = Instant::now();
+		answers = Some((day.run)(&input, &params));
+		elapsed = start.elapsed();
+	};
+	let allocations = match count_allocations {
+		Some(count) => Some(count(&mut solve)),
+		None => {
+			solve();
+			None
+		}
+	};
+	let answers = answers
+		.expect("the solver has run")
+		.map_err(|e| e.to_string())?;
+
+	for (part, answer) in answers.iter().enumerate() {
+		match answer {
+			Answer::Text(text) if text.contains('\n') => {
+				println!("  part {}:\n{}", part + 1, text.trim_end());
+			}
+			answer => println!("  part {}: {answer}", part + 1),
+		}
+	}
+	println!("  took {}", format_duration(elapsed));
+	if let Some(allocations) = allocations {
+		println!("  {allocations}");
+	}
+
+	Ok(())
+}
+
+fn run(year: u16, root: &str, days: &[Day], args: RunArgs, harness: Harness) -> Result<(), String> {
+	match args.day {
+		Some(number) => {
+			let day = days
+				.iter()
+				.find(|day| day.day == number)
+				.ok_or_else(|| format!("no solver for {year} day {number:02}"))?;
+			run_day(year, root, day, &args, harness)
+		}
+		None => days
+			.iter()
+			.try_for_each(|day| run_day(year, root, day, &args, harness)),
+	}
+}
+
+/// Entry point of the per-year runner binaries.
+///
+/// `root` is the year's workspace directory, which [`Day::input`] paths are relative to.
+pub fn main(year: u16, root: &str, days: &[Day]) -> ExitCode {
+	main_with(year, root, days, Harness::default())
+}
+
+/// [`main`] with hooks around the solvers, for runners that can report more than times.
+pub fn main_with(year: u16, root: &str, days: &[Day], harness: Harness) -> ExitCode {
+	let mut args = std::env::args().skip(1);
+
+	let result = match args.next().as_deref() {
+		Some("list") => {
+			for day in days {
+				println!("{year} day {:02}: {}", day.day, day.name);
+			}
+			Ok(())
+		}
+		Some("run") => {
+			parse_run_args(args).and_then(|run_args| run(year, root, days, run_args, harness))
+		}
+		Some("verify") => {
+			parse_verify_args(args).and_then(|day| verify::verify(year, root, days, day, harness))
+		}
+		_ => Err(USAGE.to_string()),
+	};
+
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(message) => {
+			eprintln!("{message}");
+			ExitCode::FAILURE
+		}
+	}
+}
@@ -0,0 +1,159 @@
+//! Repository-wide entry point.
+//!
+//! Every year is its own cargo workspace (with its own copy of `aoc-utils`), so the
+//! solvers cannot be linked into a single binary. Instead each year has an
+//! `aoc-runner-<year>` package, and this binary forwards to it through `cargo run`.
+
+mod baseline;
+mod new_day;
+mod readme;
+
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+	process::{Command, ExitCode},
+};
+
+const USAGE: &str = "\
+usage:
+	aoc list [<year>]
+	aoc run <year> [<day>] [--input <path>] [--param <key>=<value>]... [--allocations]
+	aoc verify [<year> [<day>]]
+	aoc readme [<year>]
+	aoc bench (save | compare) <name> [<year>]... [--no-run] [--threshold <percent>]
+	aoc new-day <year> <day> <slug>";
+
+fn repo_root() -> PathBuf {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+	root.canonicalize().unwrap_or(root)
+}
+
+fn years(root: &Path) -> Vec<u16> {
+	let mut years: Vec<u16> = fs::read_dir(root)
+		.into_iter()
+		.flatten()
+		.flatten()
+		.filter(|entry| entry.path().join("runner/Cargo.toml").is_file())
+		.filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+		.collect();
+	years.sort_unstable();
+	years
+}
+
+fn year_runner(root: &Path, year: u16, args: &[String]) -> Result<(), String> {
+	let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+		.arg("run")
+		.arg("--release")
+		.arg("--quiet")
+		.arg("--manifest-path")
+		.arg(root.join(year.to_string()).join("Cargo.toml"))
+		.arg("--package")
+		.arg(format!("aoc-runner-{year}"))
+		.arg("--")
+		.args(args)
+		.status()
+		.map_err(|e| format!("could not start cargo: {e}"))?;
+
+	if status.success() {
+		Ok(())
+	} else {
+		Err(format!("runner for {year} failed ({status})"))
+	}
+}
+
+fn parse_year(root: &Path, year: Option<String>) -> Result<u16, String> {
+	let year = year.ok_or(USAGE)?;
+	let year = year.parse().map_err(|_| format!("invalid year `{year}`"))?;
+
+	if years(root).contains(&year) {
+		Ok(year)
+	} else {
+		Err(format!("no runner for year {year}"))
+	}
+}
+
+/// Updates the README's benchmark table of `year` from its latest `cargo bench` run.
+fn update_readme(root: &Path, year: u16, required: bool) -> Result<(), String> {
+	let criterion = root.join(year.to_string()).join("target/criterion");
+	let measurements = readme::measurements(&criterion);
+	if measurements.is_empty() {
+		let message = format!("no benchmark results in {}", criterion.display());
+		if required {
+			return Err(message);
+		}
+		println!("{message}, skipping {year}");
+		return Ok(());
+	}
+
+	let path = root.join("README.md");
+	let text =
+		fs::read_to_string(&path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+	fs::write(&path, readme::update_readme(&text, year, &measurements))
+		.map_err(|e| format!("could not write {}: {e}", path.display()))?;
+
+	let mut days: Vec<u8> = measurements.iter().map(|m| m.day).collect();
+	days.sort_unstable();
+	days.dedup();
+	println!("updated {} days of {year}", days.len());
+	Ok(())
+}
+
+fn main() -> ExitCode {
+	let root = repo_root();
+	let mut args = env::args().skip(1);
+
+	let result = match args.next().as_deref() {
+		Some("list") => match args.next() {
+			Some(year) => parse_year(&root, Some(year))
+				.and_then(|year| year_runner(&root, year, &["list".to_string()])),
+			None => years(&root)
+				.into_iter()
+				.try_for_each(|year| year_runner(&root, year, &["list".to_string()])),
+		},
+		Some("run") => parse_year(&root, args.next()).and_then(|year| {
+			let forwarded: Vec<String> = ["run".to_string()].into_iter().chain(args).collect();
+			year_runner(&root, year, &forwarded)
+		}),
+		Some("verify") => match args.next() {
+			Some(year) => parse_year(&root, Some(year)).and_then(|year| {
+				let forwarded: Vec<String> =
+					["verify".to_string()].into_iter().chain(args).collect();
+				year_runner(&root, year, &forwarded)
+			}),
+			None => {
+				// Keep going after a failing year, so the whole repository gets checked.
+				let failed: Vec<String> = years(&root)
+					.into_iter()
+					.filter_map(|year| year_runner(&root, year, &["verify".to_string()]).err())
+					.collect();
+				if failed.is_empty() {
+					Ok(())
+				} else {
+					Err(failed.join("\n"))
+				}
+			}
+		},
+		Some("readme") => match args.next() {
+			Some(year) => {
+				parse_year(&root, Some(year)).and_then(|year| update_readme(&root, year, true))
+			}
+			None => years(&root)
+				.into_iter()
+				.try_for_each(|year| update_readme(&root, year, false)),
+		},
+		Some("bench") => baseline::BenchArgs::parse(args)
+			.and_then(|bench| baseline::bench(&root, &years(&root), &bench)),
+		Some("new-day") => {
+			new_day::NewDay::parse(args).and_then(|new| new_day::new_day(&root, &new))
+		}
+		_ => Err(USAGE.to_string()),
+	};
+
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(message) => {
+			eprintln!("{message}");
+			ExitCode::FAILURE
+		}
+	}
+}
@@ -0,0 +1,461 @@
+//! Scaffolds a new day from the template of its year's layout.
+//!
+//! 2022 days are instantiated from `2022/template` as `aoc-2022-NN`, later years from
+//! `2023/templates/byte-input` as `day-NN-<slug>`. Besides the crate itself, the day gets
+//! registered with the year's runner, its fuzz crate if it has one, and a row in the
+//! README's benchmark table.
+
+use crate::readme::{self, Row};
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+pub struct NewDay {
+	pub year: u16,
+	pub day: u8,
+	pub slug: String,
+}
+
+impl NewDay {
+	pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+		let (Some(year), Some(day), Some(slug), None) =
+			(args.next(), args.next(), args.next(), args.next())
+		else {
+			return Err("usage: aoc new-day <year> <day> <slug>".to_string());
+		};
+
+		let year = year.parse().map_err(|_| format!("invalid year `{year}`"))?;
+		let day = day
+			.parse()
+			.ok()
+			.filter(|day| (1..=25).contains(day))
+			.ok_or_else(|| format!("invalid day `{day}`"))?;
+		let valid_slug = !slug.is_empty()
+			&& !slug.starts_with('-')
+			&& !slug.ends_with('-')
+			&& slug
+				.chars()
+				.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+		if !valid_slug {
+			return Err(format!(
+				"invalid slug `{slug}`, expected something like `secret-entrance`"
+			));
+		}
+
+		Ok(NewDay { year, day, slug })
+	}
+
+	fn dir_name(&self) -> String {
+		format!("{:02}-{}", self.day, self.slug)
+	}
+
+	fn package(&self) -> String {
+		match self.year {
+			2022 => format!("aoc-2022-{:02}", self.day),
+			_ => format!("day-{}", self.dir_name()),
+		}
+	}
+
+	fn crate_name(&self) -> String {
+		self.package().replace('-', "_")
+	}
+
+	fn name(&self) -> String {
+		readme::title_case(&self.slug)
+	}
+}
+
+fn template(root: &Path, year: u16) -> Result<PathBuf, String> {
+	match year {
+		2022 => Ok(root.join("2022/template")),
+		2023.. => Ok(root.join("2023/templates/byte-input")),
+		_ => Err(format!("{year} has no day template")),
+	}
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+	fs::create_dir_all(to)?;
+	for entry in fs::read_dir(from)? {
+		let entry = entry?;
+		let target = to.join(entry.file_name());
+		if entry.file_type()?.is_dir() {
+			if entry.file_name() != "target" {
+				copy_dir(&entry.path(), &target)?;
+			}
+		} else {
+			fs::copy(entry.path(), target)?;
+		}
+	}
+	Ok(())
+}
+
+fn fill_manifest(manifest: &str, new: &NewDay) -> String {
+	manifest
+		.replace(
+			"name = \"day-00\"",
+			&format!("name = \"{}\"", new.package()),
+		)
+		.replace("aoc-2022-xx", &new.package())
+}
+
+fn fill_bench(bench: &str, new: &NewDay) -> String {
+	let bench = bench.replace("aoc_2022_xx", &new.crate_name()).replace(
+		"const NAME: &str = todo!();",
+		&format!("const NAME: &str = \"day_{:02}\";", new.day),
+	);
+	if bench.contains(&format!("{}::solve", new.crate_name())) {
+		return bench;
+	}
+
+	let mut lines: Vec<String> = bench.lines().map(String::from).collect();
+	let after_imports = lines
+		.iter()
+		.position(|line| line.starts_with("use criterion"))
+		.map_or(0, |i| i + 1);
+	lines.insert(after_imports, format!("use {}::solve;", new.crate_name()));
+	lines.join("\n") + "\n"
+}
+
+/// Points the template's test case at the example. Answers for the personal input are
+/// only registered in `answers.txt`.
+fn fill_lib(lib: &str) -> String {
+	lib.replace(
+		"#[case(todo!()",
+		"#[case(include_bytes!(\"../inputs/example.txt\")",
+	)
+}
+
+/// Inserts a dependency on the new day before the first one on a later day.
+fn register_dependency(manifest: &str, new: &NewDay) -> String {
+	let listed_day = |line: &str| -> Option<u8> {
+		let (_, rest) = line.split_once("path = \"../days/")?;
+		rest.get(..2)?.parse().ok()
+	};
+
+	let lines: Vec<&str> = manifest.lines().collect();
+	let position = lines
+		.iter()
+		.position(|line| listed_day(line).is_some_and(|day| day > new.day))
+		.or_else(|| Some(lines.iter().rposition(|line| listed_day(line).is_some())? + 1))
+		.unwrap_or(lines.len());
+
+	let entry = format!(
+		"{} = {{ path = \"../days/{}\" }}",
+		new.package(),
+		new.dir_name()
+	);
+	let mut result = lines[..position].to_vec();
+	result.push(&entry);
+	result.extend(&lines[position..]);
+	result.join("\n") + "\n"
+}
+
+/// Adds the new day to the runner's `DAYS`, before the first entry of a later day.
+///
+/// Entries are found by text rather than by line, as rustfmt lays them out differently
+/// depending on their length; the result is meant to be run through rustfmt again.
+fn register_runner_day(main: &str, new: &NewDay) -> Result<String, String> {
+	let start = main
+		.find("const DAYS: &[Day] = &[")
+		.ok_or("runner has no `DAYS` list")?;
+	let end = start
+		+ main[start..]
+			.find("];")
+			.ok_or("runner has no `DAYS` list")?;
+
+	let entry = format!(
+		"Day::new({}, \"{}\", \"days/{}/inputs/personal.txt\", |input, _| {{\n\t\taoc_cli::answers({}::solve(input))\n\t}}),",
+		new.day,
+		new.name(),
+		new.dir_name(),
+		new.crate_name()
+	);
+
+	let later = main[start..end]
+		.match_indices("Day::new(")
+		.find(|(i, call)| {
+			let args = main[start + i + call.len()..].trim_start();
+			let digits = args.find(|c: char| !c.is_ascii_digit()).unwrap_or(0);
+			args[..digits].parse::<u8>().is_ok_and(|day| day > new.day)
+		});
+
+	Ok(match later {
+		Some((i, _)) => format!("{}{entry}\n\t{}", &main[..start + i], &main[start + i..]),
+		None => {
+			let items = main[..end].trim_end();
+			let separator = if items.ends_with(',') || items.ends_with('[') {
+				""
+			} else {
+				","
+			};
+			format!("{items}{separator}\n\t{entry}\n{}", &main[end..])
+		}
+	})
+}
+
+/// A fuzz target for the new day. The alphabet is only a guess until the input format
+/// is known.
+fn fuzz_target(new: &NewDay) -> String {
+	format!(
+		"#![no_main]
+
+use aoc_fuzz::Input;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: Input| {{
+	aoc_fuzz::check(|| {}::solve(&input.bytes(b\"0123456789 ,\\n\")));
+}});
+",
+		new.crate_name()
+	)
+}
+
+fn register_fuzz_target(manifest: &str, new: &NewDay) -> String {
+	format!(
+		"{}
+[[bin]]
+name = \"day_{:02}\"
+path = \"fuzz_targets/day_{:02}.rs\"
+test = false
+doc = false
+bench = false
+",
+		register_dependency(manifest, new),
+		new.day,
+		new.day
+	)
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> String) -> Result<(), String> {
+	let text =
+		fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+	fs::write(path, change(&text)).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+pub fn new_day(root: &Path, new: &NewDay) -> Result<(), String> {
+	let workspace = root.join(new.year.to_string());
+	let runner = workspace.join("runner");
+	if !runner.join("Cargo.toml").is_file() {
+		return Err(format!("no runner for year {}", new.year));
+	}
+	let template = template(root, new.year)?;
+
+	let days = workspace.join("days");
+	if let Some(existing) = fs::read_dir(&days)
+		.into_iter()
+		.flatten()
+		.flatten()
+		.find(|entry| {
+			entry
+				.file_name()
+				.to_str()
+				.is_some_and(|name| name.starts_with(&format!("{:02}-", new.day)))
+		}) {
+		return Err(format!("{} already exists", existing.path().display()));
+	}
+
+	let dir = days.join(new.dir_name());
+	copy_dir(&template, &dir).map_err(|e| format!("could not copy template: {e}"))?;
+	for input in ["example", "personal"] {
+		let path = dir.join("inputs").join(format!("{input}.txt"));
+		fs::write(&path, "").map_err(|e| format!("could not write {}: {e}", path.display()))?;
+	}
+
+	edit(&dir.join("Cargo.toml"), |text| fill_manifest(text, new))?;
+	edit(&dir.join("src/lib.rs"), fill_lib)?;
+	for entry in fs::read_dir(dir.join("benches"))
+		.into_iter()
+		.flatten()
+		.flatten()
+	{
+		edit(&entry.path(), |text| fill_bench(text, new))?;
+	}
+
+	edit(&runner.join("Cargo.toml"), |text| {
+		register_dependency(text, new)
+	})?;
+	let main = runner.join("src/main.rs");
+	let text =
+		fs::read_to_string(&main).map_err(|e| format!("could not read {}: {e}", main.display()))?;
+	fs::write(&main, register_runner_day(&text, new)?)
+		.map_err(|e| format!("could not write {}: {e}", main.display()))?;
+
+	let fuzz = workspace.join("fuzz");
+	if fuzz.join("Cargo.toml").is_file() {
+		edit(&fuzz.join("Cargo.toml"), |text| {
+			register_fuzz_target(text, new)
+		})?;
+		let target = fuzz.join(format!("fuzz_targets/day_{:02}.rs", new.day));
+		fs::write(&target, fuzz_target(new))
+			.map_err(|e| format!("could not write {}: {e}", target.display()))?;
+	}
+	edit(&root.join("README.md"), |text| {
+		readme::update_table(text, new.year, |rows| {
+			if !rows.iter().any(|row| row.day == new.day) {
+				rows.push(Row {
+					day: new.day,
+					name: new.name(),
+					time: String::new(),
+					feeling: String::new(),
+				});
+				rows.sort_by_key(|row| row.day);
+			}
+		})
+	})?;
+
+	// The template is written for one edition; let the year's rustfmt settings win.
+	let formatted = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+		.current_dir(&workspace)
+		.args(["fmt", "--package", &new.package(), "--package"])
+		.arg(format!("aoc-runner-{}", new.year))
+		.status()
+		.is_ok_and(|status| status.success());
+	if !formatted {
+		eprintln!("could not run cargo fmt, the new files may need formatting");
+	}
+
+	println!("created {}", dir.display());
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	fn new_day(year: u16, day: u8, slug: &str) -> NewDay {
+		NewDay {
+			year,
+			day,
+			slug: slug.to_string(),
+		}
+	}
+
+	#[rstest]
+	#[case(&["2025", "9", "movie-theater"], Ok((2025, 9, "movie-theater")))]
+	#[case(&["2025", "26", "movie-theater"], Err("invalid day `26`"))]
+	#[case(&["2025", "9", "Movie Theater"], Err("invalid slug `Movie Theater`, expected something like `secret-entrance`"))]
+	#[case(&["2025", "9"], Err("usage: aoc new-day <year> <day> <slug>"))]
+	fn parses_arguments(#[case] args: &[&str], #[case] expected: Result<(u16, u8, &str), &str>) {
+		let parsed = NewDay::parse(args.iter().map(|arg| arg.to_string()));
+		assert_eq!(
+			parsed.map(|new| (new.year, new.day, new.slug)),
+			expected
+				.map(|(year, day, slug)| (year, day, slug.to_string()))
+				.map_err(String::from)
+		);
+	}
+
+	#[test]
+	fn names_follow_the_year_layout() {
+		let new = new_day(2025, 9, "movie-theater");
+		assert_eq!(new.package(), "day-09-movie-theater");
+		assert_eq!(new.crate_name(), "day_09_movie_theater");
+		assert_eq!(new.name(), "Movie Theater");
+
+		let new = new_day(2022, 16, "proboscidea-volcanium");
+		assert_eq!(new.package(), "aoc-2022-16");
+		assert_eq!(new.crate_name(), "aoc_2022_16");
+	}
+
+	#[test]
+	fn fills_templates() {
+		let new = new_day(2023, 8, "haunted-wasteland");
+		assert_eq!(
+			fill_manifest("[package]\nname = \"day-00\"\n", &new),
+			"[package]\nname = \"day-08-haunted-wasteland\"\n"
+		);
+		assert_eq!(
+			fill_bench(
+				"use criterion::{criterion_group, criterion_main};\nuse std::hint::black_box;\n",
+				&new
+			),
+			"use criterion::{criterion_group, criterion_main};\nuse day_08_haunted_wasteland::solve;\nuse std::hint::black_box;\n"
+		);
+
+		let new = new_day(2022, 16, "proboscidea-volcanium");
+		assert_eq!(
+			fill_bench(
+				"const NAME: &str = todo!();\n\tb.iter(|| aoc_2022_xx::solve(black_box(file)))\n",
+				&new
+			),
+			"const NAME: &str = \"day_16\";\n\tb.iter(|| aoc_2022_16::solve(black_box(file)))\n"
+		);
+		assert_eq!(
+			fill_lib("\t#[case(todo!(), Solution(0,0))]\n"),
+			"\t#[case(include_bytes!(\"../inputs/example.txt\"), Solution(0,0))]\n"
+		);
+	}
+
+	const MANIFEST: &str = "\
+[dependencies]
+aoc-cli = { path = \"../../aoc/cli\" }
+day-01-a = { path = \"../days/01-a\" }
+day-03-c = { path = \"../days/03-c\" }
+";
+
+	#[rstest]
+	#[case(
+		2,
+		"day-01-a = { path = \"../days/01-a\" }\nday-02-b = { path = \"../days/02-b\" }\nday-03-c"
+	)]
+	#[case(
+		4,
+		"day-03-c = { path = \"../days/03-c\" }\nday-04-b = { path = \"../days/04-b\" }\n"
+	)]
+	fn registers_dependencies_in_order(#[case] day: u8, #[case] expected: &str) {
+		assert!(register_dependency(MANIFEST, &new_day(2025, day, "b")).contains(expected));
+	}
+
+	const MAIN: &str = "\
+const DAYS: &[Day] = &[
+	Day::new(1, \"A\", \"days/01-a/inputs/personal.txt\", |input, _| {
+		aoc_cli::answers(day_01_a::solve(input))
+	}),
+];
+";
+
+	#[test]
+	fn registers_runner_days() {
+		assert_eq!(
+			register_runner_day(MAIN, &new_day(2025, 2, "gift-shop")).unwrap(),
+			"\
+const DAYS: &[Day] = &[
+	Day::new(1, \"A\", \"days/01-a/inputs/personal.txt\", |input, _| {
+		aoc_cli::answers(day_01_a::solve(input))
+	}),
+	Day::new(2, \"Gift Shop\", \"days/02-gift-shop/inputs/personal.txt\", |input, _| {
+		aoc_cli::answers(day_02_gift_shop::solve(input))
+	}),
+];
+"
+		);
+	}
+
+	#[test]
+	fn registers_runner_days_before_later_ones() {
+		let main = "const DAYS: &[Day] = &[Day::new(\n\t3,\n\t\"C\",\n)];\n";
+		assert_eq!(
+			register_runner_day(main, &new_day(2024, 2, "b")).unwrap(),
+			"const DAYS: &[Day] = &[Day::new(2, \"B\", \"days/02-b/inputs/personal.txt\", |input, _| {\n\t\taoc_cli::answers(day_02_b::solve(input))\n\t}),\n\tDay::new(\n\t3,\n\t\"C\",\n)];\n"
+		);
+		assert!(
+			register_runner_day(main, &new_day(2024, 4, "d"))
+				.unwrap()
+				.starts_with(
+					"const DAYS: &[Day] = &[Day::new(\n\t3,\n\t\"C\",\n),\n\tDay::new(4, "
+				)
+		);
+	}
+
+	#[test]
+	fn registers_fuzz_targets() {
+		let manifest = "[dependencies]\nday-01-a = { path = \"../days/01-a\" }\n\n[[bin]]\nname = \"day_01\"\n";
+		let registered = register_fuzz_target(manifest, &new_day(2025, 2, "b"));
+		assert!(registered.contains("day-01-a = { path = \"../days/01-a\" }\nday-02-b = "));
+		assert!(registered.ends_with("[[bin]]\nname = \"day_02\"\npath = \"fuzz_targets/day_02.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+		assert!(fuzz_target(&new_day(2025, 2, "b")).contains("day_02_b::solve(&input.bytes("));
+	}
+}
@@ -0,0 +1,538 @@
+//! Regenerates the README's benchmark tables from criterion results.
+//!
+//! Criterion keeps the latest run of every benchmark in
+//! `target/criterion/<group>/<function>/<input>/new/`, next to a `benchmark.json` naming
+//! the benchmark. Days are recognised by their group, which is either the crate name
+//! (`day-08-playground`), a plain `day_08`, or a `Day 08: Playground` function in a
+//! group per year.
+
+use aoc_cli::format_duration;
+use std::{
+	fs,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+	pub day: u8,
+	pub name: Option<String>,
+	pub function: String,
+	pub input: String,
+	/// Median time of a single run, in nanoseconds.
+	pub median: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+	pub day: u8,
+	pub name: String,
+	pub time: String,
+	pub feeling: String,
+}
+
+/// Finds `"key":` at the top level of the object starting at `json`, returning what follows.
+fn json_field<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+	let mut depth = 0;
+	let mut in_string = false;
+	let mut escaped = false;
+
+	for (i, c) in json.char_indices() {
+		match c {
+			_ if escaped => escaped = false,
+			'\\' if in_string => escaped = true,
+			'"' if in_string => in_string = false,
+			'"' => {
+				let rest = &json[i..];
+				if depth == 1
+					&& let Some(value) = rest
+						.strip_prefix(&format!("\"{key}\""))
+						.and_then(|rest| rest.trim_start().strip_prefix(':'))
+				{
+					return Some(value.trim_start());
+				}
+				in_string = true;
+			}
+			'{' | '[' => depth += 1,
+			'}' | ']' => depth -= 1,
+			_ => {}
+		}
+	}
+
+	None
+}
+
+pub fn json_string(json: &str, key: &str) -> Option<String> {
+	let value = json_field(json, key)?.strip_prefix('"')?;
+	let mut string = String::new();
+	let mut chars = value.chars();
+
+	loop {
+		match chars.next()? {
+			'"' => return Some(string),
+			'\\' => string.push(chars.next()?),
+			c => string.push(c),
+		}
+	}
+}
+
+pub fn json_number(json: &str, key: &str) -> Option<f64> {
+	let value = json_field(json, key)?;
+	let end = value
+		.find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c)))
+		.unwrap_or(value.len());
+	value[..end].parse().ok()
+}
+
+/// Turns `if-you-give-a-seed-a-fertilizer` into `If You Give A Seed A Fertilizer`.
+pub fn title_case(slug: &str) -> String {
+	slug.split('-')
+		.filter(|word| !word.is_empty())
+		.map(|word| {
+			let mut chars = word.chars();
+			chars
+				.next()
+				.map(|first| first.to_uppercase().chain(chars).collect::<String>())
+				.unwrap_or_default()
+		})
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Works out the day (and maybe its name) a benchmark belongs to. Phase benchmarks only
+/// time a part of a solver, so they belong to none.
+fn identify(group: &str, function: &str) -> Option<(u8, Option<String>)> {
+	if function.starts_with("phase ") || function.contains(" phase ") {
+		return None;
+	}
+
+	if let Some(rest) = group
+		.strip_prefix("day-")
+		.or_else(|| group.strip_prefix("day_"))
+	{
+		let (day, slug) = rest.split_once('-').unwrap_or((rest, ""));
+		let name = Some(title_case(slug)).filter(|name| !name.is_empty());
+		return Some((day.parse().ok()?, name));
+	}
+
+	let (day, name) = function.strip_prefix("Day ")?.split_once(": ")?;
+	Some((day.parse().ok()?, Some(name.trim().to_string())))
+}
+
+fn read_measurement(dir: &Path) -> Option<Measurement> {
+	let benchmark = fs::read_to_string(dir.join("benchmark.json")).ok()?;
+	let estimates = fs::read_to_string(dir.join("estimates.json")).ok()?;
+
+	let group = json_string(&benchmark, "group_id")?;
+	let function = json_string(&benchmark, "function_id").unwrap_or_default();
+	let (day, name) = identify(&group, &function)?;
+
+	Some(Measurement {
+		day,
+		name,
+		function,
+		input: json_string(&benchmark, "value_str").unwrap_or_default(),
+		median: json_number(json_field(&estimates, "median")?, "point_estimate")?,
+	})
+}
+
+/// Collects the latest measurement of every benchmark below `criterion`.
+pub fn measurements(criterion: &Path) -> Vec<Measurement> {
+	let mut measurements = vec![];
+	let mut dirs: Vec<PathBuf> = vec![criterion.to_path_buf()];
+
+	while let Some(dir) = dirs.pop() {
+		let Ok(entries) = fs::read_dir(&dir) else {
+			continue;
+		};
+		for entry in entries.flatten() {
+			let path = entry.path();
+			if !path.is_dir() {
+				continue;
+			}
+			if entry.file_name() == "new" {
+				measurements.extend(read_measurement(&path));
+			} else if entry.file_name() != "base" && entry.file_name() != "report" {
+				dirs.push(path);
+			}
+		}
+	}
+
+	measurements
+}
+
+/// Picks the time shown for a day: the personal input if it was benchmarked, otherwise
+/// the largest one (older benches label inputs by their size), and the fastest solver.
+fn best(measurements: &[&Measurement]) -> Option<f64> {
+	let size = |m: &&&Measurement| m.input.parse::<u64>().ok();
+	let personal: Vec<_> = measurements
+		.iter()
+		.filter(|m| m.input == "personal")
+		.collect();
+	let candidates: Vec<_> = if !personal.is_empty() {
+		personal
+	} else if let Some(largest) = measurements.iter().filter_map(|m| size(&m)).max() {
+		measurements
+			.iter()
+			.filter(|m| size(m) == Some(largest))
+			.collect()
+	} else {
+		measurements.iter().collect()
+	};
+
+	candidates
+		.iter()
+		.map(|m| m.median)
+		.min_by(|a, b| a.total_cmp(b))
+}
+
+fn cells(line: &str) -> Option<Vec<&str>> {
+	let inner = line.trim().strip_prefix('|')?.strip_suffix('|')?;
+	Some(inner.split('|').map(str::trim).collect())
+}
+
+/// Parses the rows of a `| Day | Name | Time | Feeling |` table, skipping its header.
+pub fn parse_rows(table: &[&str]) -> Vec<Row> {
+	table
+		.iter()
+		.filter_map(|line| cells(line))
+		.filter_map(|cells| match cells[..] {
+			[day, name, time, feeling] => Some(Row {
+				day: day.parse().ok()?,
+				name: name.to_string(),
+				time: time.to_string(),
+				feeling: feeling.to_string(),
+			}),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Applies new measurements to the rows, keeping names and feelings already written down
+/// as well as footnotes attached to a time.
+pub fn update_rows(rows: &mut Vec<Row>, measurements: &[Measurement]) {
+	let mut days: Vec<u8> = measurements.iter().map(|m| m.day).collect();
+	days.sort_unstable();
+	days.dedup();
+
+	for day in days {
+		let of_day: Vec<_> = measurements.iter().filter(|m| m.day == day).collect();
+		let Some(median) = best(&of_day) else {
+			continue;
+		};
+		let time = format_duration(Duration::from_secs_f64(median / 1e9));
+		let name = of_day
+			.iter()
+			.find_map(|m| m.name.clone())
+			.unwrap_or_default();
+
+		match rows.iter_mut().find(|row| row.day == day) {
+			Some(row) => {
+				let footnote = row.time.find("[^").map(|i| &row.time[i..]).unwrap_or("");
+				row.time = format!("{time}{footnote}");
+				if row.name.is_empty() {
+					row.name = name;
+				}
+			}
+			None => rows.push(Row {
+				day,
+				name,
+				time,
+				feeling: String::new(),
+			}),
+		}
+	}
+
+	rows.sort_by_key(|row| row.day);
+}
+
+/// Width of a cell as shown by editors, counting emoji as two columns.
+fn width(text: &str) -> usize {
+	text.chars()
+		.map(|c| {
+			if ('\u{1F300}'..='\u{1FAFF}').contains(&c) {
+				2
+			} else {
+				1
+			}
+		})
+		.sum()
+}
+
+fn center(text: &str, columns: usize) -> String {
+	let padding = columns.saturating_sub(width(text));
+	format!(
+		"{}{text}{}",
+		" ".repeat(padding / 2),
+		" ".repeat(padding - padding / 2)
+	)
+}
+
+pub fn render_table(rows: &[Row]) -> Vec<String> {
+	let name = rows
+		.iter()
+		.map(|row| width(&row.name))
+		.max()
+		.unwrap_or(0)
+		.max(4);
+	let time = rows
+		.iter()
+		.map(|row| width(&row.time))
+		.max()
+		.unwrap_or(0)
+		.max(4);
+	let pad = |text: &str, columns: usize| " ".repeat(columns.saturating_sub(width(text)));
+
+	let mut lines = vec![
+		format!(
+			"| Day | Name{} | {}Time | Feeling |",
+			pad("Name", name),
+			pad("Time", time)
+		),
+		format!(
+			"| :-: | :{} | {}: | :-----: |",
+			"-".repeat(name - 1),
+			"-".repeat(time - 1)
+		),
+	];
+	lines.extend(rows.iter().map(|row| {
+		format!(
+			"| {:02}  | {}{} | {}{} | {} |",
+			row.day,
+			row.name,
+			pad(&row.name, name),
+			pad(&row.time, time),
+			row.time,
+			center(&row.feeling, 7)
+		)
+	}));
+	lines
+}
+
+/// Rewrites the table under `### <year>` with new measurements.
+pub fn update_readme(readme: &str, year: u16, measurements: &[Measurement]) -> String {
+	update_table(readme, year, |rows| update_rows(rows, measurements))
+}
+
+/// Rewrites the table under `### <year>`, adding the section if the README has none.
+pub fn update_table(readme: &str, year: u16, update: impl FnOnce(&mut Vec<Row>)) -> String {
+	let mut lines: Vec<String> = readme.lines().map(String::from).collect();
+	let heading = format!("### {year}");
+	let is_heading = |line: &String| line.starts_with("## ") || line.starts_with("### ");
+
+	match lines.iter().position(|line| *line == heading) {
+		Some(start) => {
+			let end = lines[start + 1..]
+				.iter()
+				.position(is_heading)
+				.map_or(lines.len(), |i| start + 1 + i);
+			let table_start = lines[start..end]
+				.iter()
+				.position(|line| line.starts_with('|'))
+				.map(|i| start + i);
+
+			let (table_start, table_end, mut rows) = match table_start {
+				Some(table_start) => {
+					let table_end = lines[table_start..end]
+						.iter()
+						.position(|line| !line.starts_with('|'))
+						.map_or(end, |i| table_start + i);
+					let table: Vec<&str> = lines[table_start..table_end]
+						.iter()
+						.map(String::as_str)
+						.collect();
+					(table_start, table_end, parse_rows(&table))
+				}
+				None => {
+					lines.splice(start + 1..start + 1, [String::new()]);
+					(start + 2, start + 2, vec![])
+				}
+			};
+
+			update(&mut rows);
+			let mut table = render_table(&rows);
+			if table_start == table_end {
+				table.push(String::new());
+			}
+			lines.splice(table_start..table_end, table);
+		}
+		None => {
+			// Years are listed newest first.
+			let position = lines
+				.iter()
+				.position(|line| {
+					line.strip_prefix("### ")
+						.and_then(|other| other.parse::<u16>().ok())
+						.is_some_and(|other| other < year)
+				})
+				.unwrap_or(lines.len());
+
+			let mut rows = vec![];
+			update(&mut rows);
+			let mut section = vec![heading, String::new()];
+			section.extend(render_table(&rows));
+			section.push(String::new());
+			lines.splice(position..position, section);
+		}
+	}
+
+	lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	const BENCHMARK: &str = r#"{"group_id":"day-06-wait-for-it","function_id":"solve","value_str":"personal","throughput":{"Bytes":74},"full_id":"day-06-wait-for-it/solve/personal"}"#;
+	const ESTIMATES: &str = r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":358.8,"upper_bound":368.5},"point_estimate":363.5,"standard_error":2.49},"median":{"confidence_interval":{"confidence_level":0.95,"lower_bound":355.0,"upper_bound":369.9},"point_estimate":362.95,"standard_error":4.18}}"#;
+
+	fn measurement(day: u8, input: &str, median: f64) -> Measurement {
+		Measurement {
+			day,
+			name: None,
+			function: "solve".to_string(),
+			input: input.to_string(),
+			median,
+		}
+	}
+
+	#[test]
+	fn reads_criterion_json() {
+		assert_eq!(
+			json_string(BENCHMARK, "group_id").as_deref(),
+			Some("day-06-wait-for-it")
+		);
+		assert_eq!(
+			json_string(BENCHMARK, "value_str").as_deref(),
+			Some("personal")
+		);
+		assert_eq!(
+			json_number(json_field(ESTIMATES, "median").unwrap(), "point_estimate"),
+			Some(362.95)
+		);
+		// Only top-level keys count, not the ones of nested objects.
+		assert_eq!(json_number(ESTIMATES, "point_estimate"), None);
+	}
+
+	#[rstest]
+	#[case("day-05-if-you-give-a-seed-a-fertilizer", "solve", Some((5, Some("If You Give A Seed A Fertilizer"))))]
+	#[case("day_14", "solve_bytes", Some((14, None)))]
+	#[case("2021", "Day 02: Dive!", Some((2, Some("Dive!"))))]
+	#[case("utils", "solve", None)]
+	#[case("day-08-playground", "phase edges", None)]
+	#[case("day-10-pipe-maze", "raycast phase parse", None)]
+	fn identifies_days(
+		#[case] group: &str,
+		#[case] function: &str,
+		#[case] expected: Option<(u8, Option<&str>)>,
+	) {
+		assert_eq!(
+			identify(group, function),
+			expected.map(|(day, name)| (day, name.map(String::from)))
+		);
+	}
+
+	#[test]
+	fn prefers_personal_input_and_fastest_solver() {
+		let day_10 = [
+			measurement(10, "example_1", 100.0),
+			measurement(10, "personal", 44_000.0),
+			measurement(10, "personal", 52_000.0),
+		];
+		assert_eq!(best(&day_10.iter().collect::<Vec<_>>()), Some(44_000.0));
+
+		let day_01 = [
+			measurement(1, "14", 100.0),
+			measurement(1, "2237", 21_800.0),
+			measurement(1, "2237", 30_000.0),
+		];
+		assert_eq!(best(&day_01.iter().collect::<Vec<_>>()), Some(21_800.0));
+	}
+
+	const README: &str = "\
+## Benchmarks
+
+### 2025
+
+| Day | Name     |     Time | Feeling |
+| :-: | :------- | -------: | :-----: |
+| 01  | Entrance |    22 μs |   🙂    |
+| 02  |          |          |         |
+| 03  | Lobby    | 9.4ms[^c]|   😕    |
+
+[^c]: A footnote.
+
+### 2023
+
+| Day | Name | Time | Feeling |
+| :-: | :--- | ---: | :-----: |
+";
+
+	#[test]
+	fn updates_existing_table() {
+		let measurements = [
+			Measurement {
+				name: Some("Secret Entrance".to_string()),
+				..measurement(1, "personal", 21_300.0)
+			},
+			Measurement {
+				name: Some("Gift Shop".to_string()),
+				..measurement(2, "personal", 1_900.0)
+			},
+			measurement(3, "personal", 8_400_000.0),
+			Measurement {
+				name: Some("Printing Department".to_string()),
+				..measurement(4, "personal", 1_200_000.0)
+			},
+		];
+
+		assert_eq!(
+			update_readme(README, 2025, &measurements),
+			"\
+## Benchmarks
+
+### 2025
+
+| Day | Name                |       Time | Feeling |
+| :-: | :------------------ | ---------: | :-----: |
+| 01  | Entrance            |    21.3 μs |   🙂    |
+| 02  | Gift Shop           |     1.9 μs |         |
+| 03  | Lobby               | 8.4 ms[^c] |   😕    |
+| 04  | Printing Department |     1.2 ms |         |
+
+[^c]: A footnote.
+
+### 2023
+
+| Day | Name | Time | Feeling |
+| :-: | :--- | ---: | :-----: |
+"
+		);
+	}
+
+	#[test]
+	fn adds_missing_year() {
+		let measurements = [Measurement {
+			name: Some("Historian Hysteria".to_string()),
+			..measurement(1, "personal", 31_000.0)
+		}];
+
+		let updated = update_readme(README, 2024, &measurements);
+		assert!(updated.contains(
+			"\
+| 03  | Lobby    | 9.4ms[^c]|   😕    |
+
+[^c]: A footnote.
+
+### 2024
+
+| Day | Name               |    Time | Feeling |
+| :-: | :----------------- | ------: | :-----: |
+| 01  | Historian Hysteria | 31.0 μs |         |
+
+### 2023
+"
+		));
+	}
+}
@@ -0,0 +1,217 @@
+//! Expected answers of a year, as stored in its `answers.txt`.
+//!
+//! Every non-empty line that isn't a `#` comment has the shape
+//!
+//! ```text
+//! <day> <input> <part> <answer>
+//! <day> <input> param <key>=<value>
+//! ```
+//!
+//! where `<input>` names an input file of that day (see [`Day::input_path`]) and
+//! `<part>` is `1` or `2`. Answers containing line breaks are written with `\n`.
+//!
+//! [`Day::input_path`]: crate::Day::input_path
+
+use aoc_solution::Answer;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+	pub day: u8,
+	pub input: String,
+	/// `key=value` overrides for the day's parameters, as accepted by [`crate::Params::new`].
+	pub params: Vec<String>,
+	pub answers: [Option<Answer>; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Registry {
+	/// One entry per day and input, in order of first appearance.
+	pub entries: Vec<Entry>,
+}
+
+impl Registry {
+	/// Input names in order of first appearance, used as the columns of the verify matrix.
+	pub fn input_names(&self) -> Vec<&str> {
+		let mut names: Vec<&str> = vec![];
+		for entry in &self.entries {
+			if !names.contains(&entry.input.as_str()) {
+				names.push(&entry.input);
+			}
+		}
+		names
+	}
+
+	pub fn get(&self, day: u8, input: &str) -> Option<&Entry> {
+		self.entries
+			.iter()
+			.find(|entry| entry.day == day && entry.input == input)
+	}
+
+	fn entry_mut(&mut self, day: u8, input: &str) -> &mut Entry {
+		let index = match self
+			.entries
+			.iter()
+			.position(|entry| entry.day == day && entry.input == input)
+		{
+			Some(index) => index,
+			None => {
+				self.entries.push(Entry {
+					day,
+					input: input.to_string(),
+					params: vec![],
+					answers: [None, None],
+				});
+				self.entries.len() - 1
+			}
+		};
+		&mut self.entries[index]
+	}
+}
+
+impl FromStr for Registry {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut registry = Registry::default();
+
+		for (number, line) in s.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let error = |message: &str| format!("line {}: {message}", number + 1);
+
+			let Some((day, input, field, value)) = split_fields(line) else {
+				return Err(error("expected `<day> <input> <part> <answer>`"));
+			};
+
+			let day = day
+				.parse()
+				.map_err(|_| error(&format!("invalid day `{day}`")))?;
+			let entry = registry.entry_mut(day, input);
+
+			match field {
+				"param" if value.contains('=') => entry.params.push(value.to_string()),
+				"param" => return Err(error("expected `param <key>=<value>`")),
+				"1" | "2" => {
+					let slot = &mut entry.answers[if field == "1" { 0 } else { 1 }];
+					if slot.is_some() {
+						return Err(error(&format!(
+							"day {day:02} {input} part {field} is registered twice"
+						)));
+					}
+					*slot = Some(
+						unescape(value)
+							.parse()
+							.unwrap_or_else(|never| match never {}),
+					);
+				}
+				_ => return Err(error(&format!("invalid part `{field}`"))),
+			}
+		}
+
+		Ok(registry)
+	}
+}
+
+/// Splits off the first three whitespace separated fields, keeping the rest intact.
+fn split_fields(line: &str) -> Option<(&str, &str, &str, &str)> {
+	let (day, rest) = line.split_once(char::is_whitespace)?;
+	let (input, rest) = rest.trim_start().split_once(char::is_whitespace)?;
+	let (field, value) = rest.trim_start().split_once(char::is_whitespace)?;
+	Some((day, input, field, value.trim_start()))
+}
+
+/// Writes an answer on a single line, the way `answers.txt` stores it.
+pub fn escape(answer: &Answer) -> String {
+	answer
+		.to_string()
+		.trim_end()
+		.replace('\\', "\\\\")
+		.replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> String {
+	let mut text = String::with_capacity(value.len());
+	let mut chars = value.chars();
+
+	while let Some(c) = chars.next() {
+		match (c, chars.clone().next()) {
+			('\\', Some('n')) => {
+				text.push('\n');
+				chars.next();
+			}
+			('\\', Some('\\')) => {
+				text.push('\\');
+				chars.next();
+			}
+			_ => text.push(c),
+		}
+	}
+
+	text
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	const ANSWERS: &str = "\
+# day input part answer
+08 example param first_n=10
+08 example 1     40
+08 example 2     25272
+
+08 personal 1 98696
+10 personal 2 ##..\\n#..#
+";
+
+	#[test]
+	fn parses_entries() {
+		let registry: Registry = ANSWERS.parse().unwrap();
+
+		assert_eq!(registry.input_names(), ["example", "personal"]);
+		assert_eq!(
+			registry.get(8, "example"),
+			Some(&Entry {
+				day: 8,
+				input: "example".to_string(),
+				params: vec!["first_n=10".to_string()],
+				answers: [Some(Answer::Number(40)), Some(Answer::Number(25272))],
+			})
+		);
+		assert_eq!(
+			registry.get(8, "personal").unwrap().answers,
+			[Some(Answer::Number(98696)), None]
+		);
+		assert_eq!(
+			registry.get(10, "personal").unwrap().answers,
+			[None, Some(Answer::Text("##..\n#..#".to_string()))]
+		);
+	}
+
+	#[rstest]
+	#[case("08 example", "line 1: expected `<day> <input> <part> <answer>`")]
+	#[case("eight example 1 40", "line 1: invalid day `eight`")]
+	#[case("08 example 3 40", "line 1: invalid part `3`")]
+	#[case("08 example param first_n", "line 1: expected `param <key>=<value>`")]
+	#[case(
+		"08 example 1 40\n08 example 1 41",
+		"line 2: day 08 example part 1 is registered twice"
+	)]
+	fn rejects_malformed_lines(#[case] answers: &str, #[case] message: &str) {
+		assert_eq!(answers.parse::<Registry>(), Err(message.to_string()));
+	}
+
+	#[rstest]
+	#[case(Answer::Number(13140))]
+	#[case(Answer::Text("CMZ".to_string()))]
+	#[case(Answer::Text("#..#\n####".to_string()))]
+	#[case(Answer::Text("a\\nb".to_string()))]
+	fn escape_round_trips(#[case] answer: Answer) {
+		assert_eq!(unescape(&escape(&answer)).parse::<Answer>(), Ok(answer));
+	}
+}
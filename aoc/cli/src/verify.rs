@@ -0,0 +1,180 @@
+use crate::{
+	Answer, Day, Harness, Params,
+	registry::{Entry, Registry, escape},
+};
+use std::{
+	any::Any,
+	fs,
+	panic::{self, AssertUnwindSafe},
+	path::Path,
+};
+
+const PASS: char = '✓';
+const FAIL: char = '✗';
+const UNREGISTERED: char = '·';
+
+enum Outcome {
+	Answers([Option<Result<(), Answer>>; 2]),
+	Error(String),
+}
+
+impl Outcome {
+	fn cell(&self) -> String {
+		match self {
+			Outcome::Answers(parts) => parts
+				.iter()
+				.map(|part| match part {
+					Some(Ok(())) => PASS,
+					Some(Err(_)) => FAIL,
+					None => UNREGISTERED,
+				})
+				.map(String::from)
+				.collect::<Vec<_>>()
+				.join(" "),
+			Outcome::Error(_) => "error".to_string(),
+		}
+	}
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+	payload
+		.downcast_ref::<&str>()
+		.copied()
+		.or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+		.unwrap_or("unknown panic")
+}
+
+fn check(root: &str, day: &Day, entry: &Entry, harness: Harness) -> Outcome {
+	let path = Path::new(root).join(day.input_path(&entry.input));
+	let input = match fs::read(&path) {
+		Ok(input) => input,
+		Err(e) => return Outcome::Error(format!("could not read {}: {e}", path.display())),
+	};
+	let input = harness.prepare(&input);
+	let params = match Params::new(day, &entry.params) {
+		Ok(params) => params,
+		Err(message) => return Outcome::Error(message),
+	};
+
+	let answers = match panic::catch_unwind(AssertUnwindSafe(|| (day.run)(&input, &params))) {
+		Ok(Ok(answers)) => answers,
+		Ok(Err(e)) => return Outcome::Error(e.to_string()),
+		Err(payload) => {
+			return Outcome::Error(format!("panicked: {}", panic_message(payload.as_ref())));
+		}
+	};
+
+	let mut parts = [None, None];
+	for ((part, expected), actual) in parts.iter_mut().zip(&entry.answers).zip(answers) {
+		*part = expected.as_ref().map(|expected| {
+			if escape(expected) == escape(&actual) {
+				Ok(())
+			} else {
+				Err(actual)
+			}
+		});
+	}
+	Outcome::Answers(parts)
+}
+
+/// Runs every day against every input registered in `<root>/answers.txt` and prints
+/// a matrix of the results, followed by details on everything that didn't match.
+pub fn verify(
+	year: u16,
+	root: &str,
+	days: &[Day],
+	only: Option<u8>,
+	harness: Harness,
+) -> Result<(), String> {
+	let path = Path::new(root).join("answers.txt");
+	let registry: Registry = fs::read_to_string(&path)
+		.map_err(|e| format!("could not read {}: {e}", path.display()))?
+		.parse()
+		.map_err(|message| format!("{}: {message}", path.display()))?;
+
+	if let Some(entry) = registry
+		.entries
+		.iter()
+		.find(|entry| days.iter().all(|day| day.day != entry.day))
+	{
+		return Err(format!(
+			"{}: no solver for {year} day {:02}",
+			path.display(),
+			entry.day
+		));
+	}
+	if let Some(number) = only
+		&& days.iter().all(|day| day.day != number)
+	{
+		return Err(format!("no solver for {year} day {number:02}"));
+	}
+
+	let inputs = registry.input_names();
+	let widths: Vec<usize> = inputs.iter().map(|name| name.len().max(5)).collect();
+
+	let mut header = format!("{year:<6}");
+	for (name, width) in inputs.iter().zip(&widths) {
+		header += &format!("  {name:<width$}");
+	}
+	println!("{}", header.trim_end());
+
+	// Solver panics are reported in the matrix, not on stderr.
+	let hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+
+	let mut problems = vec![];
+	let (mut correct, mut total) = (0, 0);
+
+	for day in days
+		.iter()
+		.filter(|day| only.is_none_or(|number| day.day == number))
+	{
+		let mut row = format!("day {:02}", day.day);
+		for (name, width) in inputs.iter().zip(&widths) {
+			let cell = match registry.get(day.day, name) {
+				Some(entry) => {
+					let outcome = check(root, day, entry, harness);
+					match &outcome {
+						Outcome::Answers(parts) => {
+							for (part, result) in parts.iter().enumerate() {
+								match result {
+									Some(Ok(())) => correct += 1,
+									Some(Err(actual)) => problems.push(format!(
+										"day {:02} {name} part {}: expected {}, got {}",
+										day.day,
+										part + 1,
+										escape(entry.answers[part].as_ref().unwrap()),
+										escape(actual)
+									)),
+									None => {}
+								}
+							}
+						}
+						Outcome::Error(message) => {
+							problems.push(format!("day {:02} {name}: {message}", day.day));
+						}
+					}
+					total += entry.answers.iter().flatten().count();
+					outcome.cell()
+				}
+				None => String::new(),
+			};
+			row += &format!("  {cell:<width$}");
+		}
+		println!("{}", row.trim_end());
+	}
+
+	panic::set_hook(hook);
+
+	println!();
+	for problem in &problems {
+		println!("{problem}");
+	}
+	println!("{correct}/{total} answers correct");
+
+	if correct == total {
+		Ok(())
+	} else {
+		Err(format!("{year}: {} answers not verified", total - correct))
+	}
+}
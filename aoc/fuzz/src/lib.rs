@@ -0,0 +1,141 @@
+//! Shared harness for the `fuzz` crates of every year.
+//!
+//! Each year has a `fuzz` directory next to its `days`, with one [cargo-fuzz] target
+//! per day that feeds an [`Input`] into the day's `solve` through [`check`]:
+//!
+//! ```sh
+//! cd 2025/fuzz
+//! cargo fuzz run day_04
+//! ```
+//!
+//! # Panic policy
+//!
+//! Solvers are written for well-formed puzzle inputs and are allowed to give up on
+//! anything else, as long as they do so on purpose: `expect`, `assert!`,
+//! `unreachable!` or a `panic!` with a message are accepted and the run counts as
+//! passed. A panic that only happens because the solver didn't notice the bad input
+//! is reported as a crash:
+//!
+//! - out of bounds indexing and slicing,
+//! - arithmetic overflow and division by zero (with debug assertions enabled),
+//! - a bare `unwrap()` on `None` or `Err`.
+//!
+//! Stack overflows, running out of memory and timeouts are always crashes, libFuzzer
+//! catches those itself (`-rss_limit_mb`, `-timeout`).
+//!
+//! [cargo-fuzz]: https://github.com/rust-fuzz/cargo-fuzz
+
+use arbitrary::Arbitrary;
+use std::{
+	borrow::Cow,
+	panic::{self, AssertUnwindSafe, PanicHookInfo},
+	sync::Once,
+};
+
+/// Panic messages of std for mistakes the solver didn't see coming.
+const UNACCEPTABLE: &[&str] = &[
+	"index out of bounds",
+	"range start index",
+	"range end index",
+	"slice index starts at",
+	"byte index",
+	"attempt to ",
+	"called `Option::unwrap()` on a `None` value",
+	"called `Result::unwrap()` on an `Err` value",
+];
+
+/// Fuzz input for a solver, either passed through as is or mapped onto the bytes of
+/// the day's input format. Shaped inputs get past the first parsing step much more
+/// often than random bytes do.
+#[derive(Debug, Arbitrary)]
+pub enum Input<'a> {
+	Raw(&'a [u8]),
+	Shaped(&'a [u8]),
+}
+
+impl<'a> Input<'a> {
+	/// The bytes to solve. `alphabet` lists every byte that can appear in the day's
+	/// input, repeating a byte makes it more likely.
+	pub fn bytes(&self, alphabet: &[u8]) -> Cow<'a, [u8]> {
+		match *self {
+			Input::Raw(bytes) => Cow::Borrowed(bytes),
+			Input::Shaped(bytes) => Cow::Owned(
+				bytes
+					.iter()
+					.map(|&b| alphabet[b as usize % alphabet.len()])
+					.collect(),
+			),
+		}
+	}
+}
+
+/// Whether a panic with this message is an accepted way to reject input.
+pub fn is_acceptable(message: &str) -> bool {
+	!UNACCEPTABLE
+		.iter()
+		.any(|prefix| message.starts_with(prefix))
+}
+
+fn panic_message<'a>(info: &'a PanicHookInfo) -> &'a str {
+	info.payload()
+		.downcast_ref::<&str>()
+		.copied()
+		.or_else(|| info.payload().downcast_ref::<String>().map(String::as_str))
+		.unwrap_or("")
+}
+
+/// Runs a solver, letting it panic according to the policy above.
+///
+/// Accepted panics are swallowed, every other panic is handed to the previous hook,
+/// which libFuzzer installs to abort and record the input.
+pub fn check<T>(solve: impl FnOnce() -> T) {
+	static HOOK: Once = Once::new();
+	HOOK.call_once(|| {
+		let crash = panic::take_hook();
+		panic::set_hook(Box::new(move |info| {
+			if !is_acceptable(panic_message(info)) {
+				crash(info);
+			}
+		}));
+	});
+
+	let _ = panic::catch_unwind(AssertUnwindSafe(solve));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case("valid uint", true)]
+	#[case("Not an ascii digit", true)]
+	#[case("assertion failed: line.len() == width", true)]
+	#[case("internal error: entered unreachable code", true)]
+	#[case("index out of bounds: the len is 3 but the index is 3", false)]
+	#[case("range end index 7 out of range for slice of length 4", false)]
+	#[case("attempt to subtract with overflow", false)]
+	#[case("called `Option::unwrap()` on a `None` value", false)]
+	fn follows_panic_policy(#[case] message: &str, #[case] acceptable: bool) {
+		assert_eq!(is_acceptable(message), acceptable);
+	}
+
+	#[test]
+	fn shapes_input() {
+		let alphabet = b"LR0123456789\n";
+		assert_eq!(
+			Input::Raw(b"\xff\x00").bytes(alphabet).as_ref(),
+			b"\xff\x00"
+		);
+		assert_eq!(
+			Input::Shaped(&[0, 1, 14, 12]).bytes(alphabet).as_ref(),
+			b"LRR\n"
+		);
+	}
+
+	#[test]
+	fn swallows_accepted_panics() {
+		check(|| panic!("not a valid input"));
+		check(|| 42);
+	}
+}
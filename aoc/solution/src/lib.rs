@@ -0,0 +1,165 @@
+//! Answer types shared by every year.
+//!
+//! Each day keeps its own `Solution` struct, but implements [`Solution`] so tooling
+//! can get at both parts without knowing the struct's shape. Every year's `aoc_utils`
+//! re-exports this crate as `aoc_utils::solution`.
+
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+/// A single puzzle answer, normalised for printing, comparing and storing.
+///
+/// The string form round-trips through `FromStr` and `Display`, which is what answer files
+/// rely on. The values don't always: text that looks like a number, or like the `-` of a
+/// missing answer, parses back as one. Compare answers by their string form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+	Number(i128),
+	Text(String),
+	/// The solver found no answer, e.g. an `Option` that stayed `None`.
+	Missing,
+}
+
+const MISSING: &str = "-";
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Answer::Number(number) => write!(f, "{number}"),
+			Answer::Text(text) => write!(f, "{text}"),
+			Answer::Missing => write!(f, "{MISSING}"),
+		}
+	}
+}
+
+impl FromStr for Answer {
+	type Err = Infallible;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s == MISSING {
+			return Ok(Answer::Missing);
+		}
+
+		Ok(s.parse()
+			.map(Answer::Number)
+			.unwrap_or_else(|_| Answer::Text(s.to_string())))
+	}
+}
+
+macro_rules! number_answers {
+	($($int:ty),*) => {
+		$(
+			impl From<$int> for Answer {
+				#[inline]
+				fn from(number: $int) -> Self {
+					Answer::Number(number.into())
+				}
+			}
+		)*
+	};
+}
+
+number_answers!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+	#[inline]
+	fn from(number: usize) -> Self {
+		Answer::Number(number as i128)
+	}
+}
+
+impl From<isize> for Answer {
+	#[inline]
+	fn from(number: isize) -> Self {
+		Answer::Number(number as i128)
+	}
+}
+
+impl From<u128> for Answer {
+	fn from(number: u128) -> Self {
+		i128::try_from(number)
+			.map(Answer::Number)
+			.unwrap_or_else(|_| Answer::Text(number.to_string()))
+	}
+}
+
+impl From<String> for Answer {
+	#[inline]
+	fn from(text: String) -> Self {
+		Answer::Text(text)
+	}
+}
+
+impl From<&str> for Answer {
+	#[inline]
+	fn from(text: &str) -> Self {
+		Answer::Text(text.to_string())
+	}
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+	#[inline]
+	fn from(answer: Option<T>) -> Self {
+		answer.map_or(Answer::Missing, Into::into)
+	}
+}
+
+/// Implemented by every day's `Solution` struct.
+pub trait Solution: Sized {
+	type Part1: Into<Answer>;
+	type Part2: Into<Answer>;
+
+	fn into_parts(self) -> (Self::Part1, Self::Part2);
+
+	fn into_answers(self) -> [Answer; 2] {
+		let (part1, part2) = self.into_parts();
+		[part1.into(), part2.into()]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rstest::rstest;
+
+	#[rstest]
+	#[case(Answer::Number(98696), Answer::Number(98696))]
+	#[case(Answer::Number(-42), Answer::Number(-42))]
+	#[case(Answer::Text("CMZ".to_string()), Answer::Text("CMZ".to_string()))]
+	#[case(Answer::Text("#..#\n####".to_string()), Answer::Text("#..#\n####".to_string()))]
+	#[case(Answer::Missing, Answer::Missing)]
+	#[case(Answer::Text("42".to_string()), Answer::Number(42))]
+	#[case(Answer::Text("-".to_string()), Answer::Missing)]
+	fn display_round_trips(#[case] answer: Answer, #[case] parsed: Answer) {
+		let text = answer.to_string();
+		assert_eq!(text.parse::<Answer>(), Ok(parsed));
+		assert_eq!(text.parse::<Answer>().unwrap().to_string(), text);
+	}
+
+	#[test]
+	fn converts_numbers() {
+		assert_eq!(Answer::from(7_u8), Answer::Number(7));
+		assert_eq!(Answer::from(-7_isize), Answer::Number(-7));
+		assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+		assert_eq!(Answer::from(None::<usize>), Answer::Missing);
+		assert_eq!(Answer::from(Some(3_u32)), Answer::Number(3));
+	}
+
+	struct Tuple(usize, String);
+
+	impl Solution for Tuple {
+		type Part1 = usize;
+		type Part2 = String;
+
+		fn into_parts(self) -> (usize, String) {
+			(self.0, self.1)
+		}
+	}
+
+	#[test]
+	fn solutions_turn_into_answers() {
+		assert_eq!(
+			Tuple(13140, "##..".to_string()).into_answers(),
+			[Answer::Number(13140), Answer::Text("##..".to_string())]
+		);
+	}
+}